@@ -0,0 +1,67 @@
+//! Order book maintenance benchmarks.
+//!
+//! Apply a synthetic market stream
+//! (`benches/data/synthetic_market_frames.jsonl`) to [`OrderBooks`], with
+//! and without the parsing step:
+//!
+//! ```text
+//! cargo bench --bench book
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use polymarket_rs::websocket::{FrameCapture, OrderBooks, WsEvent};
+
+const CAPTURE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/benches/data/synthetic_market_frames.jsonl"
+);
+
+fn bench_book_application(c: &mut Criterion) {
+    let capture = FrameCapture::load(CAPTURE).expect("recorded frames");
+    let events: Vec<WsEvent> = capture
+        .market_events()
+        .collect::<polymarket_rs::Result<_>>()
+        .expect("parsable frames");
+
+    let mut group = c.benchmark_group("book");
+    group.throughput(Throughput::Elements(events.len() as u64));
+
+    group.bench_function("apply", |b| {
+        b.iter(|| {
+            let mut books = OrderBooks::new();
+            for event in &events {
+                books.apply(black_box(event));
+            }
+            books
+        })
+    });
+    // The whole hot path from text frame to updated book
+    group.bench_function("parse_and_apply", |b| {
+        b.iter(|| {
+            let mut books = OrderBooks::new();
+            for event in capture.market_events() {
+                books.apply(&event.unwrap());
+            }
+            books
+        })
+    });
+
+    // Reading the top of book after every update, as a quoting loop would
+    group.bench_function("apply_and_read_top", |b| {
+        b.iter(|| {
+            let mut books = OrderBooks::new();
+            for event in &events {
+                if books.apply(event) {
+                    for book in books.iter() {
+                        black_box((book.best_bid(), book.best_ask(), book.mid()));
+                    }
+                }
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_book_application);
+criterion_main!(benches);
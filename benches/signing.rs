@@ -0,0 +1,72 @@
+//! Order signing benchmarks.
+//!
+//! Build and EIP-712 sign limit and market orders as
+//! [`OrderBuilder`] does before every submission:
+//!
+//! ```text
+//! cargo bench --bench signing
+//! ```
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use polymarket_rs::{
+    dec, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OrderArgs, OrderBuilder,
+    PrivateKeySigner, Side, SignatureType,
+};
+use std::str::FromStr;
+
+const CHAIN_ID: u64 = 137;
+const TOKEN_ID: &str =
+    "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+/// A throwaway key, so runs sign identical payloads
+const PRIVATE_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+fn builder(sig_type: SignatureType) -> OrderBuilder {
+    let signer = PrivateKeySigner::from_str(PRIVATE_KEY).expect("valid key");
+    OrderBuilder::new(signer, Some(sig_type), None)
+}
+
+fn bench_order_signing(c: &mut Criterion) {
+    let extras = ExtraOrderArgs::default();
+    let limit = OrderArgs::new(TOKEN_ID, dec!(0.52), dec!(100), Side::Buy);
+    let market = MarketOrderArgs::new(TOKEN_ID, dec!(50), Side::Buy);
+    let mut group = c.benchmark_group("signing");
+
+    for (name, sig_type) in [
+        ("eoa", SignatureType::Eoa),
+        ("proxy", SignatureType::PolyProxy),
+    ] {
+        let builder = builder(sig_type);
+        for neg_risk in [false, true] {
+            let options = CreateOrderOptions::new()
+                .tick_size(dec!(0.01))
+                .neg_risk(neg_risk);
+            let suffix = if neg_risk { "/neg_risk" } else { "" };
+
+            group.bench_function(format!("limit/{}{}", name, suffix), |b| {
+                b.iter(|| {
+                    builder
+                        .create_order(CHAIN_ID, black_box(&limit), 0, &extras, options.clone())
+                        .unwrap()
+                })
+            });
+            group.bench_function(format!("market/{}{}", name, suffix), |b| {
+                b.iter(|| {
+                    builder
+                        .create_market_order(
+                            CHAIN_ID,
+                            black_box(&market),
+                            dec!(0.55),
+                            &extras,
+                            options.clone(),
+                        )
+                        .unwrap()
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_order_signing);
+criterion_main!(benches);
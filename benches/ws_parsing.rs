@@ -0,0 +1,114 @@
+//! Websocket message parsing benchmarks.
+//!
+//! Measures parsing of large book snapshots and of a synthetic market stream
+//! (`benches/data/synthetic_market_frames.jsonl`) with:
+//!
+//! ```text
+//! cargo bench --bench ws_parsing
+//! cargo bench --bench ws_parsing --features simd-json
+//! ```
+//!
+//! Add `--features raw` to also measure parsing that keeps each event's JSON.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+#[cfg(feature = "raw")]
+use polymarket_rs::websocket::parse_market_message_raw;
+use polymarket_rs::websocket::{
+    parse_market_message, parse_market_message_filtered, EventFilter, FrameCapture, MarketEventType,
+};
+
+const CAPTURE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/benches/data/synthetic_market_frames.jsonl"
+);
+
+/// Build a book snapshot with `levels` price levels per side
+///
+/// The server usually wraps snapshots in a one-element array.
+fn book_snapshot(levels: usize, array: bool) -> String {
+    let side = |offset: usize| {
+        (0..levels)
+            .map(|i| {
+                format!(
+                    r#"{{"price":"0.{:04}","size":"{}.{:02}"}}"#,
+                    offset + i,
+                    100 + i * 7,
+                    i % 100
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    let event = format!(
+        r#"{{"event_type":"book","asset_id":"71321045679252212594626385532706912750332728571942532289631379312455583992563","market":"0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1","timestamp":"1700000000123","hash":"0x1b6b1b7e3a4a58b9b9d24c0ae6fdfa3a5c8f6b0a","bids":[{}],"asks":[{}]}}"#,
+        side(1),
+        side(5000)
+    );
+    if array {
+        format!("[{}]", event)
+    } else {
+        event
+    }
+}
+
+fn backend() -> &'static str {
+    if cfg!(feature = "simd-json") {
+        "simd-json"
+    } else {
+        "serde_json"
+    }
+}
+
+fn bench_book_snapshots(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("book_snapshot/{}", backend()));
+
+    for (form, array) in [("object", false), ("array", true)] {
+        for levels in [10, 100, 1000] {
+            let message = book_snapshot(levels, array);
+            group.throughput(Throughput::Bytes(message.len() as u64));
+            group.bench_with_input(BenchmarkId::new(form, levels), &message, |b, msg| {
+                b.iter(|| parse_market_message(black_box(msg)).remove(0).unwrap())
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// Parse every frame of the synthetic stream, as the market client does
+fn bench_synthetic_stream(c: &mut Criterion) {
+    let capture = FrameCapture::load(CAPTURE).expect("recorded frames");
+    let mut group = c.benchmark_group(format!("synthetic_stream/{}", backend()));
+    group.throughput(Throughput::Bytes(capture.total_bytes() as u64));
+
+    group.bench_function("parse", |b| {
+        b.iter(|| {
+            for frame in capture.frames() {
+                black_box(parse_market_message(black_box(frame)));
+            }
+        })
+    });
+    // Dropping price changes before decoding, as a trades-only consumer would
+    let trades = EventFilter::only(&[MarketEventType::LastTradePrice]);
+    group.bench_function("parse_trades_only", |b| {
+        b.iter(|| {
+            for frame in capture.frames() {
+                black_box(parse_market_message_filtered(black_box(frame), trades));
+            }
+        })
+    });
+    #[cfg(feature = "raw")]
+    group.bench_function("parse_raw", |b| {
+        b.iter(|| {
+            for frame in capture.frames() {
+                black_box(parse_market_message_raw(black_box(frame)));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_book_snapshots, bench_synthetic_stream);
+criterion_main!(benches);
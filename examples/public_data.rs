@@ -11,7 +11,7 @@ async fn main() -> Result<()> {
 
     // Get all positions for a user
     println!("Fetching positions for user: {}...\n", user_address);
-    match client.get_positions(user_address).await {
+    match client.get_positions(user_address, None).await {
         Ok(positions) => {
             println!("Found {} positions:", positions.len());
 
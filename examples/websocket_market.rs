@@ -1,6 +1,7 @@
 use futures_util::StreamExt;
 use polymarket_rs::types::WsEvent;
 use polymarket_rs::websocket::{MarketWsClient, ReconnectConfig, ReconnectingStream};
+use polymarket_rs::TokenId;
 use std::time::Duration;
 
 #[tokio::main]
@@ -10,7 +11,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Token IDs to subscribe to
     let token_ids = vec![
         // "Yes" token for "Fed decreases interest rates by 25 bps after December 2025 meeting?"
-        "87769991026114894163580777793845523168226980076553814689875238288185044414090".to_string(),
+        "87769991026114894163580777793845523168226980076553814689875238288185044414090"
+            .parse::<TokenId>()?,
     ];
 
     println!("Connecting to CLOB WebSocket with auto-reconnect...");
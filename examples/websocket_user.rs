@@ -92,7 +92,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         println!("  Side: {:?}", order.side);
                         println!("  Status: {}", order.status);
                         println!("  Event Type: {}", order.order_event_type);
-                        println!("  Order Type: {}", order.order_type);
+                        println!("  Order Type: {}", order.order_type.as_str());
                         println!("  Outcome: {}", order.outcome);
                         println!("  Price: {}", order.price);
                         println!("  Original Size: {}", order.original_size);
@@ -0,0 +1,249 @@
+//! Audit trail of order activity
+//!
+//! A [`TradingClient`](crate::TradingClient) configured with
+//! [`with_audit_log`](crate::TradingClient::with_audit_log) records every
+//! order submission, acknowledgement and cancel it sends, together with the
+//! raw request and response payloads. Fills and order updates arrive on the
+//! user websocket and are recorded with [`AuditRecord::from_user_event`].
+//!
+//! [`JsonlAuditLog`] appends records to a JSON Lines file; implement
+//! [`AuditSink`] to store them elsewhere.
+
+use crate::error::Result;
+use crate::types::UserWsEvent;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// What an [`AuditRecord`] describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEventKind {
+    /// An order (or batch of orders) was sent to the exchange
+    OrderSubmitted,
+    /// The exchange answered an order submission
+    OrderAcknowledged,
+    /// An order submission failed before the exchange answered it
+    OrderRejected,
+    /// A cancel was sent to the exchange
+    CancelRequested,
+    /// The exchange answered a cancel
+    CancelAcknowledged,
+    /// A cancel failed before the exchange answered it
+    CancelRejected,
+    /// A trade involving one of the user's orders, from the user websocket
+    Fill,
+    /// An order placement, update or cancellation, from the user websocket
+    OrderUpdate,
+}
+
+/// One entry of the audit trail
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// When the record was created
+    pub timestamp: DateTime<Utc>,
+    /// What happened
+    pub kind: AuditEventKind,
+    /// The REST endpoint involved, e.g. `"/order"`; `None` for websocket events
+    pub endpoint: Option<String>,
+    /// The raw request, response or event payload
+    pub payload: serde_json::Value,
+}
+
+impl AuditRecord {
+    /// Create a record timestamped now
+    pub fn new(kind: AuditEventKind, endpoint: Option<&str>, payload: serde_json::Value) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            kind,
+            endpoint: endpoint.map(str::to_string),
+            payload,
+        }
+    }
+
+    /// Create a record for a user websocket event
+    ///
+    /// Trades become [`AuditEventKind::Fill`] and order events
+    /// [`AuditEventKind::OrderUpdate`].
+    pub fn from_user_event(event: &UserWsEvent) -> Self {
+        let kind = match event {
+            UserWsEvent::Trade(_) => AuditEventKind::Fill,
+            UserWsEvent::Order(_) => AuditEventKind::OrderUpdate,
+        };
+        let payload = serde_json::to_value(event).unwrap_or(serde_json::Value::Null);
+        Self::new(kind, None, payload)
+    }
+}
+
+/// Destination of audit records
+///
+/// Recording happens inline with the trading call, so implementations
+/// should be quick; a failed write is logged and does not fail the call.
+pub trait AuditSink: Send + Sync {
+    /// Persist one record
+    fn record(&self, record: &AuditRecord) -> Result<()>;
+}
+
+/// Audit log that appends one JSON object per line to a file
+///
+/// Each record is written with a single `write` call, so a crash leaves at
+/// most the last line incomplete. Enable [`with_sync`](Self::with_sync) to
+/// also flush each record to disk before the trading call returns.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::audit::JsonlAuditLog;
+/// use std::sync::Arc;
+///
+/// # fn example(client: polymarket_rs::TradingClient) -> polymarket_rs::Result<()> {
+/// let log = JsonlAuditLog::open("audit/session.jsonl")?.with_sync(true);
+/// let client = client.with_audit_log(Arc::new(log));
+/// # Ok(())
+/// # }
+/// ```
+pub struct JsonlAuditLog {
+    file: Mutex<File>,
+    sync: bool,
+}
+
+impl JsonlAuditLog {
+    /// Open `path` for appending, creating it if it does not exist
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+            sync: false,
+        })
+    }
+
+    /// Flush every record to disk (`fsync`) as it is written
+    pub fn with_sync(mut self, sync: bool) -> Self {
+        self.sync = sync;
+        self
+    }
+
+    /// Read back every record of a log, e.g. for post-mortem analysis
+    ///
+    /// A truncated final line, as left by a crash mid-write, is skipped.
+    pub fn read(path: impl AsRef<Path>) -> Result<Vec<AuditRecord>> {
+        let lines: Vec<String> = BufReader::new(File::open(path)?)
+            .lines()
+            .collect::<std::io::Result<_>>()?;
+
+        let mut records = Vec::with_capacity(lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(line) {
+                Ok(record) => records.push(record),
+                Err(_) if i + 1 == lines.len() => break,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(records)
+    }
+}
+
+impl AuditSink for JsonlAuditLog {
+    fn record(&self, record: &AuditRecord) -> Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        file.write_all(&line)?;
+        if self.sync {
+            file.sync_data()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::MockHttpServer;
+    use crate::types::OrderId;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    /// Sink that keeps records in memory
+    #[derive(Default)]
+    struct MemorySink(Mutex<Vec<AuditRecord>>);
+
+    impl AuditSink for MemorySink {
+        fn record(&self, record: &AuditRecord) -> Result<()> {
+            self.0.lock().unwrap().push(record.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trading_client_records_cancels() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        let sink = Arc::new(MemorySink::default());
+        let client = server.trading_client().with_audit_log(sink.clone());
+
+        client.cancel(&OrderId::new("0xabc")).await.unwrap();
+        client.cancel_all().await.unwrap_err();
+
+        let records = sink.0.lock().unwrap();
+        let kinds: Vec<_> = records.iter().map(|r| r.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                AuditEventKind::CancelRequested,
+                AuditEventKind::CancelAcknowledged,
+                AuditEventKind::CancelRequested,
+                AuditEventKind::CancelRejected,
+            ]
+        );
+        assert_eq!(records[0].payload["orderID"], "0xabc");
+        assert_eq!(records[1].endpoint.as_deref(), Some("/order"));
+        assert!(records[1].payload["canceled"].is_array());
+        assert!(records[3].payload["error"]
+            .as_str()
+            .unwrap()
+            .contains("404"));
+    }
+
+    #[test]
+    fn test_jsonl_round_trip() {
+        let dir = std::env::temp_dir().join(format!("polymarket-audit-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("audit.jsonl");
+        let _ = std::fs::remove_file(&path);
+
+        let log = JsonlAuditLog::open(&path).unwrap().with_sync(true);
+        let submitted = AuditRecord::new(
+            AuditEventKind::OrderSubmitted,
+            Some("/order"),
+            json!({ "orderType": "GTC" }),
+        );
+        let acked = AuditRecord::new(
+            AuditEventKind::OrderAcknowledged,
+            Some("/order"),
+            json!({ "orderID": "0x1", "success": true }),
+        );
+        log.record(&submitted).unwrap();
+        log.record(&acked).unwrap();
+
+        // A crash mid-write leaves a partial last line
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"timestamp\":")
+            .unwrap();
+
+        let records = JsonlAuditLog::read(&path).unwrap();
+        assert_eq!(records, [submitted, acked]);
+        assert_eq!(records[1].payload["orderID"], "0x1");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
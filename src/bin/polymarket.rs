@@ -0,0 +1,399 @@
+//! Command-line client for inspecting markets and trading
+//!
+//! Built with the `cli` feature. Every command goes through the library's
+//! public API, so the binary doubles as an end-to-end check of the crate
+//! against the live endpoints.
+//!
+//! Order commands sign with the key in `POLYMARKET_PRIVATE_KEY` (EOA
+//! wallets) and derive API credentials on the fly.
+
+use clap::{Parser, Subcommand, ValueEnum};
+use polymarket_rs::client::{AuthenticatedClient, GammaClient, TradingClient};
+use polymarket_rs::orders::OrderBuilder;
+use polymarket_rs::request::GammaMarketParams;
+use polymarket_rs::types::{
+    CancelOrdersResponse, CreateOrderOptions, OpenOrderParams, OrderArgs, PriceLevel, WsEvent,
+};
+use polymarket_rs::{
+    ClobClient, Decimal, Environment, Error, MarketWsClient, OrderId, OrderType, PrivateKeySigner,
+    Result, Side, StreamExt, TokenId,
+};
+use std::collections::BTreeMap;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+/// Gamma markets fetched per request while searching
+const SEARCH_PAGE_SIZE: u32 = 100;
+
+#[derive(Parser)]
+#[command(
+    name = "polymarket",
+    version,
+    about = "Inspect Polymarket markets and trade from the command line"
+)]
+struct Cli {
+    /// Deployment to connect to
+    #[arg(long, value_enum, default_value_t = Network::Mainnet, global = true)]
+    network: Network,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Network {
+    Mainnet,
+    Amoy,
+}
+
+impl From<Network> for Environment {
+    fn from(network: Network) -> Self {
+        match network {
+            Network::Mainnet => Environment::Mainnet,
+            Network::Amoy => Environment::Amoy,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the order book of a token
+    Book {
+        /// CLOB token ID of the outcome
+        token_id: String,
+        /// Price levels shown per side
+        #[arg(long, default_value_t = 10)]
+        depth: usize,
+    },
+    /// Show a token's order book, updated live from the market websocket
+    Watch {
+        /// CLOB token ID of the outcome
+        token_id: String,
+        /// Price levels shown per side
+        #[arg(long, default_value_t = 10)]
+        depth: usize,
+    },
+    /// Discover markets
+    #[command(subcommand)]
+    Markets(MarketsCommand),
+    /// Place, list and cancel orders (signs with POLYMARKET_PRIVATE_KEY)
+    #[command(subcommand)]
+    Order(OrderCommand),
+}
+
+#[derive(Subcommand)]
+enum MarketsCommand {
+    /// Find markets whose question contains a phrase
+    Search {
+        /// Case-insensitive phrase to look for
+        query: String,
+        /// Maximum markets printed
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+        /// Search closed markets instead of active ones
+        #[arg(long)]
+        closed: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum OrderCommand {
+    /// Place a limit order
+    Place {
+        /// CLOB token ID of the outcome
+        token_id: String,
+        /// BUY or SELL
+        side: Side,
+        /// Limit price, e.g. 0.42
+        price: Decimal,
+        /// Size in shares
+        size: Decimal,
+        /// GTC, GTD, FOK or FAK
+        #[arg(long, default_value = "GTC")]
+        order_type: OrderType,
+        /// The token belongs to a negative-risk market
+        #[arg(long)]
+        neg_risk: bool,
+    },
+    /// List open orders
+    List {
+        /// Only orders for this token
+        #[arg(long)]
+        token_id: Option<String>,
+    },
+    /// Cancel orders by ID
+    Cancel {
+        /// Order IDs (0x-prefixed hashes)
+        #[arg(required = true)]
+        order_ids: Vec<String>,
+    },
+    /// Cancel every open order
+    CancelAll,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let env = Environment::from(cli.network);
+
+    let result = match cli.command {
+        Command::Book { token_id, depth } => book(env, &token_id, depth).await,
+        Command::Watch { token_id, depth } => watch(env, &token_id, depth).await,
+        Command::Markets(MarketsCommand::Search {
+            query,
+            limit,
+            closed,
+        }) => search_markets(env, &query, limit, closed).await,
+        Command::Order(command) => order(env, command).await,
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn book(env: Environment, token_id: &str, depth: usize) -> Result<()> {
+    let client = ClobClient::new(env.clob_url());
+    let book = client.get_order_book(&TokenId::new(token_id)).await?;
+
+    // Levels are not sorted best-first by the API
+    let bids = levels(&book.bids);
+    let asks = levels(&book.asks);
+    println!("{}  {}", book.asset_id, book.timestamp);
+    print_book(
+        bids.iter().rev().map(|(p, s)| (*p, *s)),
+        asks.iter().map(|(p, s)| (*p, *s)),
+        depth,
+    );
+    Ok(())
+}
+
+async fn watch(env: Environment, token_id: &str, depth: usize) -> Result<()> {
+    let token_id = TokenId::new(token_id);
+    let ws = MarketWsClient::with_url(env.market_ws_url());
+    let mut stream = ws.subscribe(vec![token_id.clone()]).await?;
+
+    let mut bids = BTreeMap::new();
+    let mut asks = BTreeMap::new();
+    let mut last_trade = None;
+
+    while let Some(event) = stream.next().await {
+        match event? {
+            WsEvent::Book(book) if book.asset_id == token_id => {
+                bids = levels(&book.bids);
+                asks = levels(&book.asks);
+            }
+            WsEvent::PriceChange(update) => {
+                for change in update.price_changes {
+                    if change.asset_id != token_id {
+                        continue;
+                    }
+                    let side = match change.side {
+                        Side::Buy => &mut bids,
+                        Side::Sell => &mut asks,
+                    };
+                    if change.size.is_zero() {
+                        side.remove(&change.price);
+                    } else {
+                        side.insert(change.price, change.size);
+                    }
+                }
+            }
+            WsEvent::LastTradePrice(trade) if trade.asset_id == token_id => {
+                last_trade = Some(format!(
+                    "last trade: {} {} @ {}  ({})",
+                    trade.side.as_str(),
+                    trade.size,
+                    trade.price,
+                    trade.timestamp
+                ));
+            }
+            _ => continue,
+        }
+
+        // Redraw in place
+        print!("\x1b[2J\x1b[H");
+        println!("{}", token_id);
+        print_book(
+            bids.iter().rev().map(|(p, s)| (*p, *s)),
+            asks.iter().map(|(p, s)| (*p, *s)),
+            depth,
+        );
+        if let Some(line) = &last_trade {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+async fn search_markets(env: Environment, query: &str, limit: usize, closed: bool) -> Result<()> {
+    let client = GammaClient::new(env.gamma_url());
+    let needle = query.to_lowercase();
+    let mut found = 0;
+    let mut offset = 0;
+
+    while found < limit {
+        let params = GammaMarketParams::new()
+            .with_active(!closed)
+            .with_closed(closed)
+            .with_limit(SEARCH_PAGE_SIZE)
+            .with_offset(offset);
+        let page = client.get_markets(Some(params)).await?;
+
+        for market in page.iter() {
+            if found == limit || !market.question.to_lowercase().contains(&needle) {
+                continue;
+            }
+            found += 1;
+            println!("{}", market.question);
+            println!("  slug:      {}", market.slug);
+            println!("  condition: {}", market.condition_id);
+            let token_ids: Vec<String> = market
+                .clob_token_ids
+                .as_deref()
+                .and_then(|ids| serde_json::from_str(ids).ok())
+                .unwrap_or_default();
+            for token_id in token_ids {
+                println!("  token:     {}", token_id);
+            }
+        }
+
+        if page.len() < SEARCH_PAGE_SIZE as usize {
+            break;
+        }
+        offset += SEARCH_PAGE_SIZE;
+    }
+
+    if found == 0 {
+        println!("No markets match \"{}\"", query);
+    }
+    Ok(())
+}
+
+async fn order(env: Environment, command: OrderCommand) -> Result<()> {
+    let trading = trading_client(env).await?;
+
+    match command {
+        OrderCommand::Place {
+            token_id,
+            side,
+            price,
+            size,
+            order_type,
+            neg_risk,
+        } => {
+            let clob = ClobClient::new(env.clob_url());
+            let tick_size = clob
+                .get_tick_size(&TokenId::new(&token_id))
+                .await?
+                .minimum_tick_size;
+            let options = CreateOrderOptions::default()
+                .tick_size(tick_size)
+                .neg_risk(neg_risk);
+
+            let args = OrderArgs::new(token_id, price, size, side);
+            let response = trading
+                .create_and_post_order(&args, None, None, options, order_type)
+                .await?;
+            if response.success {
+                println!("{} {}", response.status, response.order_id);
+            } else {
+                return Err(Error::InvalidOrder(response.error_msg));
+            }
+        }
+        OrderCommand::List { token_id } => {
+            let mut params = OpenOrderParams::new();
+            if let Some(token_id) = token_id {
+                params = params.asset_id(token_id);
+            }
+            let mut orders = trading.paginate_orders(params).into_stream();
+            while let Some(order) = orders.next().await {
+                let order = order?;
+                println!(
+                    "{}  {} {}/{} @ {}  {}",
+                    order.id,
+                    order.side.as_str(),
+                    order.size_matched,
+                    order.original_size,
+                    order.price,
+                    order.asset_id
+                );
+            }
+        }
+        OrderCommand::Cancel { order_ids } => {
+            let order_ids: Vec<OrderId> = order_ids.into_iter().map(OrderId::new).collect();
+            print_cancels(trading.cancel_orders(&order_ids).await?);
+        }
+        OrderCommand::CancelAll => print_cancels(trading.cancel_all().await?),
+    }
+    Ok(())
+}
+
+/// Build a trading client for the key in `POLYMARKET_PRIVATE_KEY`
+async fn trading_client(env: Environment) -> Result<TradingClient> {
+    let key = std::env::var("POLYMARKET_PRIVATE_KEY")
+        .map_err(|_| Error::Config("Set POLYMARKET_PRIVATE_KEY to trade".to_string()))?;
+    let signer = PrivateKeySigner::from_str(key.trim())
+        .map_err(|e| Error::Config(format!("Invalid POLYMARKET_PRIVATE_KEY: {}", e)))?;
+
+    let auth = AuthenticatedClient::builder()
+        .with_signer(signer.clone())
+        .with_environment(env)
+        .build()?;
+    let api_creds = auth.create_or_derive_api_key().await?;
+
+    Ok(TradingClient::new(
+        env.clob_url(),
+        signer.clone(),
+        env.chain_id(),
+        api_creds,
+        OrderBuilder::new(signer, None, None),
+    ))
+}
+
+/// Index price levels by price
+fn levels(levels: &[PriceLevel]) -> BTreeMap<Decimal, Decimal> {
+    levels
+        .iter()
+        .map(|level| (level.price, level.size))
+        .collect()
+}
+
+/// Print asks above bids, best prices closest to the spread line
+///
+/// `bids` must be ordered best (highest) first and `asks` best (lowest) first.
+fn print_book(
+    bids: impl Iterator<Item = (Decimal, Decimal)>,
+    asks: impl Iterator<Item = (Decimal, Decimal)>,
+    depth: usize,
+) {
+    let bids: Vec<_> = bids.take(depth).collect();
+    let asks: Vec<_> = asks.take(depth).collect();
+
+    println!("{:>10}  {:>14}", "price", "size");
+    for (price, size) in asks.iter().rev() {
+        println!("\x1b[31m{:>10}  {:>14}\x1b[0m", price, size);
+    }
+    match (bids.first(), asks.first()) {
+        (Some((bid, _)), Some((ask, _))) => println!("{:-^26}", format!(" spread {} ", ask - bid)),
+        _ => println!("{:-^26}", ""),
+    }
+    for (price, size) in &bids {
+        println!("\x1b[32m{:>10}  {:>14}\x1b[0m", price, size);
+    }
+}
+
+fn print_cancels(response: CancelOrdersResponse) {
+    for order_id in &response.canceled {
+        println!("canceled {}", order_id);
+    }
+    if let Some(failed) = response.not_canceled.as_object() {
+        for (order_id, reason) in failed {
+            println!("not canceled {}: {}", order_id, reason);
+        }
+    }
+}
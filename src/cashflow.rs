@@ -0,0 +1,488 @@
+//! Per-market cash-flow reporting for accounting
+//!
+//! A [`CashFlowReport`] collects an account's trades from the user
+//! websocket, and its trades, splits, merges, redemptions and rewards from
+//! the data API's activity feed, and sums them per market: shares bought
+//! and their cost basis, shares sold and the proceeds, fees, and the USDC
+//! moved by splits, merges and redemptions. [`CashFlowReport::write_csv`]
+//! exports the summaries as one CSV row per market.
+//!
+//! Feed trades from one source only, either websocket trade events or
+//! `TRADE` activity: the two don't share IDs, so a trade seen through both
+//! would be counted twice.
+
+use crate::error::Result;
+use crate::types::{Activity, ActivityType, MakerOrder, Side, TradeEvent, TradeStatus};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use std::io::Write;
+
+/// What a cash-flow entry moved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CashFlowKind {
+    /// Shares bought for USDC
+    Buy,
+    /// Shares sold for USDC
+    Sell,
+    /// USDC split into a full set of outcome shares
+    Split,
+    /// A full set of outcome shares merged back into USDC
+    Merge,
+    /// Winning shares redeemed for USDC after resolution
+    Redemption,
+    /// Liquidity rewards and yield
+    Reward,
+}
+
+/// One cash movement in a market
+#[derive(Debug, Clone, PartialEq)]
+pub struct CashFlowEntry {
+    /// Condition ID of the market
+    pub market: String,
+    /// Market title, when the source carries one
+    pub title: Option<String>,
+    pub kind: CashFlowKind,
+    /// Shares traded, split, merged or redeemed
+    pub shares: Decimal,
+    /// USDC paid or received, before fees
+    pub amount: Decimal,
+    /// Fee paid in USDC
+    pub fee: Decimal,
+}
+
+/// Cash flows of one market
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarketCashFlow {
+    /// Condition ID of the market
+    pub market: String,
+    pub title: Option<String>,
+    pub shares_bought: Decimal,
+    /// USDC paid for bought shares
+    pub cost_basis: Decimal,
+    pub shares_sold: Decimal,
+    /// USDC received for sold shares
+    pub proceeds: Decimal,
+    pub fees: Decimal,
+    /// USDC paid into splits
+    pub splits: Decimal,
+    /// USDC received from merges
+    pub merges: Decimal,
+    /// USDC received from redemptions
+    pub redemptions: Decimal,
+    pub rewards: Decimal,
+}
+
+impl MarketCashFlow {
+    /// USDC received less USDC paid, fees included
+    pub fn net(&self) -> Decimal {
+        self.proceeds + self.merges + self.redemptions + self.rewards
+            - self.cost_basis
+            - self.splits
+            - self.fees
+    }
+
+    fn add(&mut self, entry: &CashFlowEntry) {
+        if self.title.is_none() {
+            self.title = entry.title.clone();
+        }
+        self.fees += entry.fee;
+        match entry.kind {
+            CashFlowKind::Buy => {
+                self.shares_bought += entry.shares;
+                self.cost_basis += entry.amount;
+            }
+            CashFlowKind::Sell => {
+                self.shares_sold += entry.shares;
+                self.proceeds += entry.amount;
+            }
+            CashFlowKind::Split => self.splits += entry.amount,
+            CashFlowKind::Merge => self.merges += entry.amount,
+            CashFlowKind::Redemption => self.redemptions += entry.amount,
+            CashFlowKind::Reward => self.rewards += entry.amount,
+        }
+    }
+}
+
+/// Fee in USDC of a trade at `fee_rate_bps`
+///
+/// The CLOB charges the rate on the cheaper side of the price, so fees are
+/// symmetric between an outcome and its complement.
+pub fn trade_fee(fee_rate_bps: Decimal, price: Decimal, size: Decimal) -> Decimal {
+    fee_rate_bps / Decimal::from(10_000) * price.min(Decimal::ONE - price) * size
+}
+
+/// Side of `order`, a maker order matched in `trade`
+///
+/// Unless the server reports it, the side is inferred from the outcome: a
+/// maker order on the taker's outcome is on the other side of the trade,
+/// while one on the complementary outcome was matched by minting or merging
+/// a full set and is on the taker's side.
+fn maker_side(trade: &TradeEvent, order: &MakerOrder) -> Side {
+    order
+        .side
+        .unwrap_or(match (order.outcome == trade.outcome, trade.side) {
+            (true, Side::Buy) | (false, Side::Sell) => Side::Sell,
+            (true, Side::Sell) | (false, Side::Buy) => Side::Buy,
+        })
+}
+
+fn cash_flow_kind(side: Side) -> CashFlowKind {
+    match side {
+        Side::Buy => CashFlowKind::Buy,
+        Side::Sell => CashFlowKind::Sell,
+    }
+}
+
+/// Cash-flow entries keyed by their source, summarized per market
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::cashflow::CashFlowReport;
+/// use polymarket_rs::client::DataClient;
+///
+/// # async fn example() -> polymarket_rs::Result<()> {
+/// let data = DataClient::new("https://data-api.polymarket.com");
+/// let activity = data.get_all_activity("0xYourProxyWallet", None).await?;
+///
+/// let mut report = CashFlowReport::new();
+/// report.record_activities(&activity);
+/// report.write_csv(std::fs::File::create("cashflows.csv")?)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CashFlowReport {
+    entries: BTreeMap<String, CashFlowEntry>,
+}
+
+impl CashFlowReport {
+    /// Create an empty report
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a user websocket trade event of the account at `owner`
+    ///
+    /// The user channel reports trades the account made as taker and as
+    /// maker. If `owner` matches the address of any of the trade's maker
+    /// orders, each of those orders is recorded at its own price, side and
+    /// matched amount, without a fee since the event's fee rate is the
+    /// taker's. Otherwise the trade is the account's taker order, and its
+    /// fee is computed from the event's fee rate, if it carries one.
+    ///
+    /// Each trade counts once however many status updates arrive, and a
+    /// trade that ends up `FAILED` is removed.
+    pub fn record_trade_event(&mut self, owner: &str, trade: &TradeEvent) {
+        let key = format!("trade:{}", trade.id);
+        self.remove_trade(&key);
+        if trade.status == TradeStatus::Failed {
+            return;
+        }
+        let market = trade.market.as_str().to_string();
+
+        let mut maker_orders = trade
+            .maker_orders
+            .iter()
+            .filter(|order| order.maker_address.eq_ignore_ascii_case(owner))
+            .peekable();
+        if maker_orders.peek().is_none() {
+            let fee = trade
+                .fee_rate_bps
+                .map(|rate| trade_fee(rate, trade.price, trade.size))
+                .unwrap_or_default();
+            self.entries.insert(
+                key,
+                CashFlowEntry {
+                    market,
+                    title: None,
+                    kind: cash_flow_kind(trade.side),
+                    shares: trade.size,
+                    amount: trade.price * trade.size,
+                    fee,
+                },
+            );
+            return;
+        }
+        for (index, order) in maker_orders.enumerate() {
+            self.entries.insert(
+                format!("{}:{}", key, index),
+                CashFlowEntry {
+                    market: market.clone(),
+                    title: None,
+                    kind: cash_flow_kind(maker_side(trade, order)),
+                    shares: order.matched_amount,
+                    amount: order.price * order.matched_amount,
+                    fee: Decimal::ZERO,
+                },
+            );
+        }
+    }
+
+    /// Remove the entries recorded for the trade at `key`
+    fn remove_trade(&mut self, key: &str) {
+        let legs = format!("{}:", key);
+        let stale: Vec<String> = self
+            .entries
+            .range(key.to_string()..)
+            .map(|(entry, _)| entry)
+            .take_while(|entry| entry.starts_with(key))
+            .filter(|entry| entry.as_str() == key || entry.starts_with(&legs))
+            .cloned()
+            .collect();
+        for entry in stale {
+            self.entries.remove(&entry);
+        }
+    }
+
+    /// Record a data API activity; returns whether it moved cash
+    ///
+    /// Conversions of neg-risk positions move no USDC and are skipped.
+    pub fn record_activity(&mut self, activity: &Activity) -> bool {
+        let kind = match (activity.activity_type, activity.side) {
+            (ActivityType::Trade, Some(Side::Buy)) => CashFlowKind::Buy,
+            (ActivityType::Trade, Some(Side::Sell)) => CashFlowKind::Sell,
+            (ActivityType::Split, _) => CashFlowKind::Split,
+            (ActivityType::Merge, _) => CashFlowKind::Merge,
+            (ActivityType::Redeem, _) => CashFlowKind::Redemption,
+            (ActivityType::Reward | ActivityType::Yield, _) => CashFlowKind::Reward,
+            _ => return false,
+        };
+        let key = format!(
+            "activity:{}:{}:{}",
+            activity.transaction_hash,
+            activity.asset,
+            activity.activity_type.as_str()
+        );
+        self.entries.insert(
+            key,
+            CashFlowEntry {
+                market: activity.condition_id.clone(),
+                title: Some(activity.title.clone()).filter(|title| !title.is_empty()),
+                kind,
+                shares: activity.size,
+                amount: activity.usdc_size,
+                fee: Decimal::ZERO,
+            },
+        );
+        true
+    }
+
+    /// Record several activities
+    pub fn record_activities<'a>(&mut self, activities: impl IntoIterator<Item = &'a Activity>) {
+        for activity in activities {
+            self.record_activity(activity);
+        }
+    }
+
+    /// Every recorded entry
+    pub fn entries(&self) -> impl Iterator<Item = &CashFlowEntry> {
+        self.entries.values()
+    }
+
+    /// Summaries per market, ordered by condition ID
+    pub fn markets(&self) -> Vec<MarketCashFlow> {
+        let mut markets: BTreeMap<&str, MarketCashFlow> = BTreeMap::new();
+        for entry in self.entries.values() {
+            markets
+                .entry(&entry.market)
+                .or_insert_with(|| MarketCashFlow {
+                    market: entry.market.clone(),
+                    ..Default::default()
+                })
+                .add(entry);
+        }
+        markets.into_values().collect()
+    }
+
+    /// Sum of every market's summary
+    pub fn total(&self) -> MarketCashFlow {
+        let mut total = MarketCashFlow {
+            market: "total".to_string(),
+            ..Default::default()
+        };
+        for entry in self.entries.values() {
+            total.add(&CashFlowEntry {
+                title: None,
+                ..entry.clone()
+            });
+        }
+        total
+    }
+
+    /// Write the per-market summaries as CSV with a header row
+    pub fn write_csv(&self, mut writer: impl Write) -> Result<()> {
+        writeln!(
+            writer,
+            "market,title,shares_bought,cost_basis,shares_sold,proceeds,fees,splits,merges,redemptions,rewards,net"
+        )?;
+        for market in self.markets() {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{},{},{},{},{}",
+                csv_field(&market.market),
+                csv_field(market.title.as_deref().unwrap_or("")),
+                market.shares_bought.normalize(),
+                market.cost_basis.normalize(),
+                market.shares_sold.normalize(),
+                market.proceeds.normalize(),
+                market.fees.normalize(),
+                market.splits.normalize(),
+                market.merges.normalize(),
+                market.redemptions.normalize(),
+                market.rewards.normalize(),
+                market.net().normalize(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// The per-market summaries as a CSV string
+    pub fn to_csv(&self) -> String {
+        let mut csv = Vec::new();
+        // Writing to a Vec can't fail
+        let _ = self.write_csv(&mut csv);
+        String::from_utf8(csv).unwrap_or_default()
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    const MARKET: &str = "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1";
+    const OWNER: &str = "0xD216153c06E857cD7f72665E0aF1d7D82172F494";
+
+    fn trade(id: &str, side: &str, price: &str, size: &str, status: &str) -> TradeEvent {
+        serde_json::from_value(json!({
+            "event_type": "trade",
+            "id": id,
+            "market": MARKET,
+            "asset_id": "123",
+            "side": side,
+            "outcome": "Yes",
+            "price": price,
+            "size": size,
+            "status": status,
+            "fee_rate_bps": "100",
+            "maker_orders": []
+        }))
+        .unwrap()
+    }
+
+    fn activity(kind: &str, usdc: f64, title: &str) -> Activity {
+        serde_json::from_value(json!({
+            "proxyWallet": "0x00",
+            "timestamp": 1700000000,
+            "conditionId": MARKET,
+            "type": kind,
+            "size": usdc,
+            "usdcSize": usdc,
+            "transactionHash": format!("0x{}", kind),
+            "price": 0,
+            "asset": "123",
+            "outcomeIndex": 0,
+            "title": title,
+            "slug": "",
+            "icon": "",
+            "eventSlug": "",
+            "outcome": "",
+            "name": ""
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_trades_and_settlement_summarized_per_market() {
+        let mut report = CashFlowReport::new();
+        report.record_trade_event(OWNER, &trade("t1", "BUY", "0.4", "100", "MATCHED"));
+        report.record_trade_event(OWNER, &trade("t1", "BUY", "0.4", "100", "CONFIRMED"));
+        report.record_trade_event(OWNER, &trade("t2", "SELL", "0.7", "50", "MATCHED"));
+        report.record_trade_event(OWNER, &trade("t3", "BUY", "0.5", "10", "MATCHED"));
+        report.record_trade_event(OWNER, &trade("t3", "BUY", "0.5", "10", "FAILED"));
+        assert!(report.record_activity(&activity("REDEEM", 50.0, "Will it rain, tomorrow?")));
+        assert!(!report.record_activity(&activity("CONVERSION", 5.0, "")));
+
+        let markets = report.markets();
+        assert_eq!(markets.len(), 1);
+        let market = &markets[0];
+        assert_eq!(market.title.as_deref(), Some("Will it rain, tomorrow?"));
+        assert_eq!(
+            (market.shares_bought, market.cost_basis),
+            (dec!(100), dec!(40))
+        );
+        assert_eq!((market.shares_sold, market.proceeds), (dec!(50), dec!(35)));
+        // 1% of min(p, 1 - p) per share: 0.4 + 0.15
+        assert_eq!(market.fees, dec!(0.55));
+        assert_eq!(market.redemptions, dec!(50));
+        assert_eq!(market.net(), dec!(44.45));
+        assert_eq!(report.total().net(), dec!(44.45));
+
+        let csv = report.to_csv();
+        let rows: Vec<_> = csv.lines().collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[1],
+            format!(
+                "{},\"Will it rain, tomorrow?\",100,40,50,35,0.55,0,0,50,0,44.45",
+                MARKET
+            )
+        );
+    }
+
+    #[test]
+    fn test_maker_fills_recorded_from_maker_orders() {
+        let mut trade = trade("t1", "BUY", "0.6", "30", "MATCHED");
+        let maker = |address: &str, amount: &str, price: &str, outcome: &str| {
+            serde_json::from_value(json!({
+                "maker_address": address,
+                "matched_amount": amount,
+                "price": price,
+                "outcome": outcome
+            }))
+            .unwrap()
+        };
+        trade.maker_orders = vec![
+            // Sold into the taker's bid
+            maker(&OWNER.to_lowercase(), "10", "0.6", "Yes"),
+            // Bought the complement, matched by minting a full set
+            maker(OWNER, "5", "0.4", "No"),
+            maker(
+                "0x0000000000000000000000000000000000000001",
+                "15",
+                "0.6",
+                "Yes",
+            ),
+        ];
+
+        let mut report = CashFlowReport::new();
+        report.record_trade_event(OWNER, &trade);
+        let market = &report.markets()[0];
+        assert_eq!((market.shares_sold, market.proceeds), (dec!(10), dec!(6)));
+        assert_eq!(
+            (market.shares_bought, market.cost_basis),
+            (dec!(5), dec!(2))
+        );
+        assert_eq!(market.fees, Decimal::ZERO);
+
+        // The taker sees the whole trade, with its fee
+        let mut taker = CashFlowReport::new();
+        taker.record_trade_event("0x0000000000000000000000000000000000000002", &trade);
+        assert_eq!(taker.markets()[0].shares_bought, dec!(30));
+
+        trade.status = TradeStatus::Failed;
+        report.record_trade_event(OWNER, &trade);
+        assert_eq!(report.entries().count(), 0);
+    }
+}
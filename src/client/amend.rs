@@ -0,0 +1,273 @@
+use super::TradingClient;
+use crate::error::{Error, Result};
+use crate::types::{
+    CreateOrderOptions, OpenOrder, OrderArgs, OrderId, OrderType, PostOrderResponse,
+};
+use rust_decimal::Decimal;
+
+/// Final outcome of [`TradingClient::amend_order`]
+#[derive(Debug)]
+pub enum AmendOutcome {
+    /// The original order was canceled and the replacement posted
+    ///
+    /// `size` is what was posted: the requested size less anything the
+    /// original filled before the cancel took effect. The replacement may
+    /// still have been rejected by the exchange; check
+    /// [`PostOrderResponse::success`].
+    Replaced {
+        original: OpenOrder,
+        replacement: PostOrderResponse,
+        size: Decimal,
+    },
+    /// The original filled up to the requested size before it could be
+    /// canceled, so nothing was posted
+    Filled { original: OpenOrder },
+    /// The exchange didn't confirm the cancel and the order isn't in a final
+    /// state, so it may still rest and nothing was posted
+    CancelRejected { original: OpenOrder, reason: String },
+    /// The original was canceled but the replacement could not be created
+    /// or posted; no order is resting
+    ReplaceFailed {
+        original: OpenOrder,
+        size: Decimal,
+        error: Error,
+    },
+}
+
+impl AmendOutcome {
+    /// The original order as it stood after the cancel
+    pub fn original(&self) -> &OpenOrder {
+        match self {
+            Self::Replaced { original, .. }
+            | Self::Filled { original }
+            | Self::CancelRejected { original, .. }
+            | Self::ReplaceFailed { original, .. } => original,
+        }
+    }
+
+    /// The ID of the resting replacement, if one was accepted
+    pub fn replacement_id(&self) -> Option<&OrderId> {
+        match self {
+            Self::Replaced { replacement, .. } if replacement.success => {
+                Some(&replacement.order_id)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Whether an order status means the order can no longer fill
+///
+/// The API spells cancellation both `CANCELED` and `CANCELLED`.
+fn is_final(status: &str) -> bool {
+    ["matched", "canceled", "cancelled", "unmatched"]
+        .iter()
+        .any(|final_status| status.eq_ignore_ascii_case(final_status))
+}
+
+impl TradingClient {
+    /// Cancel an order and replace it at a new price and size
+    ///
+    /// `size` is the total the amended order should trade, counting what
+    /// the original has already filled: if 30 of a 100 share order filled
+    /// and it is amended to 80, the replacement is for 50. The token and
+    /// side are taken from the original.
+    ///
+    /// The order is canceled first and only then read back, so fills that
+    /// race the cancel are reflected in the replacement size and the
+    /// replacement never doubles the exposure. The replacement is posted
+    /// only if the cancel response lists the order or the order has reached
+    /// a final status (matched, canceled or unmatched); an order that is
+    /// live, delayed or in an unknown state is left alone.
+    ///
+    /// Errors are returned only when nothing was changed or the state of the
+    /// original is unknown (the cancel or the read-back failed); failures
+    /// after the cancel are reported as [`AmendOutcome::ReplaceFailed`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::client::{AmendOutcome, TradingClient};
+    /// # use polymarket_rs::{dec, CreateOrderOptions, OrderId, OrderType};
+    /// # async fn example(client: &TradingClient, order_id: OrderId) -> polymarket_rs::Result<()> {
+    /// let options = CreateOrderOptions::new().tick_size(dec!(0.01)).neg_risk(false);
+    /// match client.amend_order(&order_id, dec!(0.52), dec!(100), options, OrderType::Gtc).await? {
+    ///     AmendOutcome::Replaced { size, .. } => println!("requoted {} shares", size),
+    ///     AmendOutcome::Filled { .. } => println!("filled before the amend"),
+    ///     other => println!("amend failed: {:?}", other),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn amend_order(
+        &self,
+        order_id: &OrderId,
+        price: Decimal,
+        size: Decimal,
+        options: CreateOrderOptions,
+        order_type: OrderType,
+    ) -> Result<AmendOutcome> {
+        let cancel = self.cancel(order_id).await?;
+        let canceled = cancel.canceled.contains(order_id);
+
+        // Read back after the cancel so no more fills can arrive
+        let original = self.get_order(order_id).await?;
+        if !canceled && !is_final(&original.status) {
+            let reason = cancel
+                .not_canceled
+                .get(order_id.as_str())
+                .and_then(|reason| reason.as_str())
+                .unwrap_or("not canceled")
+                .to_string();
+            return Ok(AmendOutcome::CancelRejected { original, reason });
+        }
+
+        let remaining = size - original.size_matched;
+        if remaining <= Decimal::ZERO {
+            return Ok(AmendOutcome::Filled { original });
+        }
+
+        let args = OrderArgs::new(original.asset_id.clone(), price, remaining, original.side);
+        let result = match self.create_order(&args, None, None, options) {
+            Ok(order) => self.post_order(order, order_type).await,
+            Err(e) => Err(e),
+        };
+        Ok(match result {
+            Ok(replacement) => AmendOutcome::Replaced {
+                original,
+                replacement,
+                size: remaining,
+            },
+            Err(error) => AmendOutcome::ReplaceFailed {
+                original,
+                size: remaining,
+                error,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::MockHttpServer;
+    use rust_decimal_macros::dec;
+
+    const ORDER_ID: &str = "0x3c1a1b2e5a0d9d7f2b8f4f1e0c6a2b9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b";
+
+    fn order(status: &str, size_matched: &str) -> String {
+        serde_json::json!({
+            "id": ORDER_ID,
+            "associate_trades": [],
+            "status": status,
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "original_size": "100",
+            "outcome": "Yes",
+            "maker_address": "0x00",
+            "owner": "key",
+            "price": "0.5",
+            "side": "BUY",
+            "size_matched": size_matched,
+            "asset_id": "123",
+            "expiration": "0",
+            "order_type": "GTC",
+            "created_at": 1700000000
+        })
+        .to_string()
+    }
+
+    fn options() -> CreateOrderOptions {
+        CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false)
+    }
+
+    #[tokio::test]
+    async fn test_replacement_shrinks_by_racing_fills() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        let path = format!("/data/order/{}", ORDER_ID);
+        server.mock("GET", &path, 200, order("CANCELED", "30"));
+        let client = server.trading_client();
+        let order_id = OrderId::new(ORDER_ID);
+
+        let outcome = client
+            .amend_order(&order_id, dec!(0.52), dec!(80), options(), OrderType::Gtc)
+            .await
+            .unwrap();
+        match &outcome {
+            AmendOutcome::Replaced { size, .. } => assert_eq!(*size, dec!(50)),
+            other => panic!("unexpected outcome {:?}", other),
+        }
+        assert!(outcome.replacement_id().is_some());
+
+        let methods: Vec<_> = server
+            .requests()
+            .iter()
+            .map(|request| request.method.clone())
+            .collect();
+        assert_eq!(methods, ["DELETE", "GET", "POST"]);
+    }
+
+    #[tokio::test]
+    async fn test_no_replacement_when_filled_or_still_open() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        let path = format!("/data/order/{}", ORDER_ID);
+        let client = server.trading_client();
+        let order_id = OrderId::new(ORDER_ID);
+
+        server.mock(
+            "DELETE",
+            "/order",
+            200,
+            format!(
+                r#"{{"canceled":[],"not_canceled":{{"{}":"order matched"}}}}"#,
+                ORDER_ID
+            ),
+        );
+        server.mock("GET", &path, 200, order("MATCHED", "100"));
+        let outcome = client
+            .amend_order(&order_id, dec!(0.52), dec!(80), options(), OrderType::Gtc)
+            .await
+            .unwrap();
+        assert!(matches!(outcome, AmendOutcome::Filled { .. }));
+
+        server.mock("GET", &path, 200, order("LIVE", "10"));
+        let outcome = client
+            .amend_order(&order_id, dec!(0.52), dec!(80), options(), OrderType::Gtc)
+            .await
+            .unwrap();
+        match outcome {
+            AmendOutcome::CancelRejected { reason, .. } => assert_eq!(reason, "order matched"),
+            other => panic!("unexpected outcome {:?}", other),
+        }
+
+        // A delayed order may still be matched, so it counts as open too
+        server.mock("GET", &path, 200, order("DELAYED", "10"));
+        let outcome = client
+            .amend_order(&order_id, dec!(0.52), dec!(80), options(), OrderType::Gtc)
+            .await
+            .unwrap();
+        assert!(matches!(outcome, AmendOutcome::CancelRejected { .. }));
+
+        // Filled past the amended size but still resting: the rest is live
+        server.mock("GET", &path, 200, order("LIVE", "30"));
+        let outcome = client
+            .amend_order(&order_id, dec!(0.52), dec!(20), options(), OrderType::Gtc)
+            .await
+            .unwrap();
+        assert!(matches!(outcome, AmendOutcome::CancelRejected { .. }));
+
+        assert!(!server
+            .requests()
+            .iter()
+            .any(|request| request.method == "POST"));
+
+        // Either spelling of a confirmed cancel allows the replacement
+        server.mock("GET", &path, 200, order("CANCELLED", "10"));
+        let outcome = client
+            .amend_order(&order_id, dec!(0.52), dec!(20), options(), OrderType::Gtc)
+            .await
+            .unwrap();
+        assert!(matches!(outcome, AmendOutcome::Replaced { .. }));
+    }
+}
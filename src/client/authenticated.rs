@@ -1,8 +1,34 @@
+use crate::config::Environment;
 use crate::error::{Error, Result};
 use crate::http::{create_l1_headers, create_l2_headers, HttpClient};
 use crate::signing::EthSigner;
 use crate::types::{ApiCreds, ApiKeysResponse, BalanceAllowanceParams};
 use alloy_primitives::{Address, U256};
+use alloy_signer_local::PrivateKeySigner;
+use std::str::FromStr;
+
+/// Environment variable holding the hex-encoded private key of the signer
+pub const ENV_PRIVATE_KEY: &str = "POLYMARKET_PRIVATE_KEY";
+/// Environment variable holding the path of an encrypted JSON keystore (`keystore` feature)
+#[cfg(feature = "keystore")]
+pub const ENV_KEYSTORE: &str = "POLYMARKET_KEYSTORE";
+/// Environment variable holding the password of the keystore
+#[cfg(feature = "keystore")]
+pub const ENV_KEYSTORE_PASSWORD: &str = "POLYMARKET_KEYSTORE_PASSWORD";
+/// Environment variable holding the path of a JSON or TOML API credentials file
+pub const ENV_CREDS_FILE: &str = "POLYMARKET_CREDS_FILE";
+/// Environment variable holding the L2 API key
+pub const ENV_API_KEY: &str = "POLYMARKET_API_KEY";
+/// Environment variable holding the L2 API secret
+pub const ENV_API_SECRET: &str = "POLYMARKET_API_SECRET";
+/// Environment variable holding the L2 API passphrase
+pub const ENV_API_PASSPHRASE: &str = "POLYMARKET_API_PASSPHRASE";
+/// Environment variable holding the chain ID (defaults to 137)
+pub const ENV_CHAIN_ID: &str = "POLYMARKET_CHAIN_ID";
+/// Environment variable holding the CLOB base URL (defaults to the chain's environment)
+pub const ENV_HOST: &str = "POLYMARKET_HOST";
+/// Environment variable holding the funder (proxy wallet) address
+pub const ENV_FUNDER: &str = "POLYMARKET_FUNDER";
 
 /// Client for authenticated operations
 ///
@@ -20,6 +46,38 @@ pub struct AuthenticatedClient {
 }
 
 impl AuthenticatedClient {
+    /// Start building an AuthenticatedClient
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polymarket_rs::{AuthenticatedClient, Environment, PrivateKeySigner};
+    /// let client = AuthenticatedClient::builder()
+    ///     .with_environment(Environment::Amoy)
+    ///     .with_signer(PrivateKeySigner::random())
+    ///     .build()?;
+    /// assert!(client.api_creds().is_none());
+    /// # Ok::<(), polymarket_rs::Error>(())
+    /// ```
+    pub fn builder() -> AuthenticatedClientBuilder {
+        AuthenticatedClientBuilder::default()
+    }
+
+    /// Create an AuthenticatedClient from environment variables
+    ///
+    /// Reads:
+    /// - `POLYMARKET_PRIVATE_KEY`, or with the `keystore` feature
+    ///   `POLYMARKET_KEYSTORE` and `POLYMARKET_KEYSTORE_PASSWORD` (required)
+    /// - `POLYMARKET_API_KEY`, `POLYMARKET_API_SECRET` and
+    ///   `POLYMARKET_API_PASSPHRASE` (optional, all three or none), or
+    ///   `POLYMARKET_CREDS_FILE` pointing at a credentials file
+    /// - `POLYMARKET_CHAIN_ID` (optional, defaults to 137)
+    /// - `POLYMARKET_HOST` (optional, defaults to the CLOB URL of the chain)
+    /// - `POLYMARKET_FUNDER` (optional, the proxy wallet address)
+    pub fn from_env() -> Result<Self> {
+        AuthenticatedClientBuilder::from_lookup(|name| std::env::var(name).ok())?.build()
+    }
+
     /// Create a new AuthenticatedClient
     ///
     /// # Arguments
@@ -51,6 +109,27 @@ impl AuthenticatedClient {
         }
     }
 
+    /// Send requests through `client`, sharing its connection pool
+    ///
+    /// See [`HttpConfig`](crate::config::HttpConfig) for tuning the pool.
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = self.http_client.with_client(client);
+        self
+    }
+
+    /// Retry failed `GET` and `DELETE` requests according to `policy`
+    ///
+    /// See [`RetryPolicy`](crate::config::RetryPolicy).
+    pub fn with_retry_policy(mut self, policy: crate::config::RetryPolicy) -> Self {
+        self.http_client = self.http_client.with_retry_policy(policy);
+        self
+    }
+
+    /// The underlying `reqwest::Client`, to share its pool with other clients
+    pub fn http_client(&self) -> &reqwest::Client {
+        self.http_client.client()
+    }
+
     /// Get the API credentials if available
     ///
     /// Returns a reference to the API credentials if they were provided when creating
@@ -282,3 +361,221 @@ impl AuthenticatedClient {
         self.funder
     }
 }
+
+/// Builder for [`AuthenticatedClient`]
+///
+/// Only the signer is required; the host and chain ID default to mainnet.
+#[derive(Default)]
+pub struct AuthenticatedClientBuilder {
+    host: Option<String>,
+    signer: Option<Box<dyn EthSigner>>,
+    chain_id: Option<u64>,
+    api_creds: Option<ApiCreds>,
+    funder: Option<Address>,
+    http_client: Option<reqwest::Client>,
+    retry_policy: Option<crate::config::RetryPolicy>,
+}
+
+impl AuthenticatedClientBuilder {
+    /// Set the CLOB base URL
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.host = Some(host.into());
+        self
+    }
+
+    /// Set the signer used for API authentication
+    pub fn with_signer(mut self, signer: impl EthSigner + 'static) -> Self {
+        self.signer = Some(Box::new(signer));
+        self
+    }
+
+    /// Set the chain ID (137 for Polygon, 80002 for Amoy testnet)
+    pub fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Set the host and chain ID of a deployment
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        self.host = Some(environment.clob_url().to_string());
+        self.chain_id = Some(environment.chain_id());
+        self
+    }
+
+    /// Set the API credentials for L2 operations
+    pub fn with_api_creds(mut self, api_creds: ApiCreds) -> Self {
+        self.api_creds = Some(api_creds);
+        self
+    }
+
+    /// Set the funder address (the proxy wallet for PolyProxy wallets)
+    pub fn with_funder(mut self, funder: Address) -> Self {
+        self.funder = Some(funder);
+        self
+    }
+
+    /// Send requests through `client`, sharing its connection pool
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Retry failed `GET` and `DELETE` requests according to `policy`
+    pub fn with_retry_policy(mut self, policy: crate::config::RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Build the client
+    ///
+    /// Fails if no signer was set. Without an explicit host, the CLOB URL of
+    /// the chain's [`Environment`] is used.
+    pub fn build(self) -> Result<AuthenticatedClient> {
+        let signer = self
+            .signer
+            .ok_or_else(|| Error::Config("AuthenticatedClient requires a signer".to_string()))?;
+        let chain_id = self.chain_id.unwrap_or(Environment::Mainnet.chain_id());
+        let host = match self.host {
+            Some(host) => host,
+            None => Environment::from_chain_id(chain_id)
+                .ok_or_else(|| {
+                    Error::Config(format!("No default host for chain {}; set one", chain_id))
+                })?
+                .clob_url()
+                .to_string(),
+        };
+
+        let mut http_client = HttpClient::new(host);
+        if let Some(client) = self.http_client {
+            http_client = http_client.with_client(client);
+        }
+        if let Some(policy) = self.retry_policy {
+            http_client = http_client.with_retry_policy(policy);
+        }
+
+        Ok(AuthenticatedClient {
+            http_client,
+            signer,
+            chain_id,
+            api_creds: self.api_creds,
+            funder: self.funder,
+        })
+    }
+
+    /// Populate a builder from the `POLYMARKET_*` variables returned by `lookup`
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let signer = Self::signer_from_lookup(&lookup)?;
+        let mut builder = Self::default().with_signer(signer);
+
+        match (
+            lookup(ENV_API_KEY),
+            lookup(ENV_API_SECRET),
+            lookup(ENV_API_PASSPHRASE),
+        ) {
+            (Some(key), Some(secret), Some(passphrase)) => {
+                builder = builder.with_api_creds(ApiCreds::new(key, secret, passphrase));
+            }
+            (None, None, None) => {
+                if let Some(path) = lookup(ENV_CREDS_FILE) {
+                    builder = builder.with_api_creds(ApiCreds::from_file(path)?);
+                }
+            }
+            _ => {
+                return Err(Error::Config(format!(
+                    "{}, {} and {} must be set together",
+                    ENV_API_KEY, ENV_API_SECRET, ENV_API_PASSPHRASE
+                )))
+            }
+        }
+
+        if let Some(chain_id) = lookup(ENV_CHAIN_ID) {
+            let chain_id = chain_id
+                .trim()
+                .parse()
+                .map_err(|e| Error::Config(format!("Invalid {}: {}", ENV_CHAIN_ID, e)))?;
+            builder = builder.with_chain_id(chain_id);
+        }
+        if let Some(host) = lookup(ENV_HOST) {
+            builder = builder.with_host(host);
+        }
+        if let Some(funder) = lookup(ENV_FUNDER) {
+            let funder = Address::from_str(funder.trim())
+                .map_err(|e| Error::Config(format!("Invalid {}: {}", ENV_FUNDER, e)))?;
+            builder = builder.with_funder(funder);
+        }
+
+        Ok(builder)
+    }
+
+    /// Load the signer from a raw private key or, if enabled, an encrypted keystore
+    fn signer_from_lookup(lookup: &impl Fn(&str) -> Option<String>) -> Result<PrivateKeySigner> {
+        #[cfg(feature = "keystore")]
+        if let Some(path) = lookup(ENV_KEYSTORE) {
+            let password = lookup(ENV_KEYSTORE_PASSWORD)
+                .ok_or_else(|| Error::Config(format!("{} is not set", ENV_KEYSTORE_PASSWORD)))?;
+            return crate::signing::load_keystore(path, password);
+        }
+
+        let private_key = lookup(ENV_PRIVATE_KEY)
+            .ok_or_else(|| Error::Config(format!("{} is not set", ENV_PRIVATE_KEY)))?;
+        PrivateKeySigner::from_str(private_key.trim())
+            .map_err(|e| Error::Config(format!("Invalid {}: {}", ENV_PRIVATE_KEY, e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        assert!(AuthenticatedClient::builder().build().is_err());
+
+        let client = AuthenticatedClient::builder()
+            .with_signer(PrivateKeySigner::random())
+            .build()
+            .unwrap();
+        assert_eq!(client.chain_id, 137);
+
+        let client = AuthenticatedClient::builder()
+            .with_signer(PrivateKeySigner::random())
+            .with_chain_id(1)
+            .build();
+        assert!(client.is_err());
+    }
+
+    #[test]
+    fn test_from_env_vars() {
+        let builder = AuthenticatedClientBuilder::from_lookup(lookup(&[
+            (ENV_PRIVATE_KEY, KEY),
+            (ENV_API_KEY, "key"),
+            (ENV_API_SECRET, "secret"),
+            (ENV_API_PASSPHRASE, "pass"),
+            (ENV_CHAIN_ID, "80002"),
+            (ENV_FUNDER, "0xD216153c06E857cD7f72665E0aF1d7D82172F494"),
+        ]))
+        .unwrap();
+        let client = builder.build().unwrap();
+        assert_eq!(client.chain_id, 80002);
+        assert_eq!(client.api_creds().unwrap().passphrase, "pass");
+        assert!(client.get_funder().is_some());
+
+        assert!(AuthenticatedClientBuilder::from_lookup(lookup(&[])).is_err());
+        let partial_creds = AuthenticatedClientBuilder::from_lookup(lookup(&[
+            (ENV_PRIVATE_KEY, KEY),
+            (ENV_API_KEY, "key"),
+        ]));
+        assert!(partial_creds.is_err());
+    }
+}
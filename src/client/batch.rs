@@ -0,0 +1,199 @@
+use crate::error::{Error, Result};
+use futures_util::stream::{self, StreamExt};
+use std::future::Future;
+
+/// Send `items` in chunks of at most `chunk_size`, with at most `concurrency` in flight
+///
+/// Returns every chunk with its result, in input order. A failing chunk
+/// doesn't stop the others, so callers can report what was done alongside
+/// what failed; an empty input sends no requests.
+pub(crate) async fn chunked<'a, I, T, F, Fut>(
+    items: &'a [I],
+    chunk_size: usize,
+    concurrency: usize,
+    mut request: F,
+) -> Vec<(&'a [I], Result<T>)>
+where
+    F: FnMut(&'a [I]) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    stream::iter(items.chunks(chunk_size))
+        .map(|chunk| {
+            let response = request(chunk);
+            async move { (chunk, response.await) }
+        })
+        .buffered(concurrency)
+        .collect()
+        .await
+}
+
+/// Concatenate the chunks' results, failing with the first error
+///
+/// For reads, which change nothing and can simply be retried.
+pub(crate) fn all_chunks<I, T>(chunks: Vec<(&[I], Result<Vec<T>>)>) -> Result<Vec<T>> {
+    let mut results = Vec::new();
+    for (_, result) in chunks {
+        results.extend(result?);
+    }
+    Ok(results)
+}
+
+/// Concatenate the chunks' results, with `failed` standing in for each item
+/// of a failed chunk
+///
+/// For writes, where earlier chunks may have changed state: an error is
+/// returned only when every chunk failed, as nothing was done then.
+pub(crate) fn partial_chunks<I, T>(
+    chunks: Vec<(&[I], Result<Vec<T>>)>,
+    failed: impl Fn(&I, &Error) -> T,
+) -> Result<Vec<T>> {
+    if chunks.iter().all(|(_, result)| result.is_err()) {
+        if let Some((_, Err(e))) = chunks.into_iter().next() {
+            return Err(e);
+        }
+        return Ok(Vec::new());
+    }
+
+    let mut results = Vec::new();
+    for (chunk, result) in chunks {
+        match result {
+            Ok(done) => results.extend(done),
+            Err(e) => results.extend(chunk.iter().map(|item| failed(item, &e))),
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClobClient;
+    use crate::config::BatchConfig;
+    use crate::test_util::{fixtures, MockHttpServer};
+    use crate::types::{BookParams, CancelOrdersResponse, OrderId, Side};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_chunked_preserves_order_and_caps_concurrency() {
+        let items: Vec<u32> = (0..10).collect();
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        let chunks = chunked(&items, 3, 2, |chunk| {
+            let in_flight = &in_flight;
+            let peak = &peak;
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                // Later chunks finish first to check the output order
+                tokio::time::sleep(Duration::from_millis(20 - chunk[0] as u64)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(chunk.iter().map(|i| i * 10).collect())
+            }
+        })
+        .await;
+
+        assert_eq!(
+            all_chunks(chunks).unwrap(),
+            (0..10).map(|i| i * 10).collect::<Vec<_>>()
+        );
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_chunk_keeps_the_others() {
+        let items = [1, 2, 3, 4, 5];
+        let send = |chunk: &[i32]| {
+            let chunk = chunk.to_vec();
+            async move {
+                if chunk.contains(&3) {
+                    Err(Error::InvalidParameter("bad chunk".to_string()))
+                } else {
+                    Ok(chunk)
+                }
+            }
+        };
+
+        let chunks = chunked(&items, 2, 4, send).await;
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks[1].1.is_err());
+        assert_eq!(
+            partial_chunks(chunks, |item, _| -item).unwrap(),
+            [1, 2, -3, -4, 5]
+        );
+
+        assert!(all_chunks(chunked(&items, 2, 4, send).await).is_err());
+        assert!(partial_chunks(chunked(&items[2..4], 2, 4, send).await, |i, _| *i).is_err());
+
+        let empty = chunked(&[] as &[i32], 2, 4, send).await;
+        assert!(partial_chunks(empty, |i, _| *i).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_reports_orders_of_a_failed_request() {
+        let server = MockHttpServer::start().await.unwrap();
+        server.mock("DELETE", "/orders", 500, r#"{"error": "overloaded"}"#);
+        server.mock_once(
+            "DELETE",
+            "/orders",
+            200,
+            r#"{"canceled": ["0x1"], "not_canceled": {"0x2": "matched"}}"#,
+        );
+        let client = server.trading_client().with_batch_config(
+            BatchConfig::default()
+                .with_max_cancels(2)
+                .with_max_concurrency(1),
+        );
+
+        let ids: Vec<OrderId> = ["0x1", "0x2", "0x3"].map(OrderId::new).to_vec();
+        let response = client.cancel_orders(&ids).await.unwrap();
+        assert_eq!(response.canceled, [OrderId::new("0x1")]);
+        assert_eq!(response.not_canceled["0x2"], "matched");
+        assert!(response.not_canceled["0x3"]
+            .as_str()
+            .unwrap()
+            .contains("overloaded"));
+
+        // With no request through, the error itself is returned
+        assert!(client.cancel_orders(&ids[2..]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_order_books_are_split_into_chunks() {
+        let server = MockHttpServer::start().await.unwrap();
+        server.mock("POST", "/books", 200, format!("[{}]", fixtures::ORDER_BOOK));
+        let client = ClobClient::new(server.url())
+            .with_batch_config(BatchConfig::default().with_max_tokens(2));
+
+        let params: Vec<_> = (0..5)
+            .map(|i| BookParams::new(i.to_string(), Side::Buy))
+            .collect();
+        let books = client.get_order_books(&params).await.unwrap();
+        assert_eq!(books.len(), 3);
+
+        let mut sizes: Vec<usize> = server
+            .requests()
+            .iter()
+            .map(|r| r.json::<Vec<serde_json::Value>>().unwrap().len())
+            .collect();
+        sizes.sort();
+        assert_eq!(sizes, [1, 2, 2]);
+    }
+
+    #[test]
+    fn test_cancel_responses_merge() {
+        let parse = |json: &str| serde_json::from_str::<CancelOrdersResponse>(json).unwrap();
+        let merged = CancelOrdersResponse::merge([
+            parse(r#"{"canceled": ["0x1"], "not_canceled": {"0x2": "not found"}}"#),
+            parse(r#"{"canceled": ["0x3"], "not_canceled": {}}"#),
+            parse(r#"{"canceled": [], "not_canceled": {"0x4": "matched"}}"#),
+            parse(r#"{"canceled": [], "not_canceled": null}"#),
+            parse(r#"{"canceled": [], "not_canceled": []}"#),
+        ]);
+
+        assert_eq!(merged.canceled, [OrderId::new("0x1"), OrderId::new("0x3")]);
+        assert_eq!(merged.not_canceled["0x2"], "not found");
+        assert_eq!(merged.not_canceled["0x4"], "matched");
+    }
+}
@@ -1,11 +1,17 @@
+use super::batch::{all_chunks, chunked};
+use super::paginated::Paginated;
+use crate::config::BatchConfig;
 use crate::error::Result;
 use crate::http::HttpClient;
-use crate::request::PaginationParams;
+use crate::request::{encode_query_value, PaginationParams};
+#[cfg(feature = "raw")]
+use crate::types::Raw;
 use crate::types::{
     BookParams, ConditionId, Market, MarketsResponse, MidpointResponse, NegRiskResponse,
-    OrderBookSummary, PriceHistoryResponse, PriceResponse, SimplifiedMarketsResponse,
-    SpreadResponse, TickSizeResponse, TokenId,
+    OrderBookSummary, Page, PriceHistoryResponse, PriceResponse, SimplifiedMarket,
+    SimplifiedMarketsResponse, SpreadResponse, TickSizeResponse, TokenId,
 };
+use crate::websocket::CandleAggregator;
 use crate::Side;
 
 /// Client for CLOB (Central Limit Order Book) market data APIs
@@ -14,6 +20,7 @@ use crate::Side;
 /// without requiring authentication.
 pub struct ClobClient {
     http_client: HttpClient,
+    batch_config: BatchConfig,
 }
 
 impl ClobClient {
@@ -24,9 +31,37 @@ impl ClobClient {
     pub fn new(host: impl Into<String>) -> Self {
         Self {
             http_client: HttpClient::new(host),
+            batch_config: BatchConfig::default(),
         }
     }
 
+    /// Set the chunk sizes and parallelism of batch requests
+    pub fn with_batch_config(mut self, batch_config: BatchConfig) -> Self {
+        self.batch_config = batch_config;
+        self
+    }
+
+    /// Send requests through `client`, sharing its connection pool
+    ///
+    /// See [`HttpConfig`](crate::config::HttpConfig) for tuning the pool.
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = self.http_client.with_client(client);
+        self
+    }
+
+    /// Retry failed `GET` and `DELETE` requests according to `policy`
+    ///
+    /// See [`RetryPolicy`](crate::config::RetryPolicy).
+    pub fn with_retry_policy(mut self, policy: crate::config::RetryPolicy) -> Self {
+        self.http_client = self.http_client.with_retry_policy(policy);
+        self
+    }
+
+    /// The underlying `reqwest::Client`, to share its pool with other clients
+    pub fn http_client(&self) -> &reqwest::Client {
+        self.http_client.client()
+    }
+
     /// Check if the server is responsive
     pub async fn get_ok(&self) -> Result<serde_json::Value> {
         self.http_client.get("/", None).await
@@ -51,10 +86,7 @@ impl ClobClient {
     /// # Arguments
     /// * `token_ids` - List of token IDs to query
     pub async fn get_midpoints(&self, token_ids: &[TokenId]) -> Result<Vec<MidpointResponse>> {
-        let ids: Vec<&str> = token_ids.iter().map(|id| id.as_str()).collect();
-        self.http_client
-            .post("/midpoints", &serde_json::json!({ "token_ids": ids }), None)
-            .await
+        self.post_token_batch("/midpoints", token_ids).await
     }
 
     /// Get the current price for a token
@@ -70,10 +102,7 @@ impl ClobClient {
 
     /// Get prices for multiple tokens
     pub async fn get_prices(&self, token_ids: &[TokenId]) -> Result<Vec<PriceResponse>> {
-        let ids: Vec<&str> = token_ids.iter().map(|id| id.as_str()).collect();
-        self.http_client
-            .post("/prices", &serde_json::json!({ "token_ids": ids }), None)
-            .await
+        self.post_token_batch("/prices", token_ids).await
     }
 
     /// Get price history for a token
@@ -104,6 +133,25 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Seed a token's candles with its price history over `interval`
+    ///
+    /// Requests history at the aggregator's candle length (at least a
+    /// minute) and merges it with any candles already built from the live
+    /// stream. Returns the number of history points received.
+    pub async fn backfill_candles(
+        &self,
+        candles: &mut CandleAggregator,
+        token_id: &TokenId,
+        interval: &str,
+    ) -> Result<usize> {
+        let fidelity = (candles.interval().as_secs() / 60).max(1);
+        let response = self
+            .get_prices_history(token_id, interval, None, None, Some(fidelity))
+            .await?;
+        candles.backfill(token_id, &response.history);
+        Ok(response.history.len())
+    }
+
     /// Get the bid/ask spread for a token
     pub async fn get_spread(&self, token_id: &TokenId) -> Result<SpreadResponse> {
         let path = format!("/spread?token_id={}", token_id.as_str());
@@ -112,10 +160,7 @@ impl ClobClient {
 
     /// Get spreads for multiple tokens
     pub async fn get_spreads(&self, token_ids: &[TokenId]) -> Result<Vec<SpreadResponse>> {
-        let ids: Vec<&str> = token_ids.iter().map(|id| id.as_str()).collect();
-        self.http_client
-            .post("/spreads", &serde_json::json!({ "token_ids": ids }), None)
-            .await
+        self.post_token_batch("/spreads", token_ids).await
     }
 
     /// Get the minimum tick size for a token
@@ -139,9 +184,48 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get the order book for a token, keeping the response's JSON; see [`Raw`]
+    #[cfg(feature = "raw")]
+    pub async fn get_order_book_raw(&self, token_id: &TokenId) -> Result<Raw<OrderBookSummary>> {
+        let path = format!("/book?token_id={}", token_id.as_str());
+        self.http_client.get(&path, None).await
+    }
+
     /// Get order books for multiple tokens
+    ///
+    /// Any number of tokens may be requested; see [`BatchConfig`] for how
+    /// large inputs are split.
     pub async fn get_order_books(&self, params: &[BookParams]) -> Result<Vec<OrderBookSummary>> {
-        self.http_client.post("/books", &params, None).await
+        let config = self.batch_config;
+        let chunks = chunked(
+            params,
+            config.max_tokens(),
+            config.max_concurrency(),
+            |chunk| async move { self.http_client.post("/books", &chunk, None).await },
+        )
+        .await;
+        all_chunks(chunks)
+    }
+
+    /// POST token IDs to a batch endpoint, splitting them per [`BatchConfig`]
+    async fn post_token_batch<T>(&self, path: &str, token_ids: &[TokenId]) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let config = self.batch_config;
+        let chunks = chunked(
+            token_ids,
+            config.max_tokens(),
+            config.max_concurrency(),
+            |chunk| async move {
+                let ids: Vec<&str> = chunk.iter().map(|id| id.as_str()).collect();
+                self.http_client
+                    .post(path, &serde_json::json!({ "token_ids": ids }), None)
+                    .await
+            },
+        )
+        .await;
+        all_chunks(chunks)
     }
 
     /// Get the last trade price for a token
@@ -170,15 +254,8 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<MarketsResponse> {
-        let mut path = "/sampling-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
-        self.http_client.get(&path, None).await
+        self.get_page("/sampling-markets", pagination.and_then(|p| p.next_cursor))
+            .await
     }
 
     /// Get sampling simplified markets with pagination
@@ -189,15 +266,11 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<SimplifiedMarketsResponse> {
-        let mut path = "/sampling-simplified-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
-        self.http_client.get(&path, None).await
+        self.get_page(
+            "/sampling-simplified-markets",
+            pagination.and_then(|p| p.next_cursor),
+        )
+        .await
     }
 
     /// Get markets with pagination
@@ -208,15 +281,8 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<MarketsResponse> {
-        let mut path = "/markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
-        self.http_client.get(&path, None).await
+        self.get_page("/markets", pagination.and_then(|p| p.next_cursor))
+            .await
     }
 
     /// Get simplified markets with pagination
@@ -224,15 +290,45 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<SimplifiedMarketsResponse> {
-        let mut path = "/simplified-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
+        self.get_page(
+            "/simplified-markets",
+            pagination.and_then(|p| p.next_cursor),
+        )
+        .await
+    }
+
+    /// Walk every page of markets
+    pub fn paginate_markets(&self) -> Paginated<'_, Market> {
+        Paginated::new(move |cursor| self.get_page("/markets", cursor))
+    }
+
+    /// Walk every page of simplified markets
+    pub fn paginate_simplified_markets(&self) -> Paginated<'_, SimplifiedMarket> {
+        Paginated::new(move |cursor| self.get_page("/simplified-markets", cursor))
+    }
+
+    /// Walk every page of sampling (reward-eligible) markets
+    pub fn paginate_sampling_markets(&self) -> Paginated<'_, Market> {
+        Paginated::new(move |cursor| self.get_page("/sampling-markets", cursor))
+    }
+
+    /// Walk every page of sampling simplified markets
+    pub fn paginate_sampling_simplified_markets(&self) -> Paginated<'_, SimplifiedMarket> {
+        Paginated::new(move |cursor| self.get_page("/sampling-simplified-markets", cursor))
+    }
+
+    /// Get one page of a cursor-paginated endpoint
+    async fn get_page<T>(&self, path: &str, cursor: Option<String>) -> Result<Page<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        match cursor {
+            Some(cursor) => {
+                let path = format!("{}?next_cursor={}", path, encode_query_value(&cursor));
+                self.http_client.get(&path, None).await
             }
+            None => self.http_client.get(path, None).await,
         }
-        self.http_client.get(&path, None).await
     }
 
     /// Get a specific market by condition ID
@@ -241,6 +337,13 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get a specific market by condition ID, keeping the response's JSON; see [`Raw`]
+    #[cfg(feature = "raw")]
+    pub async fn get_market_raw(&self, condition_id: &ConditionId) -> Result<Raw<Market>> {
+        let path = format!("/markets/{}", condition_id.as_str());
+        self.http_client.get(&path, None).await
+    }
+
     /// Get a specific market by slug
     pub async fn get_market_by_slug(&self, market_slug: &str) -> Result<Market> {
         let path = format!("/markets/slug/{}", market_slug);
@@ -1,7 +1,48 @@
+use super::paginated::Paginated;
 use crate::error::Result;
 use crate::http::HttpClient;
-use crate::request::{ActivityQueryParams, TradeQueryParams};
-use crate::types::{Activity, ClosedPosition, Position, PositionValue, Trade};
+use crate::request::{
+    encode_query_value, ActivityQueryParams, LeaderboardParams, PositionQueryParams,
+    TradeQueryParams,
+};
+#[cfg(feature = "raw")]
+use crate::types::Raw;
+use crate::types::{
+    Activity, ClosedPosition, ConditionId, LeaderboardEntry, MarketHolders, Page, Position,
+    PositionValue, Trade,
+};
+
+/// Page size used when walking every page of a paginated data API endpoint
+const DEFAULT_PAGE_SIZE: u32 = 500;
+
+/// Offset of the page at `cursor`, or `start` for the first page
+fn offset_cursor(cursor: Option<String>, start: u32) -> u32 {
+    cursor.and_then(|c| c.parse().ok()).unwrap_or(start)
+}
+
+/// Path of a per-user endpoint, with any query parameters appended
+fn user_path(endpoint: &str, user: &str, params: Option<String>) -> String {
+    let mut path = format!("{}?user={}", endpoint, encode_query_value(user));
+    if let Some(params) = params {
+        path.push_str(&params);
+    }
+    path
+}
+
+/// Wrap an offset-paginated response as a [`Page`] whose cursor is the next offset
+///
+/// Only an empty page is the last one: the server may cap pages below the
+/// requested size, so a short page doesn't mean the results ran out.
+fn offset_page<T>(data: Vec<T>, page_size: u32, offset: u32) -> Page<T> {
+    let count = data.len() as u32;
+    let next_cursor = (count > 0).then(|| (offset + count).to_string());
+    Page {
+        limit: page_size as u64,
+        count: count as u64,
+        next_cursor,
+        data,
+    }
+}
 
 /// Client for accessing position and portfolio data
 ///
@@ -22,16 +63,60 @@ impl DataClient {
         }
     }
 
-    /// Get all positions for a user
+    /// Send requests through `client`, sharing its connection pool
+    ///
+    /// See [`HttpConfig`](crate::config::HttpConfig) for tuning the pool.
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = self.http_client.with_client(client);
+        self
+    }
+
+    /// Retry failed `GET` and `DELETE` requests according to `policy`
+    ///
+    /// See [`RetryPolicy`](crate::config::RetryPolicy).
+    pub fn with_retry_policy(mut self, policy: crate::config::RetryPolicy) -> Self {
+        self.http_client = self.http_client.with_retry_policy(policy);
+        self
+    }
+
+    /// The underlying `reqwest::Client`, to share its pool with other clients
+    pub fn http_client(&self) -> &reqwest::Client {
+        self.http_client.client()
+    }
+
+    /// Get positions for a user
+    ///
+    /// Positions are reported by the data API from on-chain state, so they are
+    /// authoritative regardless of any locally tracked fills.
     ///
     /// # Arguments
     /// * `user` - The user's wallet address
+    /// * `params` - Optional filters (markets, size threshold, redeemable, sorting, pagination)
     ///
     /// # Returns
     /// A list of positions owned by the user
-    pub async fn get_positions(&self, user: &str) -> Result<Vec<Position>> {
-        let path = format!("/positions?user={}", user);
-        self.http_client.get(&path, None).await
+    pub async fn get_positions(
+        &self,
+        user: &str,
+        params: Option<PositionQueryParams>,
+    ) -> Result<Vec<Position>> {
+        let params = params.map(|params| params.to_query_string());
+        self.http_client
+            .get(&user_path("/positions", user, params), None)
+            .await
+    }
+
+    /// Get positions for a user, keeping the response's JSON; see [`Raw`]
+    #[cfg(feature = "raw")]
+    pub async fn get_positions_raw(
+        &self,
+        user: &str,
+        params: Option<PositionQueryParams>,
+    ) -> Result<Raw<Vec<Position>>> {
+        let params = params.map(|params| params.to_query_string());
+        self.http_client
+            .get(&user_path("/positions", user, params), None)
+            .await
     }
 
     /// Get the total value of positions for a user
@@ -42,7 +127,7 @@ impl DataClient {
     /// # Returns
     /// A list of position values for the user
     pub async fn get_positions_value(&self, user: &str) -> Result<Vec<PositionValue>> {
-        let path = format!("/value?user={}", user);
+        let path = format!("/value?user={}", encode_query_value(user));
         self.http_client.get(&path, None).await
     }
 
@@ -50,7 +135,7 @@ impl DataClient {
     ///
     /// # Arguments
     /// * `user` - User wallet address to filter trades
-    /// * `params` - Optional query parameters (limit, offset, taker_only)
+    /// * `params` - Optional query parameters (limit, offset, taker_only, market, side)
     ///
     /// # Returns
     /// A list of recent trades
@@ -59,13 +144,48 @@ impl DataClient {
         user: &str,
         params: Option<TradeQueryParams>,
     ) -> Result<Vec<Trade>> {
-        let mut path = format!("/trades?user={}", user);
+        let params = params.map(|params| params.to_query_string());
+        self.http_client
+            .get(&user_path("/trades", user, params), None)
+            .await
+    }
 
-        if let Some(params) = params {
-            path.push_str(&params.to_query_string());
-        }
+    /// Get recent trades for a user, keeping the response's JSON; see [`Raw`]
+    #[cfg(feature = "raw")]
+    pub async fn get_trades_raw(
+        &self,
+        user: &str,
+        params: Option<TradeQueryParams>,
+    ) -> Result<Raw<Vec<Trade>>> {
+        let params = params.map(|params| params.to_query_string());
+        self.http_client
+            .get(&user_path("/trades", user, params), None)
+            .await
+    }
 
-        println!("{}", path);
+    /// Get recent public trades for a market
+    ///
+    /// This is the market-wide trade tape (every taker fill, across all users)
+    /// and does not require authentication. Each trade carries its outcome
+    /// token in `asset`, so a single token's tape can be obtained by filtering
+    /// on that field.
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID of the market
+    /// * `params` - Optional query parameters (limit, offset, taker_only, side);
+    ///   any markets set on them are replaced by `condition_id`
+    ///
+    /// # Returns
+    /// A list of recent trades, newest first
+    pub async fn get_market_trades(
+        &self,
+        condition_id: &ConditionId,
+        params: Option<TradeQueryParams>,
+    ) -> Result<Vec<Trade>> {
+        let mut params = params.unwrap_or_default();
+        params.markets = vec![condition_id.clone()];
+        let query = params.to_query_string();
+        let path = format!("/trades?{}", query.trim_start_matches('&'));
 
         self.http_client.get(&path, None).await
     }
@@ -74,7 +194,7 @@ impl DataClient {
     ///
     /// # Arguments
     /// * `user` - User wallet address to filter activity
-    /// * `params` - Optional query parameters (limit, offset, sort_by, sort_direction)
+    /// * `params` - Optional query parameters (limit, offset, sorting, type, market, side, time range)
     ///
     /// # Returns
     /// A list of recent activity events
@@ -83,7 +203,7 @@ impl DataClient {
         user: &str,
         params: Option<ActivityQueryParams>,
     ) -> Result<Vec<Activity>> {
-        let mut path = format!("/activity?user={}", user);
+        let mut path = format!("/activity?user={}", encode_query_value(user));
 
         if let Some(params) = params {
             path.push_str(&params.to_query_string());
@@ -92,6 +212,95 @@ impl DataClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get a user's full trade history by walking every page
+    ///
+    /// Requests pages of `params.limit` trades (500 if unset), starting at
+    /// `params.offset`, until the API returns an empty page.
+    ///
+    /// # Arguments
+    /// * `user` - User wallet address to filter trades
+    /// * `params` - Optional query parameters; `limit` is used as the page size
+    ///
+    /// # Returns
+    /// Every trade matching the filters
+    pub async fn get_all_trades(
+        &self,
+        user: &str,
+        params: Option<TradeQueryParams>,
+    ) -> Result<Vec<Trade>> {
+        self.paginate_trades(user, params).collect_all().await
+    }
+
+    /// Walk a user's trade history page by page
+    ///
+    /// Pages hold `params.limit` trades (500 if unset), starting at
+    /// `params.offset`; the page cursors are offsets.
+    pub fn paginate_trades(
+        &self,
+        user: &str,
+        params: Option<TradeQueryParams>,
+    ) -> Paginated<'_, Trade> {
+        let user = user.to_string();
+        let params = params.unwrap_or_default();
+        let page_size = params.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        let start = params.offset.unwrap_or(0);
+
+        Paginated::new(move |cursor| {
+            let offset = offset_cursor(cursor, start);
+            let params = params.clone().with_limit(page_size).with_offset(offset);
+            let user = user.clone();
+            async move {
+                let trades = self.get_trades(&user, Some(params)).await?;
+                Ok(offset_page(trades, page_size, offset))
+            }
+        })
+    }
+
+    /// Get a user's full activity history (trades, splits, merges, redemptions, ...)
+    /// by walking every page
+    ///
+    /// Requests pages of `params.limit` events (500 if unset), starting at
+    /// `params.offset`, until the API returns an empty page.
+    ///
+    /// # Arguments
+    /// * `user` - User wallet address to filter activity
+    /// * `params` - Optional query parameters; `limit` is used as the page size
+    ///
+    /// # Returns
+    /// Every activity event matching the filters
+    pub async fn get_all_activity(
+        &self,
+        user: &str,
+        params: Option<ActivityQueryParams>,
+    ) -> Result<Vec<Activity>> {
+        self.paginate_activity(user, params).collect_all().await
+    }
+
+    /// Walk a user's activity history page by page
+    ///
+    /// Pages hold `params.limit` events (500 if unset), starting at
+    /// `params.offset`; the page cursors are offsets.
+    pub fn paginate_activity(
+        &self,
+        user: &str,
+        params: Option<ActivityQueryParams>,
+    ) -> Paginated<'_, Activity> {
+        let user = user.to_string();
+        let params = params.unwrap_or_default();
+        let page_size = params.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        let start = params.offset.unwrap_or(0);
+
+        Paginated::new(move |cursor| {
+            let offset = offset_cursor(cursor, start);
+            let params = params.clone().with_limit(page_size).with_offset(offset);
+            let user = user.clone();
+            async move {
+                let activity = self.get_activity(&user, Some(params)).await?;
+                Ok(offset_page(activity, page_size, offset))
+            }
+        })
+    }
+
     /// Get closed positions
     ///
     /// # Arguments
@@ -100,7 +309,91 @@ impl DataClient {
     /// # Returns
     /// A list of closed positions for the user
     pub async fn get_closed_positions(&self, user: &str) -> Result<Vec<ClosedPosition>> {
-        let path = format!("/closed-positions?user={}", user);
+        let path = format!("/closed-positions?user={}", encode_query_value(user));
         self.http_client.get(&path, None).await
     }
+
+    /// Get the top holders of each outcome in a market
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID of the market
+    /// * `limit` - Optional maximum number of holders returned per outcome
+    ///
+    /// # Returns
+    /// One entry per outcome token, each listing its largest holders
+    pub async fn get_holders(
+        &self,
+        condition_id: &ConditionId,
+        limit: Option<u32>,
+    ) -> Result<Vec<MarketHolders>> {
+        let mut path = format!(
+            "/holders?market={}",
+            encode_query_value(condition_id.as_str())
+        );
+
+        if let Some(limit) = limit {
+            path.push_str(&format!("&limit={}", limit));
+        }
+
+        self.http_client.get(&path, None).await
+    }
+
+    /// Get the trader leaderboard
+    ///
+    /// Ranks traders by profit (`LeaderboardOrderBy::Pnl`) or traded volume
+    /// (`LeaderboardOrderBy::Volume`) over a time window.
+    ///
+    /// # Arguments
+    /// * `params` - Optional query parameters (window, ranking metric, category, pagination)
+    ///
+    /// # Returns
+    /// Leaderboard entries in rank order
+    pub async fn get_leaderboard(
+        &self,
+        params: Option<LeaderboardParams>,
+    ) -> Result<Vec<LeaderboardEntry>> {
+        let mut path = "/v1/leaderboard".to_string();
+
+        if let Some(params) = params {
+            path.push_str(&params.to_query_string());
+        }
+
+        self.http_client.get(&path, None).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::MockHttpServer;
+
+    #[test]
+    fn test_offset_pages_end_only_when_empty() {
+        // A page capped below the requested size still has a successor
+        let page = offset_page(vec![1, 2, 3], 500, 1000);
+        assert_eq!(page.next_cursor.as_deref(), Some("1003"));
+        assert_eq!(offset_cursor(page.next_cursor, 0), 1003);
+
+        let page = offset_page(Vec::<u32>::new(), 500, 1003);
+        assert!(page.next_page_cursor().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_market_trades_send_one_market() {
+        let server = MockHttpServer::start().await.unwrap();
+        server.mock("GET", "/trades", 200, "[]");
+        let client = DataClient::new(server.url());
+
+        let params = TradeQueryParams::new().with_market("0xother").with_limit(5);
+        let trades = client
+            .get_market_trades(&ConditionId::new("0xabc"), Some(params))
+            .await
+            .unwrap();
+
+        assert!(trades.is_empty());
+        assert_eq!(
+            server.requests()[0].query.as_deref(),
+            Some("limit=5&market=0xabc")
+        );
+    }
 }
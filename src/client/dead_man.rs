@@ -0,0 +1,415 @@
+use super::TradingClient;
+use crate::config::RetryPolicy;
+use crate::error::{Error, Result};
+use crate::types::ConditionId;
+use futures_util::stream::{self, Stream, StreamExt};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+/// Cancels resting orders when heartbeats stop
+///
+/// A background task cancels all orders (or only those in the configured
+/// markets) once no heartbeat has arrived for the timeout, or immediately
+/// when a guarded websocket stream disconnects. Cancels that fail are
+/// retried with backoff until they succeed; after that, the switch stays
+/// idle until the next heartbeat re-arms it.
+///
+/// Heartbeats come from [`Heartbeat::beat`], called by the caller's own
+/// event loop, and from every event of a stream wrapped with
+/// [`guard`](Self::guard).
+///
+/// The switch runs inside the process it protects: it catches a stalled
+/// event loop or lost network, but cannot cancel anything once the process
+/// itself is killed.
+///
+/// # Example
+///
+/// ```no_run
+/// # use polymarket_rs::client::{DeadManSwitch, TradingClient};
+/// # use polymarket_rs::{StreamExt, UserWsClient};
+/// # use std::sync::Arc;
+/// # use std::time::Duration;
+/// # async fn example(trading_client: Arc<TradingClient>, creds: polymarket_rs::ApiCreds) -> polymarket_rs::Result<()> {
+/// let switch = DeadManSwitch::builder(Duration::from_secs(15)).spawn(trading_client);
+///
+/// let stream = UserWsClient::new().subscribe_with_creds(&creds).await?;
+/// let mut stream = switch.guard(stream);
+/// while let Some(event) = stream.next().await {
+///     // handle fills and order updates
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct DeadManSwitch {
+    heartbeat: Heartbeat,
+    task: JoinHandle<()>,
+}
+
+/// Configuration of a [`DeadManSwitch`], created with [`DeadManSwitch::builder`]
+#[derive(Debug, Clone)]
+pub struct DeadManSwitchBuilder {
+    timeout: Duration,
+    markets: Vec<ConditionId>,
+    retry_policy: RetryPolicy,
+}
+
+impl DeadManSwitchBuilder {
+    /// Only cancel orders in these markets instead of every open order
+    pub fn with_markets(mut self, markets: impl IntoIterator<Item = ConditionId>) -> Self {
+        self.markets = markets.into_iter().collect();
+        self
+    }
+
+    /// Wait between attempts to cancel according to `policy`'s backoff
+    ///
+    /// Only the delays are used: a failed cancel is retried until it
+    /// succeeds, whatever the error. Defaults to [`RetryPolicy::new`]'s
+    /// backoff, from 500ms up to 30s.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Start the switch, armed as if a heartbeat had just arrived
+    ///
+    /// Must be called within a Tokio runtime.
+    pub fn spawn(self, trading_client: Arc<TradingClient>) -> DeadManSwitch {
+        let shared = Arc::new(Shared {
+            last_beat: Mutex::new(Instant::now()),
+            trip_requested: AtomicBool::new(false),
+            trips: AtomicU64::new(0),
+            wake: Notify::new(),
+        });
+        let task = tokio::spawn(run(self, trading_client, shared.clone()));
+        DeadManSwitch {
+            heartbeat: Heartbeat(shared),
+            task,
+        }
+    }
+}
+
+impl DeadManSwitch {
+    /// Configure a switch that fires after `timeout` without a heartbeat
+    pub fn builder(timeout: Duration) -> DeadManSwitchBuilder {
+        DeadManSwitchBuilder {
+            timeout,
+            markets: Vec::new(),
+            retry_policy: RetryPolicy::new(),
+        }
+    }
+
+    /// A cloneable handle for sending heartbeats from other tasks
+    pub fn heartbeat(&self) -> Heartbeat {
+        self.heartbeat.clone()
+    }
+
+    /// Record a heartbeat, postponing the deadline and re-arming a fired switch
+    pub fn beat(&self) {
+        self.heartbeat.beat();
+    }
+
+    /// Cancel now, without waiting for the timeout
+    pub fn trip(&self) {
+        self.heartbeat.trip();
+    }
+
+    /// Number of times the switch has fired
+    pub fn trip_count(&self) -> u64 {
+        self.heartbeat.trip_count()
+    }
+
+    /// Pass a websocket stream through, beating on every event
+    ///
+    /// The switch trips as soon as the stream reports a disconnect
+    /// ([`Error::WebSocket`], [`Error::ConnectionClosed`] or
+    /// [`Error::ReconnectFailed`]) or ends. It trips once per disconnect: a
+    /// stream that ends right after reporting one does not trip again. With a
+    /// [`ReconnectingStream`](crate::websocket::ReconnectingStream), events
+    /// after a reconnect re-arm it.
+    pub fn guard<T: Send + 'static>(
+        &self,
+        stream: impl Stream<Item = Result<T>> + Send + 'static,
+    ) -> Pin<Box<dyn Stream<Item = Result<T>> + Send>> {
+        let heartbeat = self.heartbeat();
+        Box::pin(stream::unfold(
+            (Box::pin(stream), heartbeat, false),
+            |(mut stream, heartbeat, mut disconnected)| async move {
+                match stream.next().await {
+                    Some(item) => {
+                        match &item {
+                            Ok(_) => {
+                                disconnected = false;
+                                heartbeat.beat();
+                            }
+                            Err(
+                                Error::WebSocket(_)
+                                | Error::ConnectionClosed
+                                | Error::ReconnectFailed { .. },
+                            ) if !disconnected => {
+                                disconnected = true;
+                                heartbeat.trip();
+                            }
+                            Err(_) => {}
+                        }
+                        Some((item, (stream, heartbeat, disconnected)))
+                    }
+                    None => {
+                        if !disconnected {
+                            heartbeat.trip();
+                        }
+                        None
+                    }
+                }
+            },
+        ))
+    }
+}
+
+impl Drop for DeadManSwitch {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Handle for feeding a [`DeadManSwitch`] from anywhere in the program
+#[derive(Clone)]
+pub struct Heartbeat(Arc<Shared>);
+
+impl Heartbeat {
+    /// Record a heartbeat, postponing the deadline and re-arming a fired switch
+    pub fn beat(&self) {
+        *self
+            .0
+            .last_beat
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Instant::now();
+        self.0.wake.notify_one();
+    }
+
+    /// Cancel now, without waiting for the timeout
+    pub fn trip(&self) {
+        self.0.trip_requested.store(true, Ordering::SeqCst);
+        self.0.wake.notify_one();
+    }
+
+    /// Number of times the switch has fired
+    pub fn trip_count(&self) -> u64 {
+        self.0.trips.load(Ordering::SeqCst)
+    }
+}
+
+/// State shared between the switch's handles and its task
+struct Shared {
+    last_beat: Mutex<Instant>,
+    trip_requested: AtomicBool,
+    trips: AtomicU64,
+    wake: Notify,
+}
+
+impl Shared {
+    fn last_beat(&self) -> Instant {
+        *self
+            .last_beat
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+async fn run(
+    config: DeadManSwitchBuilder,
+    trading_client: Arc<TradingClient>,
+    shared: Arc<Shared>,
+) {
+    let mut armed = true;
+    let mut fired_at = Instant::now();
+    loop {
+        let expired = shared.last_beat() + config.timeout <= Instant::now();
+        if shared.trip_requested.swap(false, Ordering::SeqCst) || (armed && expired) {
+            fired_at = Instant::now();
+            fire(&config, &trading_client).await;
+            shared.trips.fetch_add(1, Ordering::SeqCst);
+            armed = false;
+            continue;
+        }
+
+        if armed {
+            let deadline = shared.last_beat() + config.timeout;
+            tokio::select! {
+                _ = tokio::time::sleep_until(deadline) => {}
+                _ = shared.wake.notified() => {}
+            }
+        } else {
+            // Stay idle until the next heartbeat (or an explicit trip)
+            shared.wake.notified().await;
+            armed = shared.last_beat() > fired_at;
+        }
+    }
+}
+
+/// Cancel every order in scope, retrying failed cancels until they succeed
+async fn fire(config: &DeadManSwitchBuilder, trading_client: &TradingClient) {
+    let mut pending: Vec<Option<&ConditionId>> = if config.markets.is_empty() {
+        log::warn!("Dead man's switch fired, canceling all orders");
+        vec![None]
+    } else {
+        config.markets.iter().map(Some).collect()
+    };
+
+    let mut attempts = 0;
+    loop {
+        let mut failed = Vec::new();
+        for market in pending {
+            let result = match market {
+                None => trading_client.cancel_all().await.map(drop),
+                Some(market) => {
+                    log::warn!("Dead man's switch fired, canceling orders in {}", market);
+                    trading_client
+                        .cancel_market_orders(Some(market.as_str()), None)
+                        .await
+                        .map(drop)
+                }
+            };
+            if let Err(e) = result {
+                match market {
+                    None => log::error!("Dead man's switch failed to cancel all orders: {}", e),
+                    Some(market) => log::error!(
+                        "Dead man's switch failed to cancel orders in {}: {}",
+                        market,
+                        e
+                    ),
+                }
+                failed.push(market);
+            }
+        }
+        if failed.is_empty() {
+            return;
+        }
+
+        attempts += 1;
+        let delay = config.retry_policy.delay(attempts);
+        log::warn!("Dead man's switch retrying cancels in {:?}", delay);
+        tokio::time::sleep(delay).await;
+        pending = failed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::MockHttpServer;
+
+    fn cancels(server: &MockHttpServer, path: &str) -> usize {
+        server
+            .requests()
+            .iter()
+            .filter(|request| request.method == "DELETE" && request.path == path)
+            .count()
+    }
+
+    /// Wait until the switch has fired `trips` times, failing after 10s
+    async fn wait_for_trips(switch: &DeadManSwitch, trips: u64) {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while switch.trip_count() < trips {
+            assert!(Instant::now() < deadline, "switch did not fire");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fires_once_after_silence() {
+        let server = MockHttpServer::start().await.unwrap();
+        server.mock(
+            "DELETE",
+            "/cancel-all",
+            200,
+            r#"{"canceled":[],"not_canceled":{}}"#,
+        );
+        let switch =
+            DeadManSwitch::builder(Duration::from_secs(1)).spawn(Arc::new(server.trading_client()));
+
+        // Heartbeats, well inside the timeout, keep it quiet
+        for _ in 0..4 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            switch.beat();
+        }
+        assert_eq!(switch.trip_count(), 0);
+
+        // Silence fires it once, then it waits for the next heartbeat
+        wait_for_trips(&switch, 1).await;
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert_eq!(switch.trip_count(), 1);
+        assert_eq!(cancels(&server, "/cancel-all"), 1);
+
+        switch.beat();
+        wait_for_trips(&switch, 2).await;
+        assert_eq!(cancels(&server, "/cancel-all"), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retries_a_failed_cancel() {
+        let server = MockHttpServer::start().await.unwrap();
+        server.mock(
+            "DELETE",
+            "/cancel-all",
+            200,
+            r#"{"canceled":[],"not_canceled":{}}"#,
+        );
+        server.mock_once("DELETE", "/cancel-all", 500, r#"{"error":"overloaded"}"#);
+        let switch = DeadManSwitch::builder(Duration::from_secs(60))
+            .with_retry_policy(RetryPolicy::new().with_backoff(
+                Duration::from_millis(20),
+                Duration::from_millis(20),
+                1.0,
+            ))
+            .spawn(Arc::new(server.trading_client()));
+
+        switch.trip();
+        wait_for_trips(&switch, 1).await;
+        assert_eq!(cancels(&server, "/cancel-all"), 2);
+    }
+
+    #[tokio::test]
+    async fn test_guarded_stream_trips_on_disconnect() {
+        let server = MockHttpServer::start().await.unwrap();
+        server.mock(
+            "DELETE",
+            "/cancel-market-orders",
+            200,
+            r#"{"canceled":[],"not_canceled":{}}"#,
+        );
+        let switch = DeadManSwitch::builder(Duration::from_secs(60))
+            .with_markets([ConditionId::new("0x01"), ConditionId::new("0x02")])
+            .spawn(Arc::new(server.trading_client()));
+
+        // The stream ends once the disconnect has been handled, which must
+        // not trip the switch a second time
+        let heartbeat = switch.heartbeat();
+        let end = stream::once(async move {
+            while heartbeat.trip_count() < 1 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .filter_map(|()| async { None });
+        let events = stream::iter(vec![Ok(1), Err(Error::ConnectionClosed)]).chain(end);
+        let items: Vec<_> = switch.guard(events).collect().await;
+        assert_eq!(items.len(), 2);
+
+        wait_for_trips(&switch, 1).await;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(switch.trip_count(), 1);
+        let bodies: Vec<serde_json::Value> = server
+            .requests()
+            .iter()
+            .filter(|request| request.path == "/cancel-market-orders")
+            .map(|request| request.json().unwrap())
+            .collect();
+        assert_eq!(bodies.len(), 2);
+        assert_eq!(bodies[0]["market"], "0x01");
+        assert_eq!(bodies[1]["market"], "0x02");
+    }
+}
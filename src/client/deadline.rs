@@ -0,0 +1,120 @@
+use crate::error::{Error, Result};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Run a client call, failing with [`Error::Timeout`] if it takes longer
+/// than `timeout`
+///
+/// Works with any REST method on any client, and with helpers that make
+/// several requests (pagination, batches, [`amend_order`]), bounding the
+/// call as a whole. On expiry the call is dropped, which aborts its
+/// in-flight requests; a request that already reached the server (an order
+/// post, a cancel) may still have taken effect, so reconcile before
+/// retrying.
+///
+/// Applies on top of the per-request default set with
+/// [`HttpConfig::with_timeout`](crate::config::HttpConfig::with_timeout),
+/// whichever expires first. Keep that default at what bulk queries need and
+/// tighten latency-critical calls here.
+///
+/// [`amend_order`]: super::TradingClient::amend_order
+///
+/// # Example
+///
+/// ```no_run
+/// # use polymarket_rs::client::{with_timeout, TradingClient};
+/// # use polymarket_rs::types::{OpenOrderParams, SignedOrderRequest};
+/// # use polymarket_rs::{Error, OrderType};
+/// # use std::time::Duration;
+/// # async fn example(client: &TradingClient, order: SignedOrderRequest) -> polymarket_rs::Result<()> {
+/// // Fail fast when placing
+/// match with_timeout(Duration::from_millis(300), client.post_order(order, OrderType::Gtc)).await {
+///     Err(Error::Timeout) => println!("too slow, quote is stale"),
+///     result => println!("{:?}", result?),
+/// }
+///
+/// // Allow a bulk query longer
+/// let orders = with_timeout(Duration::from_secs(30), client.get_orders(OpenOrderParams::new())).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_timeout<T, F>(timeout: Duration, call: F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    with_deadline(Instant::now() + timeout, call).await
+}
+
+/// Run a client call, failing with [`Error::Timeout`] if it hasn't finished
+/// by `deadline`
+///
+/// The deadline form of [`with_timeout`], for sharing one budget across
+/// several calls: each call gets whatever time is left.
+pub async fn with_deadline<T, F>(deadline: Instant, call: F) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    tokio::time::timeout_at(deadline, call)
+        .await
+        .unwrap_or(Err(Error::Timeout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::HttpConfig;
+    use crate::test_util::MockHttpServer;
+    use crate::{ClobClient, TokenId};
+    use tokio::net::TcpListener;
+
+    /// A server that accepts connections and never answers
+    async fn unresponsive_server() -> (String, tokio::task::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let task = tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((tcp, _)) = listener.accept().await {
+                held.push(tcp);
+            }
+        });
+        (url, task)
+    }
+
+    #[tokio::test]
+    async fn test_deadline_expires() {
+        let (url, task) = unresponsive_server().await;
+        let client = ClobClient::new(url);
+        let token_id = TokenId::new("123");
+
+        let result = with_timeout(Duration::from_millis(50), client.get_midpoint(&token_id)).await;
+        assert!(matches!(result, Err(Error::Timeout)));
+
+        // A deadline shared by two calls leaves nothing for the second
+        let deadline = Instant::now() + Duration::from_millis(50);
+        let first = with_deadline(deadline, client.get_midpoint(&token_id)).await;
+        let second = with_deadline(deadline, client.get_spread(&token_id)).await;
+        assert!(matches!(first, Err(Error::Timeout)));
+        assert!(matches!(second, Err(Error::Timeout)));
+        task.abort();
+    }
+
+    #[tokio::test]
+    async fn test_client_default_timeout() {
+        let (url, task) = unresponsive_server().await;
+        let http = HttpConfig::new()
+            .with_timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+        let client = ClobClient::new(url).with_http_client(http);
+        let result = client.get_midpoint(&TokenId::new("123")).await;
+        assert!(matches!(result, Err(Error::Timeout)));
+        task.abort();
+
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        let client = ClobClient::new(server.url());
+        let token_id = TokenId::new("123");
+        let result = with_timeout(Duration::from_secs(5), client.get_midpoint(&token_id)).await;
+        assert!(result.is_ok());
+    }
+}
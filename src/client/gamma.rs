@@ -1,7 +1,9 @@
 use crate::error::Result;
 use crate::http::HttpClient;
 use crate::request::GammaMarketParams;
-use crate::types::{GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag};
+#[cfg(feature = "raw")]
+use crate::types::Raw;
+use crate::types::{EventMarkets, GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag};
 
 /// Client for Gamma API - Market discovery and metadata
 ///
@@ -54,6 +56,27 @@ impl GammaClient {
         }
     }
 
+    /// Send requests through `client`, sharing its connection pool
+    ///
+    /// See [`HttpConfig`](crate::config::HttpConfig) for tuning the pool.
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = self.http_client.with_client(client);
+        self
+    }
+
+    /// Retry failed `GET` and `DELETE` requests according to `policy`
+    ///
+    /// See [`RetryPolicy`](crate::config::RetryPolicy).
+    pub fn with_retry_policy(mut self, policy: crate::config::RetryPolicy) -> Self {
+        self.http_client = self.http_client.with_retry_policy(policy);
+        self
+    }
+
+    /// The underlying `reqwest::Client`, to share its pool with other clients
+    pub fn http_client(&self) -> &reqwest::Client {
+        self.http_client.client()
+    }
+
     /// Get markets with optional filtering and pagination
     ///
     /// # Arguments
@@ -85,10 +108,7 @@ impl GammaClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_markets(
-        &self,
-        params: Option<GammaMarketParams>,
-    ) -> Result<Vec<GammaMarket>> {
+    pub async fn get_markets(&self, params: Option<GammaMarketParams>) -> Result<Vec<GammaMarket>> {
         let mut path = "/markets".to_string();
         if let Some(p) = params {
             path.push_str(&p.to_query_string());
@@ -123,6 +143,13 @@ impl GammaClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get a specific market by ID, keeping the response's JSON; see [`Raw`]
+    #[cfg(feature = "raw")]
+    pub async fn get_market_raw(&self, condition_id: &str) -> Result<Raw<GammaMarket>> {
+        let path = format!("/markets/{}", condition_id);
+        self.http_client.get(&path, None).await
+    }
+
     /// Get all available tags
     ///
     /// Tags are used for categorizing and filtering markets. This endpoint returns
@@ -249,6 +276,25 @@ impl GammaClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get a specific event by ID, keeping the response's JSON; see [`Raw`]
+    #[cfg(feature = "raw")]
+    pub async fn get_event_by_id_raw(&self, id: &str) -> Result<Raw<GammaEvent>> {
+        let path = format!("/events/{}", id);
+        self.http_client.get(&path, None).await
+    }
+
+    /// Get an event's markets with their outcome tokens and relationships
+    ///
+    /// Fetches the event like [`get_event_by_id`](Self::get_event_by_id) and
+    /// decodes each market's outcomes and token IDs, and whether the markets
+    /// form a neg-risk group. See [`EventMarkets`].
+    ///
+    /// # Arguments
+    /// * `id` - The numeric ID of the event
+    pub async fn get_event_markets(&self, id: &str) -> Result<EventMarkets> {
+        EventMarkets::from_event(&self.get_event_by_id(id).await?)
+    }
+
     /// Get all series
     ///
     /// Series are groupings of related events and markets. This endpoint returns
@@ -0,0 +1,349 @@
+use super::ClobClient;
+use crate::error::Result;
+use crate::onchain::OnchainClient;
+use crate::types::{ConditionId, Market, MarketLifecycleEvent};
+use futures_util::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// Last status seen for a watched market
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MarketStatus {
+    accepting_orders: bool,
+    closed: bool,
+}
+
+impl MarketStatus {
+    /// An open market taking orders, the status every watch starts from
+    const TRADING: Self = Self {
+        accepting_orders: true,
+        closed: false,
+    };
+}
+
+/// A market still being watched
+struct Watched {
+    condition_id: ConditionId,
+    status: MarketStatus,
+}
+
+/// State carried between polls of [`ClobClient::watch_markets`]
+struct WatchState<'a> {
+    client: &'a ClobClient,
+    payouts: Option<&'a OnchainClient>,
+    pending: Vec<Watched>,
+    ready: VecDeque<Result<MarketLifecycleEvent>>,
+    poll_interval: Duration,
+    first_poll: bool,
+}
+
+/// Events for the transition from `previous` to the market's current status
+///
+/// Returns the new status and whether the market has resolved, which ends
+/// its watch.
+fn transitions(
+    condition_id: &ConditionId,
+    previous: MarketStatus,
+    market: &Market,
+    events: &mut VecDeque<Result<MarketLifecycleEvent>>,
+) -> (MarketStatus, bool) {
+    let current = MarketStatus {
+        accepting_orders: market.accepting_orders,
+        closed: market.closed,
+    };
+    let condition_id = condition_id.clone();
+
+    if !current.closed && current.accepting_orders != previous.accepting_orders {
+        events.push_back(Ok(if current.accepting_orders {
+            MarketLifecycleEvent::TradingResumed { condition_id }
+        } else {
+            MarketLifecycleEvent::TradingPaused { condition_id }
+        }));
+        return (current, false);
+    }
+
+    if current.closed && !previous.closed {
+        events.push_back(Ok(MarketLifecycleEvent::Closed {
+            condition_id: condition_id.clone(),
+        }));
+    }
+
+    // The CLOB only flags a winner after the payout is reported
+    let winner = market.winner();
+    let resolved = current.closed && winner.is_some();
+    if resolved {
+        events.push_back(Ok(MarketLifecycleEvent::Resolved {
+            condition_id,
+            winner: winner.cloned(),
+        }));
+    }
+    (current, resolved)
+}
+
+/// Check whether a closed market's payout was reported as a split
+///
+/// Pushes `Resolved { winner: None }` and returns true if so. Full payouts
+/// to one outcome are left to the CLOB's winner flag, which names the token.
+async fn split_payout(
+    onchain: &OnchainClient,
+    condition_id: &ConditionId,
+    events: &mut VecDeque<Result<MarketLifecycleEvent>>,
+) -> bool {
+    match onchain.get_resolution(condition_id).await {
+        Ok(resolution) if resolution.is_resolved() && resolution.winning_outcome().is_none() => {
+            events.push_back(Ok(MarketLifecycleEvent::Resolved {
+                condition_id: condition_id.clone(),
+                winner: None,
+            }));
+            true
+        }
+        Ok(_) => false,
+        Err(e) => {
+            events.push_back(Err(e));
+            false
+        }
+    }
+}
+
+impl ClobClient {
+    /// Watch markets through their lifecycle until they resolve
+    ///
+    /// Polls every pending market once per `poll_interval` and yields a
+    /// [`MarketLifecycleEvent`] whenever trading pauses or resumes, the
+    /// market closes, or a winner is declared. Markets are assumed open and
+    /// trading when the watch starts, so the first poll reports any market
+    /// already paused, closed or resolved.
+    ///
+    /// The stream ends once every market has resolved. API errors are
+    /// yielded and the affected markets are retried on the next poll.
+    ///
+    /// Resolution is read from the CLOB's winner flags; markets resolved
+    /// 50/50 never get one, so they stay watched after closing. Use
+    /// [`watch_markets_with_payouts`](Self::watch_markets_with_payouts) to
+    /// resolve those from the reported payouts.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::{ClobClient, ConditionId, StreamExt};
+    /// # use polymarket_rs::types::MarketLifecycleEvent;
+    /// # use std::time::Duration;
+    /// # async fn example(client: &ClobClient) -> polymarket_rs::Result<()> {
+    /// let mut events = client.watch_markets(vec![ConditionId::new("0x...")], Duration::from_secs(30));
+    /// while let Some(event) = events.next().await {
+    ///     match event? {
+    ///         MarketLifecycleEvent::TradingPaused { condition_id } => println!("stop quoting {}", condition_id),
+    ///         MarketLifecycleEvent::Resolved { winner, .. } => println!("won: {:?}", winner),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_markets(
+        &self,
+        condition_ids: Vec<ConditionId>,
+        poll_interval: Duration,
+    ) -> Pin<Box<dyn Stream<Item = Result<MarketLifecycleEvent>> + Send + '_>> {
+        self.watch(condition_ids, poll_interval, None)
+    }
+
+    /// Watch markets like [`watch_markets`](Self::watch_markets), also
+    /// reading the payouts of closed markets on-chain
+    ///
+    /// A closed market without a winner flag whose payout `onchain` finds
+    /// reported as a split (e.g. 50/50) resolves with `winner: None`.
+    pub fn watch_markets_with_payouts<'a>(
+        &'a self,
+        condition_ids: Vec<ConditionId>,
+        poll_interval: Duration,
+        onchain: &'a OnchainClient,
+    ) -> Pin<Box<dyn Stream<Item = Result<MarketLifecycleEvent>> + Send + 'a>> {
+        self.watch(condition_ids, poll_interval, Some(onchain))
+    }
+
+    fn watch<'a>(
+        &'a self,
+        condition_ids: Vec<ConditionId>,
+        poll_interval: Duration,
+        payouts: Option<&'a OnchainClient>,
+    ) -> Pin<Box<dyn Stream<Item = Result<MarketLifecycleEvent>> + Send + 'a>> {
+        let state = WatchState {
+            client: self,
+            payouts,
+            pending: condition_ids
+                .into_iter()
+                .map(|condition_id| Watched {
+                    condition_id,
+                    status: MarketStatus::TRADING,
+                })
+                .collect(),
+            ready: VecDeque::new(),
+            poll_interval,
+            first_poll: true,
+        };
+
+        Box::pin(stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.ready.pop_front() {
+                    return Some((item, state));
+                }
+                if state.pending.is_empty() {
+                    return None;
+                }
+
+                if !state.first_poll {
+                    tokio::time::sleep(state.poll_interval).await;
+                }
+                state.first_poll = false;
+
+                let mut still_pending = Vec::with_capacity(state.pending.len());
+                for mut watched in state.pending.drain(..) {
+                    match state.client.get_market(&watched.condition_id).await {
+                        Ok(market) => {
+                            let (status, mut resolved) = transitions(
+                                &watched.condition_id,
+                                watched.status,
+                                &market,
+                                &mut state.ready,
+                            );
+                            match state.payouts {
+                                Some(onchain) if status.closed && !resolved => {
+                                    resolved = split_payout(
+                                        onchain,
+                                        &watched.condition_id,
+                                        &mut state.ready,
+                                    )
+                                    .await;
+                                }
+                                _ => {}
+                            }
+                            if !resolved {
+                                watched.status = status;
+                                still_pending.push(watched);
+                            }
+                        }
+                        Err(e) => {
+                            state.ready.push_back(Err(e));
+                            still_pending.push(watched);
+                        }
+                    }
+                }
+                state.pending = still_pending;
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::MockHttpServer;
+    use crate::PrivateKeySigner;
+    use futures_util::StreamExt;
+
+    fn market(accepting_orders: bool, closed: bool, winner: Option<usize>) -> String {
+        serde_json::json!({
+            "condition_id": "0x01",
+            "tokens": [
+                { "token_id": "1", "outcome": "Yes", "winner": winner == Some(0) },
+                { "token_id": "2", "outcome": "No", "winner": winner == Some(1) },
+            ],
+            "rewards": { "rates": null, "min_size": 0, "max_spread": 0 },
+            "active": true,
+            "closed": closed,
+            "enable_order_book": true,
+            "archived": false,
+            "accepting_orders": accepting_orders,
+            "accepting_order_timestamp": null,
+            "question_id": "0x02",
+            "question": "Will it happen?",
+            "minimum_order_size": 5,
+            "minimum_tick_size": 0.01,
+            "description": "",
+            "end_date_iso": null,
+            "game_start_time": null,
+            "market_slug": "will-it-happen",
+            "icon": "",
+            "fpmm": "",
+            "neg_risk": false,
+            "neg_risk_market_id": "",
+            "neg_risk_request_id": "",
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_lifecycle_events_until_resolved() {
+        let server = MockHttpServer::start().await.unwrap();
+        let client = ClobClient::new(server.url());
+        let condition_id = ConditionId::new("0x01");
+
+        server.mock("GET", "/markets/0x01", 200, market(false, false, None));
+        let mut events =
+            client.watch_markets(vec![condition_id.clone()], Duration::from_millis(10));
+        assert_eq!(
+            events.next().await.unwrap().unwrap(),
+            MarketLifecycleEvent::TradingPaused {
+                condition_id: condition_id.clone()
+            }
+        );
+
+        server.mock("GET", "/markets/0x01", 200, market(false, true, Some(1)));
+        assert_eq!(
+            events.next().await.unwrap().unwrap(),
+            MarketLifecycleEvent::Closed {
+                condition_id: condition_id.clone()
+            }
+        );
+        match events.next().await.unwrap().unwrap() {
+            MarketLifecycleEvent::Resolved { winner, .. } => {
+                assert_eq!(winner.unwrap().outcome, "No")
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        assert!(events.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_split_payout_resolves_without_winner() {
+        let server = MockHttpServer::start().await.unwrap();
+        let client = ClobClient::new(server.url());
+        let onchain = OnchainClient::new(server.url(), PrivateKeySigner::random(), 137).unwrap();
+        let condition_id =
+            ConditionId::new("0x0000000000000000000000000000000000000000000000000000000000000001");
+        let word = |value: u64| format!("0x{:064x}", value);
+
+        server.mock(
+            "GET",
+            &format!("/markets/{}", condition_id),
+            200,
+            market(false, true, None),
+        );
+        // Unresolved on the first poll, then a denominator of 2, two
+        // outcome slots and a numerator of 1 for each
+        server.mock_rpc("eth_call", &word(1));
+        server.mock_rpc_once("eth_call", &word(2));
+        server.mock_rpc_once("eth_call", &word(2));
+        server.mock_rpc_once("eth_call", &word(0));
+
+        let mut events = client.watch_markets_with_payouts(
+            vec![condition_id.clone()],
+            Duration::from_millis(10),
+            &onchain,
+        );
+        assert!(matches!(
+            events.next().await.unwrap().unwrap(),
+            MarketLifecycleEvent::Closed { .. }
+        ));
+        assert_eq!(
+            events.next().await.unwrap().unwrap(),
+            MarketLifecycleEvent::Resolved {
+                condition_id,
+                winner: None
+            }
+        );
+        assert!(events.next().await.is_none());
+    }
+}
@@ -1,11 +1,32 @@
+mod amend;
 mod authenticated;
+mod batch;
 mod clob;
 mod data;
+mod dead_man;
+mod deadline;
 mod gamma;
+mod lifecycle;
+mod paginated;
+mod pool;
+mod reconcile;
+mod requote;
+mod slicing;
 mod trading;
 
-pub use authenticated::AuthenticatedClient;
+pub use amend::AmendOutcome;
+pub use authenticated::{
+    AuthenticatedClient, AuthenticatedClientBuilder, ENV_API_KEY, ENV_API_PASSPHRASE,
+    ENV_API_SECRET, ENV_CHAIN_ID, ENV_CREDS_FILE, ENV_FUNDER, ENV_HOST, ENV_PRIVATE_KEY,
+};
+#[cfg(feature = "keystore")]
+pub use authenticated::{ENV_KEYSTORE, ENV_KEYSTORE_PASSWORD};
 pub use clob::ClobClient;
 pub use data::DataClient;
+pub use dead_man::{DeadManSwitch, DeadManSwitchBuilder, Heartbeat};
+pub use deadline::{with_deadline, with_timeout};
 pub use gamma::GammaClient;
+pub use paginated::Paginated;
+pub use pool::{Account, AccountEvent, ClientPool};
+pub use requote::{Quote, RequoteOutcome, RequotePlan};
 pub use trading::TradingClient;
@@ -0,0 +1,178 @@
+use crate::error::Result;
+use crate::types::Page;
+use futures_util::future::BoxFuture;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use std::future::Future;
+use std::pin::Pin;
+
+type FetchPage<'a, T> =
+    Box<dyn FnMut(Option<String>) -> BoxFuture<'a, Result<Page<T>>> + Send + 'a>;
+
+/// A paginated listing, fetched one page at a time
+///
+/// Returned by the `paginate_*` methods of the REST clients. Walk it page
+/// by page with [`next_page`](Self::next_page), or flatten every page into a
+/// stream of items with [`into_stream`](Self::into_stream). Pages are only
+/// requested as they are consumed.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::{ClobClient, StreamExt};
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let client = ClobClient::new("https://clob.polymarket.com");
+///
+/// let mut markets = client.paginate_markets().into_stream();
+/// while let Some(market) = markets.next().await {
+///     println!("{}", market?.question);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginated<'a, T> {
+    fetch: FetchPage<'a, T>,
+    cursor: Option<String>,
+    done: bool,
+}
+
+impl<'a, T: Send + 'a> Paginated<'a, T> {
+    /// Paginate with `fetch`, which requests the page at a cursor
+    ///
+    /// The first page is requested with `None`.
+    pub(crate) fn new<F, Fut>(mut fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<Page<T>>> + Send + 'a,
+    {
+        Self {
+            fetch: Box::new(move |cursor| Box::pin(fetch(cursor))),
+            cursor: None,
+            done: false,
+        }
+    }
+
+    /// Start from `cursor` instead of the first page
+    pub fn starting_at(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Fetch the next page, or `None` once the last page has been returned
+    ///
+    /// A failed request can be retried by calling this again.
+    pub async fn next_page(&mut self) -> Result<Option<Page<T>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let page = (self.fetch)(self.cursor.clone()).await?;
+        match page.next_page_cursor() {
+            Some(cursor) => self.cursor = Some(cursor.to_string()),
+            None => self.done = true,
+        }
+        Ok(Some(page))
+    }
+
+    /// Stream every remaining page
+    ///
+    /// The stream ends after the last page or the first error.
+    pub fn into_pages(self) -> Pin<Box<dyn Stream<Item = Result<Page<T>>> + Send + 'a>> {
+        Box::pin(stream::unfold(self, |mut pages| async move {
+            match pages.next_page().await {
+                Ok(Some(page)) => Some((Ok(page), pages)),
+                Ok(None) => None,
+                Err(e) => {
+                    pages.done = true;
+                    Some((Err(e), pages))
+                }
+            }
+        }))
+    }
+
+    /// Stream every remaining item across all pages
+    ///
+    /// The stream ends after the last page or the first error.
+    pub fn into_stream(self) -> Pin<Box<dyn Stream<Item = Result<T>> + Send + 'a>> {
+        Box::pin(
+            self.into_pages()
+                .map_ok(|page| stream::iter(page.data.into_iter().map(Ok)))
+                .try_flatten(),
+        )
+    }
+
+    /// Fetch every remaining page and collect the items
+    pub async fn collect_all(self) -> Result<Vec<T>> {
+        self.into_stream().try_collect().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClobClient;
+    use crate::error::Error;
+    use crate::request::END_CURSOR;
+    use crate::test_util::MockHttpServer;
+    use futures_util::StreamExt;
+
+    /// Serve pages `[0, 1]`, `[2, 3]`, `[4]` keyed by cursor
+    fn numbers(fail_at: Option<&'static str>) -> Paginated<'static, u32> {
+        Paginated::new(move |cursor: Option<String>| async move {
+            let (data, next) = match cursor.as_deref() {
+                None => (vec![0, 1], "a"),
+                Some("a") => (vec![2, 3], "b"),
+                Some("b") => (vec![4], END_CURSOR),
+                Some(other) => panic!("unexpected cursor {}", other),
+            };
+            if fail_at.is_some() && cursor.as_deref() == fail_at {
+                return Err(Error::InvalidParameter("page unavailable".to_string()));
+            }
+            Ok(Page {
+                limit: 2,
+                count: data.len() as u64,
+                next_cursor: Some(next.to_string()),
+                data,
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_page_at_a_time() {
+        let mut pages = numbers(None).starting_at("a");
+        assert_eq!(pages.next_page().await.unwrap().unwrap().data, [2, 3]);
+        assert_eq!(pages.next_page().await.unwrap().unwrap().data, [4]);
+        assert!(pages.next_page().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_into_stream_walks_all_pages() {
+        assert_eq!(numbers(None).collect_all().await.unwrap(), [0, 1, 2, 3, 4]);
+
+        let items: Vec<_> = numbers(Some("b")).into_stream().collect().await;
+        assert_eq!(items.len(), 5);
+        assert!(items[..4].iter().all(|item| item.is_ok()));
+        assert!(items[4].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cursor_is_encoded() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        server.mock(
+            "GET",
+            "/markets",
+            200,
+            r#"{"limit": 0, "count": 0, "next_cursor": "LTE=", "data": []}"#,
+        );
+        let client = ClobClient::new(server.url());
+
+        let mut pages = client.paginate_markets().starting_at("MTAw+/=");
+        assert!(pages.next_page().await.unwrap().is_some());
+        assert!(pages.next_page().await.unwrap().is_none());
+        assert_eq!(
+            server.requests()[0].query.as_deref(),
+            Some("next_cursor=MTAw%2B%2F%3D")
+        );
+    }
+}
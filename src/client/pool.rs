@@ -0,0 +1,296 @@
+use super::{AuthenticatedClient, TradingClient};
+use crate::error::{Error, Result};
+use crate::types::{
+    CreateOrderOptions, ExtraOrderArgs, OrderArgs, OrderType, PostOrderResponse,
+    SignedOrderRequest, UserWsEvent,
+};
+use crate::websocket::UserWsClient;
+use futures_util::stream::{self, Stream, StreamExt};
+use std::collections::BTreeMap;
+use std::pin::Pin;
+
+/// The clients of one wallet and API key
+pub struct Account {
+    authenticated: AuthenticatedClient,
+    trading: TradingClient,
+}
+
+impl Account {
+    /// Pair an account's L1/L2 client with its trading client
+    ///
+    /// Both should be built from the same signer and API credentials.
+    pub fn new(authenticated: AuthenticatedClient, trading: TradingClient) -> Self {
+        Self {
+            authenticated,
+            trading,
+        }
+    }
+
+    /// Client for API keys, balances and notifications
+    pub fn authenticated(&self) -> &AuthenticatedClient {
+        &self.authenticated
+    }
+
+    /// Client for orders and trades
+    pub fn trading(&self) -> &TradingClient {
+        &self.trading
+    }
+}
+
+/// A user websocket event tagged with the account it belongs to
+#[derive(Debug)]
+pub struct AccountEvent {
+    /// Label the account was registered under
+    pub account: String,
+    /// The event, or the error that account's stream hit
+    pub event: Result<UserWsEvent>,
+}
+
+/// Registry of accounts keyed by label
+///
+/// Runs several wallets or API keys side by side, e.g. one per strategy so
+/// their positions and risk stay segregated. Orders are routed by label and
+/// the accounts' user websocket streams are merged into one stream of
+/// [`AccountEvent`]s.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::client::{Account, ClientPool};
+/// use polymarket_rs::types::{CreateOrderOptions, OrderArgs};
+/// use polymarket_rs::websocket::UserWsClient;
+/// use polymarket_rs::{dec, OrderType, StreamExt};
+/// # async fn example(market_maker: Account, arbitrage: Account, args: OrderArgs) -> polymarket_rs::Result<()> {
+/// let pool = ClientPool::new()
+///     .with_account("market-maker", market_maker)
+///     .with_account("arbitrage", arbitrage);
+///
+/// let options = CreateOrderOptions::default().tick_size(dec!(0.01)).neg_risk(false);
+/// pool.create_and_post_order("arbitrage", &args, None, None, options, OrderType::Gtc)
+///     .await?;
+///
+/// let mut events = pool.subscribe_user(&UserWsClient::new()).await?;
+/// while let Some(event) = events.next().await {
+///     println!("{}: {:?}", event.account, event.event);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct ClientPool {
+    accounts: BTreeMap<String, Account>,
+}
+
+impl ClientPool {
+    /// Create an empty pool
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an account under `label`, replacing any with the same label
+    pub fn with_account(mut self, label: impl Into<String>, account: Account) -> Self {
+        self.insert(label, account);
+        self
+    }
+
+    /// Register an account under `label`, returning the one it replaced
+    pub fn insert(&mut self, label: impl Into<String>, account: Account) -> Option<Account> {
+        self.accounts.insert(label.into(), account)
+    }
+
+    /// Remove an account
+    pub fn remove(&mut self, label: &str) -> Option<Account> {
+        self.accounts.remove(label)
+    }
+
+    /// The account registered under `label`
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`] if no account has that label.
+    pub fn account(&self, label: &str) -> Result<&Account> {
+        self.accounts
+            .get(label)
+            .ok_or_else(|| Error::InvalidParameter(format!("Unknown account: {}", label)))
+    }
+
+    /// The trading client of the account registered under `label`
+    pub fn trading(&self, label: &str) -> Result<&TradingClient> {
+        Ok(self.account(label)?.trading())
+    }
+
+    /// Labels of the registered accounts, in sorted order
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.accounts.keys().map(String::as_str)
+    }
+
+    /// Registered accounts with their labels, in label order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Account)> {
+        self.accounts
+            .iter()
+            .map(|(label, account)| (label.as_str(), account))
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// Post a signed order from the account registered under `label`
+    ///
+    /// The order must have been signed by that account's signer.
+    pub async fn post_order(
+        &self,
+        label: &str,
+        order: SignedOrderRequest,
+        order_type: OrderType,
+    ) -> Result<PostOrderResponse> {
+        self.trading(label)?.post_order(order, order_type).await
+    }
+
+    /// Create, sign and post an order from the account registered under
+    /// `label`
+    pub async fn create_and_post_order(
+        &self,
+        label: &str,
+        order_args: &OrderArgs,
+        expiration: Option<u64>,
+        extras: Option<&ExtraOrderArgs>,
+        options: CreateOrderOptions,
+        order_type: OrderType,
+    ) -> Result<PostOrderResponse> {
+        self.trading(label)?
+            .create_and_post_order(order_args, expiration, extras, options, order_type)
+            .await
+    }
+
+    /// Subscribe every account to the user channel and merge the streams
+    ///
+    /// Events are tagged with their account's label and interleaved in
+    /// arrival order. An account's stream ending or failing does not end
+    /// the others; its errors are yielded tagged like its events.
+    ///
+    /// # Errors
+    /// Returns [`Error::AuthRequired`] if an account has no API credentials,
+    /// or the connection error of the first account that fails to subscribe.
+    pub async fn subscribe_user(
+        &self,
+        client: &UserWsClient,
+    ) -> Result<Pin<Box<dyn Stream<Item = AccountEvent> + Send>>> {
+        let mut streams = Vec::with_capacity(self.accounts.len());
+        for (label, account) in &self.accounts {
+            let creds = account.authenticated.api_creds().ok_or_else(|| {
+                Error::AuthRequired(format!("Account {} has no API credentials", label))
+            })?;
+            let account = label.clone();
+            let stream = client.subscribe_with_creds(creds).await?;
+            streams.push(stream.map(move |event| AccountEvent {
+                account: account.clone(),
+                event,
+            }));
+        }
+        Ok(Box::pin(stream::select_all(streams)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockHttpServer, MockWsServer, ScriptedFrame};
+    use crate::types::{ApiCreds, Side};
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn account(server: &MockHttpServer, api_key: &str) -> Account {
+        let signer = PrivateKeySigner::random();
+        let creds = ApiCreds::new(api_key.into(), "c2VjcmV0".into(), "pass".into());
+        let authenticated = AuthenticatedClient::builder()
+            .with_host(server.url())
+            .with_signer(signer.clone())
+            .with_api_creds(creds.clone())
+            .build()
+            .unwrap();
+        Account::new(authenticated, server.trading_client_with(signer, creds))
+    }
+
+    fn order_event(id: &str) -> serde_json::Value {
+        json!({
+            "event_type": "order",
+            "id": id,
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "asset_id": "123",
+            "side": "BUY",
+            "original_size": "10",
+            "size_matched": "0",
+            "price": "0.5",
+            "outcome": "Yes",
+            "type": "PLACEMENT",
+            "order_type": "GTC",
+            "status": "LIVE",
+            "maker_address": "0x0000000000000000000000000000000000000000"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_routes_orders_by_label() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        let pool = ClientPool::new()
+            .with_account("alpha", account(&server, "alpha-key"))
+            .with_account("beta", account(&server, "beta-key"));
+        assert_eq!(pool.labels().collect::<Vec<_>>(), ["alpha", "beta"]);
+
+        let args = OrderArgs::new("123", dec!(0.5), dec!(10), Side::Buy);
+        let options = CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        pool.create_and_post_order("beta", &args, None, None, options.clone(), OrderType::Gtc)
+            .await
+            .unwrap();
+        let unknown = pool
+            .create_and_post_order("gamma", &args, None, None, options, OrderType::Gtc)
+            .await;
+        assert!(matches!(unknown, Err(Error::InvalidParameter(_))));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].header("POLY_API_KEY"), Some("beta-key"));
+    }
+
+    #[tokio::test]
+    async fn test_user_streams_are_tagged() {
+        let ws = MockWsServer::start(vec![
+            vec![ScriptedFrame::json(&order_event("0xa"))],
+            vec![ScriptedFrame::json(&order_event("0xb"))],
+        ])
+        .await
+        .unwrap();
+        let server = MockHttpServer::start().await.unwrap();
+        let pool = ClientPool::new()
+            .with_account("alpha", account(&server, "alpha-key"))
+            .with_account("beta", account(&server, "beta-key"));
+
+        let mut events = pool
+            .subscribe_user(&UserWsClient::with_url(ws.url()))
+            .await
+            .unwrap();
+        let mut seen = Vec::new();
+        for _ in 0..2 {
+            let event = events.next().await.unwrap();
+            match event.event.unwrap() {
+                UserWsEvent::Order(order) => seen.push((event.account, order.id.to_string())),
+                other => panic!("unexpected event {:?}", other),
+            }
+        }
+        seen.sort();
+        assert_eq!(
+            seen,
+            [
+                ("alpha".to_string(), "0xa".to_string()),
+                ("beta".to_string(), "0xb".to_string())
+            ]
+        );
+    }
+}
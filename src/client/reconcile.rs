@@ -0,0 +1,81 @@
+use super::TradingClient;
+use crate::error::Result;
+use crate::reconcile::{Fill, OrderLedger, ReconciliationReport};
+use crate::types::{OpenOrderParams, TradeParams};
+
+impl TradingClient {
+    /// Reconcile a ledger against the account's open orders and trades
+    ///
+    /// Fetches every open order and every trade matching `trades` (narrow it
+    /// with `after` to the period the ledger covers), then compares them
+    /// with [`OrderLedger::reconcile`].
+    pub async fn reconcile(
+        &self,
+        ledger: &OrderLedger,
+        trades: TradeParams,
+    ) -> Result<ReconciliationReport> {
+        let open_orders = self
+            .paginate_orders(OpenOrderParams::new())
+            .collect_all()
+            .await?;
+        let trades = self
+            .paginate_trades(trades)
+            .collect_all()
+            .await?
+            .iter()
+            .map(Fill::from_trade)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ledger.reconcile(&open_orders, &trades))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{AuditEventKind, AuditRecord};
+    use crate::test_util::MockHttpServer;
+    use crate::types::OrderId;
+    use serde_json::json;
+
+    /// The order listed by the open orders fixture
+    const ORDER_ID: &str = "0x3c1a1b2e5a0d9d7f2b8f4f1e0c6a2b9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b";
+
+    #[tokio::test]
+    async fn test_reconcile_against_exchange() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        server.mock_json(
+            "GET",
+            "/data/trades",
+            &json!({
+                "limit": 500,
+                "count": 1,
+                "next_cursor": "LTE=",
+                "data": [{
+                    "id": "t1",
+                    "asset_id": "123",
+                    "side": "SELL",
+                    "price": "0.4",
+                    "size": "25",
+                    "status": "CONFIRMED"
+                }]
+            }),
+        );
+        let client = server.trading_client();
+
+        let ledger = OrderLedger::from_audit_records(&[AuditRecord::new(
+            AuditEventKind::OrderAcknowledged,
+            Some("/order"),
+            json!({ "orderID": ORDER_ID, "status": "live", "success": true, "errorMsg": "" }),
+        )]);
+        let report = client.reconcile(&ledger, TradeParams::new()).await.unwrap();
+
+        assert!(report.missing_orders.is_empty());
+        assert!(report.extra_orders.is_empty());
+        assert_eq!(report.missing_fills.len(), 1);
+        assert_eq!(report.missing_fills[0].size, rust_decimal_macros::dec!(25));
+        assert!(!report.is_clean());
+        assert!(ledger
+            .orders()
+            .any(|order| order.order_id == OrderId::new(ORDER_ID)));
+    }
+}
@@ -0,0 +1,402 @@
+use super::TradingClient;
+use crate::error::Result;
+use crate::types::{
+    CancelOrdersResponse, CreateOrderOptions, OpenOrder, OpenOrderParams, OrderArgs, OrderId,
+    OrderType, PostOrderArgs, PostOrderResponse, TokenId,
+};
+use crate::Side;
+use rust_decimal::Decimal;
+
+/// One order of a desired quote set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quote {
+    pub side: Side,
+    pub price: Decimal,
+    pub size: Decimal,
+}
+
+impl Quote {
+    pub fn new(side: Side, price: Decimal, size: Decimal) -> Self {
+        Self { side, price, size }
+    }
+
+    /// A buy order
+    pub fn bid(price: Decimal, size: Decimal) -> Self {
+        Self::new(Side::Buy, price, size)
+    }
+
+    /// A sell order
+    pub fn ask(price: Decimal, size: Decimal) -> Self {
+        Self::new(Side::Sell, price, size)
+    }
+
+    /// Whether `order` rests exactly as this quote: same side and price,
+    /// and the quote's size still unfilled
+    pub fn matches(&self, order: &OpenOrder) -> bool {
+        order.side == self.side
+            && order.price == self.price
+            && order.original_size - order.size_matched == self.size
+    }
+
+    /// Whether this quote would trade against `order`: a bid at or above a
+    /// resting ask, or an ask at or below a resting bid
+    pub fn crosses(&self, order: &OpenOrder) -> bool {
+        order.side != self.side
+            && match self.side {
+                Side::Buy => self.price >= order.price,
+                Side::Sell => self.price <= order.price,
+            }
+    }
+}
+
+/// The changes that turn resting orders into a desired quote set
+#[derive(Debug, Clone, Default)]
+pub struct RequotePlan {
+    /// Resting orders that already match a quote
+    pub keep: Vec<OpenOrder>,
+    /// Resting orders no quote matches
+    pub cancel: Vec<OpenOrder>,
+    /// Quotes no resting order matches
+    pub place: Vec<Quote>,
+}
+
+impl RequotePlan {
+    /// Pair resting orders with quotes, each order serving at most one quote
+    ///
+    /// Only orders resting exactly as a quote (see [`Quote::matches`]) are
+    /// kept; a partly filled order is replaced rather than topped up.
+    pub fn diff(resting: &[OpenOrder], quotes: &[Quote]) -> Self {
+        let mut plan = Self::default();
+        let mut unmatched: Vec<&OpenOrder> = resting.iter().collect();
+        for quote in quotes {
+            match unmatched.iter().position(|order| quote.matches(order)) {
+                Some(index) => plan.keep.push(unmatched.swap_remove(index).clone()),
+                None => plan.place.push(*quote),
+            }
+        }
+        plan.cancel = unmatched.into_iter().cloned().collect();
+        plan
+    }
+
+    /// Whether the resting orders already match the quotes
+    pub fn is_empty(&self) -> bool {
+        self.cancel.is_empty() && self.place.is_empty()
+    }
+}
+
+/// Result of [`TradingClient::requote`]
+#[derive(Debug)]
+pub struct RequoteOutcome {
+    /// What was kept, canceled and placed
+    pub plan: RequotePlan,
+    /// Response to the batch cancel; empty if nothing needed canceling
+    pub canceled: Result<CancelOrdersResponse>,
+    /// Responses to the batch post, one per quote in `plan.place` that
+    /// wasn't skipped, in order
+    pub posted: Result<Vec<PostOrderResponse>>,
+    /// Quotes not posted because an order they cross wasn't confirmed canceled
+    pub skipped: Vec<Quote>,
+}
+
+impl RequoteOutcome {
+    /// Whether every stale order was canceled and every new quote accepted
+    pub fn is_complete(&self) -> bool {
+        let canceled = self.canceled.as_ref().is_ok_and(|response| {
+            self.plan
+                .cancel
+                .iter()
+                .all(|order| response.canceled.contains(&order.id))
+        });
+        let posted = self
+            .posted
+            .as_ref()
+            .is_ok_and(|responses| responses.iter().all(|response| response.success));
+        canceled && posted && self.skipped.is_empty()
+    }
+
+    /// IDs of the orders now quoting: the kept ones and the accepted new ones
+    pub fn resting_ids(&self) -> Vec<OrderId> {
+        let kept = self.plan.keep.iter().map(|order| order.id.clone());
+        let posted = self
+            .posted
+            .iter()
+            .flatten()
+            .filter(|response| response.success)
+            .map(|response| response.order_id.clone());
+        kept.chain(posted).collect()
+    }
+}
+
+impl TradingClient {
+    /// Move a token's resting orders to `quotes` with the fewest changes
+    ///
+    /// Fetches the open orders on `token_id`, keeps those already resting
+    /// as a quote, and sends one batch cancel for the rest and one batch
+    /// post for the missing quotes. The two batches are sent concurrently,
+    /// so the book is left without the replaced quotes only for the
+    /// duration of one round trip, and unchanged quotes keep their queue
+    /// position.
+    ///
+    /// If a new quote [crosses](Quote::crosses) an order being canceled,
+    /// posting it first could trade against that order, so the post waits
+    /// for the cancel instead. Crossing quotes whose orders weren't all
+    /// confirmed canceled, including when the cancel failed, are not posted
+    /// and are reported in [`RequoteOutcome::skipped`].
+    ///
+    /// New orders are signed before anything is sent: an error is returned
+    /// only when nothing was changed (the open orders couldn't be fetched
+    /// or a quote couldn't be signed, e.g. because of a risk limit).
+    /// Failures of either batch are reported in the [`RequoteOutcome`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::client::{Quote, TradingClient};
+    /// # use polymarket_rs::{dec, CreateOrderOptions, OrderType, TokenId};
+    /// # async fn example(client: &TradingClient, token_id: TokenId) -> polymarket_rs::Result<()> {
+    /// let options = CreateOrderOptions::default().tick_size(dec!(0.01)).neg_risk(false);
+    /// let quotes = [
+    ///     Quote::bid(dec!(0.48), dec!(100)),
+    ///     Quote::ask(dec!(0.52), dec!(100)),
+    /// ];
+    /// let outcome = client.requote(&token_id, &quotes, options, OrderType::Gtc).await?;
+    /// if !outcome.is_complete() {
+    ///     println!("partial requote: {:?}", outcome);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn requote(
+        &self,
+        token_id: &TokenId,
+        quotes: &[Quote],
+        options: CreateOrderOptions,
+        order_type: OrderType,
+    ) -> Result<RequoteOutcome> {
+        let resting: Vec<OpenOrder> = self
+            .paginate_orders(OpenOrderParams::new().asset_id(token_id.as_str()))
+            .collect_all()
+            .await?
+            .into_iter()
+            .filter(|order| &order.asset_id == token_id)
+            .collect();
+        let plan = RequotePlan::diff(&resting, quotes);
+
+        let orders = plan
+            .place
+            .iter()
+            .map(|quote| {
+                let args = OrderArgs::new(token_id.as_str(), quote.price, quote.size, quote.side);
+                let order = self.create_order(&args, None, None, options.clone())?;
+                Ok(PostOrderArgs::new(order, order_type))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let stale: Vec<OrderId> = plan.cancel.iter().map(|order| order.id.clone()).collect();
+        let crossing = |quote: Quote| plan.cancel.iter().filter(move |order| quote.crosses(order));
+
+        let cancel = async {
+            if stale.is_empty() {
+                return Ok(CancelOrdersResponse::merge([]));
+            }
+            self.cancel_orders(&stale).await
+        };
+        if plan
+            .place
+            .iter()
+            .all(|quote| crossing(*quote).next().is_none())
+        {
+            let (canceled, posted) =
+                futures_util::future::join(cancel, self.post_quotes(&orders)).await;
+            return Ok(RequoteOutcome {
+                plan,
+                canceled,
+                posted,
+                skipped: Vec::new(),
+            });
+        }
+
+        let canceled = cancel.await;
+        let confirmed = |quote: &Quote| {
+            crossing(*quote).all(|order| {
+                canceled
+                    .as_ref()
+                    .is_ok_and(|response| response.canceled.contains(&order.id))
+            })
+        };
+        let mut ready = Vec::new();
+        let mut skipped = Vec::new();
+        for (order, quote) in orders.into_iter().zip(&plan.place) {
+            if confirmed(quote) {
+                ready.push(order);
+            } else {
+                skipped.push(*quote);
+            }
+        }
+        let posted = self.post_quotes(&ready).await;
+
+        Ok(RequoteOutcome {
+            plan,
+            canceled,
+            posted,
+            skipped,
+        })
+    }
+
+    /// Post a batch of quotes, skipping the request when there are none
+    async fn post_quotes(&self, orders: &[PostOrderArgs]) -> Result<Vec<PostOrderResponse>> {
+        if orders.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.post_orders(orders).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fixtures, MockHttpServer};
+    use rust_decimal_macros::dec;
+
+    fn options() -> CreateOrderOptions {
+        CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false)
+    }
+
+    fn methods(server: &MockHttpServer) -> Vec<String> {
+        let mut methods: Vec<_> = server
+            .requests()
+            .iter()
+            .map(|request| format!("{} {}", request.method, request.path))
+            .collect();
+        // The cancel and post batches race each other
+        methods.sort();
+        methods
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_quote_is_kept() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        server.mock(
+            "POST",
+            "/orders",
+            200,
+            format!("[{}]", fixtures::POST_ORDER),
+        );
+        let client = server.trading_client();
+        let token_id = TokenId::new(fixtures::TOKEN_ID);
+
+        // The fixture order rests as a 100 share bid at 0.5
+        let quotes = [
+            Quote::bid(dec!(0.50), dec!(100)),
+            Quote::ask(dec!(0.55), dec!(100)),
+        ];
+        let outcome = client
+            .requote(&token_id, &quotes, options(), OrderType::Gtc)
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.plan.keep.len(), 1);
+        assert!(outcome.plan.cancel.is_empty());
+        assert_eq!(outcome.plan.place, [quotes[1]]);
+        assert!(outcome.is_complete());
+        assert_eq!(outcome.resting_ids().len(), 2);
+        assert_eq!(methods(&server), ["GET /data/orders", "POST /orders"]);
+    }
+
+    #[tokio::test]
+    async fn test_moved_quote_is_replaced_in_one_round() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        server.mock(
+            "POST",
+            "/orders",
+            200,
+            format!("[{}]", fixtures::POST_ORDER),
+        );
+        server.mock("DELETE", "/orders", 200, fixtures::CANCEL);
+        let client = server.trading_client();
+        let token_id = TokenId::new(fixtures::TOKEN_ID);
+
+        let quotes = [Quote::bid(dec!(0.49), dec!(100))];
+        let outcome = client
+            .requote(&token_id, &quotes, options(), OrderType::Gtc)
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.plan.cancel.len(), 1);
+        assert!(outcome.is_complete());
+        assert_eq!(
+            methods(&server),
+            ["DELETE /orders", "GET /data/orders", "POST /orders"]
+        );
+
+        // Nothing to do when the book already matches
+        let plan = RequotePlan::diff(&outcome.plan.cancel, &[Quote::bid(dec!(0.5), dec!(100))]);
+        assert!(plan.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_crossing_quote_waits_for_cancel() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        server.mock(
+            "POST",
+            "/orders",
+            200,
+            format!("[{}]", fixtures::POST_ORDER),
+        );
+        server.mock("DELETE", "/orders", 200, fixtures::CANCEL);
+        let client = server.trading_client();
+        let token_id = TokenId::new(fixtures::TOKEN_ID);
+
+        // An ask at the resting bid's price would trade against it
+        let quotes = [Quote::ask(dec!(0.50), dec!(100))];
+        let outcome = client
+            .requote(&token_id, &quotes, options(), OrderType::Gtc)
+            .await
+            .unwrap();
+
+        assert!(quotes[0].crosses(&outcome.plan.cancel[0]));
+        assert!(outcome.is_complete());
+        let methods: Vec<_> = server
+            .requests()
+            .iter()
+            .map(|request| request.method.clone())
+            .collect();
+        assert_eq!(methods, ["GET", "DELETE", "POST"]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_cancel_skips_crossing_quotes() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        server.mock(
+            "POST",
+            "/orders",
+            200,
+            format!("[{}]", fixtures::POST_ORDER),
+        );
+        server.mock("DELETE", "/orders", 400, r#"{"error": "rejected"}"#);
+        let client = server.trading_client();
+        let token_id = TokenId::new(fixtures::TOKEN_ID);
+
+        let quotes = [
+            Quote::bid(dec!(0.49), dec!(100)),
+            Quote::ask(dec!(0.50), dec!(100)),
+        ];
+        let outcome = client
+            .requote(&token_id, &quotes, options(), OrderType::Gtc)
+            .await
+            .unwrap();
+
+        // The bid doesn't cross the stale bid, so it's still posted
+        assert_eq!(outcome.skipped, [quotes[1]]);
+        assert!(outcome.posted.is_ok());
+        assert!(!outcome.is_complete());
+        assert_eq!(
+            methods(&server),
+            ["DELETE /orders", "GET /data/orders", "POST /orders"]
+        );
+        let post = server.requests().pop().unwrap();
+        let body: Vec<serde_json::Value> = serde_json::from_str(&post.body).unwrap();
+        assert_eq!(body.len(), 1);
+    }
+}
@@ -0,0 +1,105 @@
+use super::TradingClient;
+use crate::error::Result;
+use crate::orders::SlicePlan;
+use crate::types::{
+    CreateOrderOptions, OrderArgs, OrderType, PostOrderArgs, PostOrderResponse, TokenId,
+};
+use tokio::time::Instant;
+
+impl TradingClient {
+    /// Send the child orders of a [`SlicePlan`] on its schedule
+    ///
+    /// Children due at the same time are posted in one batch; later waves
+    /// wait for their delay. Returns the responses of every child posted.
+    /// An error stops execution, leaving the children already sent working.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::orders::OrderSlicer;
+    /// # use polymarket_rs::types::OrderBookSummary;
+    /// # use polymarket_rs::{dec, CreateOrderOptions, OrderType, Side, TokenId, TradingClient};
+    /// # async fn example(client: &TradingClient, token_id: TokenId, book: OrderBookSummary) -> polymarket_rs::Result<()> {
+    /// let plan = OrderSlicer::new(dec!(0.02)).plan(&book.asks, Side::Buy, dec!(5000))?;
+    /// let options = CreateOrderOptions::new().tick_size(dec!(0.01)).neg_risk(false);
+    /// let responses = client
+    ///     .execute_slices(&token_id, &plan, options, OrderType::Fak)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_slices(
+        &self,
+        token_id: &TokenId,
+        plan: &SlicePlan,
+        options: CreateOrderOptions,
+        order_type: OrderType,
+    ) -> Result<Vec<PostOrderResponse>> {
+        let start = Instant::now();
+        let mut responses = Vec::with_capacity(plan.children.len());
+
+        for wave in plan.children.chunk_by(|a, b| a.delay == b.delay) {
+            tokio::time::sleep_until(start + wave[0].delay).await;
+            let orders = wave
+                .iter()
+                .map(|child| {
+                    let args =
+                        OrderArgs::new(token_id.as_str(), child.price, child.size, plan.side);
+                    let order = self.create_order(&args, None, None, options.clone())?;
+                    Ok(PostOrderArgs::new(order, order_type))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            responses.extend(self.post_orders(&orders).await?);
+        }
+        Ok(responses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::OrderSlicer;
+    use crate::test_util::MockHttpServer;
+    use crate::types::PriceLevel;
+    use crate::Side;
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_posts_one_batch_per_wave() {
+        let server = MockHttpServer::start().await.unwrap();
+        let response = r#"{"errorMsg":"","orderID":"0x01","status":"matched","success":true}"#;
+        server.mock("POST", "/orders", 200, format!("[{}]", response));
+        let client = server.trading_client();
+
+        let asks = vec![
+            PriceLevel {
+                price: dec!(0.50),
+                size: dec!(10),
+            },
+            PriceLevel {
+                price: dec!(0.51),
+                size: dec!(10),
+            },
+        ];
+        let plan = OrderSlicer::new(dec!(0.01))
+            .with_wave_interval(Duration::from_millis(10))
+            .with_max_waves(2)
+            .plan(&asks, Side::Buy, dec!(30))
+            .unwrap();
+        let options = CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        client
+            .execute_slices(&TokenId::new("123"), &plan, options, OrderType::Fak)
+            .await
+            .unwrap();
+
+        let batches: Vec<usize> = server
+            .requests()
+            .iter()
+            .map(|request| request.json::<Vec<serde_json::Value>>().unwrap().len())
+            .collect();
+        assert_eq!(batches, [2, 1]);
+    }
+}
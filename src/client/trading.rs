@@ -1,12 +1,34 @@
-use crate::error::Result;
+use super::batch::{chunked, partial_chunks};
+use super::paginated::Paginated;
+use crate::audit::{AuditEventKind, AuditRecord, AuditSink};
+use crate::config::BatchConfig;
+use crate::error::{Error, Result};
 use crate::http::{create_l2_headers, HttpClient};
-use crate::orders::{calculate_market_price, OrderBuilder};
+use crate::orders::{
+    calculate_market_price, token_u64_to_decimal, OrderBuilder, RiskLimits, RiskState,
+};
+use crate::request::encode_query_value;
 use crate::signing::EthSigner;
 use crate::types::{
     ApiCreds, CancelOrdersResponse, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OpenOrder,
     OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary, OrderId, OrderType,
-    PostOrder, PostOrderArgs, PostOrderResponse, Side, SignedOrderRequest, TradeParams,
+    PostOrder, PostOrderArgs, PostOrderResponse, Side, SignedOrderRequest, TokenId, TradeParams,
 };
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Audit kinds of a successful and a failed order submission
+const ORDER_OUTCOMES: (AuditEventKind, AuditEventKind) = (
+    AuditEventKind::OrderAcknowledged,
+    AuditEventKind::OrderRejected,
+);
+
+/// Audit kinds of a successful and a failed cancel
+const CANCEL_OUTCOMES: (AuditEventKind, AuditEventKind) = (
+    AuditEventKind::CancelAcknowledged,
+    AuditEventKind::CancelRejected,
+);
 
 /// Client for trading operations
 ///
@@ -18,6 +40,10 @@ pub struct TradingClient {
     chain_id: u64,
     api_creds: ApiCreds,
     order_builder: OrderBuilder,
+    batch_config: BatchConfig,
+    audit_log: Option<Arc<dyn AuditSink>>,
+    risk_limits: Option<RiskLimits>,
+    risk_state: Mutex<RiskState>,
 }
 
 impl TradingClient {
@@ -42,9 +68,137 @@ impl TradingClient {
             chain_id,
             api_creds,
             order_builder,
+            batch_config: BatchConfig::default(),
+            audit_log: None,
+            risk_limits: None,
+            risk_state: Mutex::new(RiskState::new()),
         }
     }
 
+    /// Check every order against `risk_limits` before signing and again before posting it
+    ///
+    /// Violating orders fail with [`Error::RiskLimit`](crate::Error::RiskLimit)
+    /// and are never sent; a batch posted with [`post_orders`](Self::post_orders)
+    /// is rejected as a whole. Open orders and their sizes are tracked from
+    /// post and cancel responses, with orders still being posted held against
+    /// the limits; positions and mids must be fed in through
+    /// [`risk_state`](Self::risk_state).
+    pub fn with_risk_limits(mut self, risk_limits: RiskLimits) -> Self {
+        self.risk_limits = Some(risk_limits);
+        self
+    }
+
+    /// The state risk limits are checked against, for recording fills and mids
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use polymarket_rs::client::TradingClient;
+    /// # use polymarket_rs::{Side, TokenId};
+    /// # use rust_decimal_macros::dec;
+    /// # fn example(trading_client: &TradingClient) {
+    /// let token = TokenId::new("123");
+    /// let mut state = trading_client.risk_state();
+    /// state.set_mid(token.clone(), dec!(0.48));
+    /// state.record_fill(token, Side::Buy, dec!(25));
+    /// # }
+    /// ```
+    pub fn risk_state(&self) -> MutexGuard<'_, RiskState> {
+        self.risk_state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Resync the open-order count with the exchange
+    ///
+    /// Orders filled or canceled elsewhere (another session, the UI, expiry)
+    /// are only noticed by listing open orders; call this periodically when
+    /// relying on [`RiskLimits::with_max_open_orders`] or
+    /// [`RiskLimits::with_max_token_position`].
+    pub async fn sync_open_orders(&self) -> Result<usize> {
+        let orders = self
+            .paginate_orders(OpenOrderParams::new())
+            .collect_all()
+            .await?;
+        self.risk_state().set_resting_orders(&orders);
+        Ok(orders.len())
+    }
+
+    /// Check an order against the risk limits, if any are configured
+    fn check_risk(&self, token_id: &str, side: Side, price: Decimal, size: Decimal) -> Result<()> {
+        if let Some(limits) = &self.risk_limits {
+            let token_id = TokenId::new(token_id);
+            limits.check(&self.risk_state(), &token_id, side, price, size)?;
+        }
+        Ok(())
+    }
+
+    /// Check signed orders against the risk limits and hold open-order
+    /// capacity and position exposure for them until the returned
+    /// reservation is dropped
+    ///
+    /// The check and the reservation happen under one lock, so concurrent
+    /// posts can't both take the last slot.
+    fn reserve_risk<'a>(
+        &self,
+        orders: impl IntoIterator<Item = &'a SignedOrderRequest>,
+    ) -> Result<Reservation<'_>> {
+        let mut reservation = Reservation {
+            state: &self.risk_state,
+            orders: Vec::new(),
+        };
+        if let Some(limits) = &self.risk_limits {
+            let terms = orders
+                .into_iter()
+                .map(order_terms)
+                .collect::<Result<Vec<_>>>()?;
+            let mut state = self.risk_state();
+            limits.check_batch(
+                &state,
+                terms
+                    .iter()
+                    .map(|(token_id, side, price, size)| (token_id, *side, *price, *size)),
+            )?;
+            state.reserve(&terms);
+            reservation.orders = terms;
+        }
+        Ok(reservation)
+    }
+
+    /// Record every order submission and cancel, with its raw payloads, to `audit_log`
+    ///
+    /// See the [`audit`](crate::audit) module.
+    pub fn with_audit_log(mut self, audit_log: Arc<dyn AuditSink>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Set the chunk sizes and parallelism of batch requests
+    pub fn with_batch_config(mut self, batch_config: BatchConfig) -> Self {
+        self.batch_config = batch_config;
+        self
+    }
+
+    /// Send requests through `client`, sharing its connection pool
+    ///
+    /// See [`HttpConfig`](crate::config::HttpConfig) for tuning the pool.
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = self.http_client.with_client(client);
+        self
+    }
+
+    /// Retry failed `GET` and `DELETE` requests according to `policy`
+    ///
+    /// See [`RetryPolicy`](crate::config::RetryPolicy).
+    pub fn with_retry_policy(mut self, policy: crate::config::RetryPolicy) -> Self {
+        self.http_client = self.http_client.with_retry_policy(policy);
+        self
+    }
+
+    /// The underlying `reqwest::Client`, to share its pool with other clients
+    pub fn http_client(&self) -> &reqwest::Client {
+        self.http_client.client()
+    }
+
     /// Create a limit order (local operation, not posted)
     ///
     /// # Arguments
@@ -63,6 +217,13 @@ impl TradingClient {
         let default_extras = ExtraOrderArgs::default();
         let extras = extras.unwrap_or(&default_extras);
 
+        self.check_risk(
+            &order_args.token_id,
+            order_args.side,
+            order_args.price,
+            order_args.size,
+        )?;
+
         self.order_builder
             .create_order(self.chain_id, order_args, expiration, extras, options)
     }
@@ -93,6 +254,22 @@ impl TradingClient {
         // Calculate market price from order book
         let price = calculate_market_price(book_side, order_args.amount, order_args.side)?;
 
+        if self.risk_limits.is_some() {
+            // Buy amounts are in USDC, sell amounts in shares
+            let size = match order_args.side {
+                Side::Buy => order_args.amount / price,
+                Side::Sell => order_args.amount,
+            };
+            // Collar against the book being walked when no mid was recorded
+            let token_id = TokenId::new(&order_args.token_id);
+            let mut state = self.risk_state();
+            if state.mid(&token_id).is_none() {
+                state.update_mid_from_book(order_book);
+            }
+            drop(state);
+            self.check_risk(&order_args.token_id, order_args.side, price, size)?;
+        }
+
         self.order_builder
             .create_market_order(self.chain_id, order_args, price, extras, options)
     }
@@ -107,6 +284,8 @@ impl TradingClient {
         order: SignedOrderRequest,
         order_type: OrderType,
     ) -> Result<PostOrderResponse> {
+        let _reservation = self.reserve_risk([&order])?;
+        let terms = [order_terms(&order).ok()];
         let owner = self.api_creds.api_key.clone();
         let post_order = PostOrder::new(order, owner, order_type);
 
@@ -117,13 +296,28 @@ impl TradingClient {
             "/order",
             Some(&post_order),
         )?;
-        self.http_client
+        self.audit(AuditEventKind::OrderSubmitted, "/order", &post_order);
+        let result = self
+            .http_client
             .post("/order", &post_order, Some(headers))
-            .await
+            .await;
+        if let Ok(response) = &result {
+            self.track_posted(&terms, std::slice::from_ref(response));
+        }
+        self.audit_result(ORDER_OUTCOMES, "/order", result)
     }
 
     /// Post multiple orders to the exchange
     ///
+    /// Large batches are sent in several requests (see [`BatchConfig`]). If
+    /// some of them fail, their orders are reported as unsuccessful responses
+    /// carrying the error in `error_msg`, alongside the responses of the
+    /// requests that went through; an error is returned only when every
+    /// request failed.
+    ///
+    /// With [risk limits](Self::with_risk_limits) configured, the whole batch
+    /// is checked before the first request is sent.
+    ///
     /// # Arguments
     /// * `orders` - Slice of order arguments with their types
     ///
@@ -140,6 +334,20 @@ impl TradingClient {
     /// # }
     /// ```
     pub async fn post_orders(&self, orders: &[PostOrderArgs]) -> Result<Vec<PostOrderResponse>> {
+        let _reservation = self.reserve_risk(orders.iter().map(|arg| &arg.order))?;
+        let config = self.batch_config;
+        let chunks = chunked(
+            orders,
+            config.max_orders(),
+            config.max_concurrency(),
+            |chunk| self.post_order_chunk(chunk),
+        )
+        .await;
+        partial_chunks(chunks, |_, e| PostOrderResponse::failed(e))
+    }
+
+    /// Post one request's worth of orders to `/orders`
+    async fn post_order_chunk(&self, orders: &[PostOrderArgs]) -> Result<Vec<PostOrderResponse>> {
         let owner = self.api_creds.api_key.clone();
 
         // Build array of PostOrder structs
@@ -156,9 +364,19 @@ impl TradingClient {
             Some(&post_orders),
         )?;
 
-        self.http_client
+        self.audit(AuditEventKind::OrderSubmitted, "/orders", &post_orders);
+        let result: Result<Vec<PostOrderResponse>> = self
+            .http_client
             .post("/orders", &post_orders, Some(headers))
-            .await
+            .await;
+        if let Ok(responses) = &result {
+            let terms: Vec<_> = orders
+                .iter()
+                .map(|arg| order_terms(&arg.order).ok())
+                .collect();
+            self.track_posted(&terms, responses);
+        }
+        self.audit_result(ORDER_OUTCOMES, "/orders", result)
     }
 
     /// Create and post an order in one step
@@ -188,29 +406,31 @@ impl TradingClient {
     /// # Arguments
     /// * `params` - Query parameters to filter orders
     pub async fn get_orders(&self, params: OpenOrderParams) -> Result<OpenOrdersResponse> {
-        // IMPORTANT: Sign the base path WITHOUT query parameters
-        // Query parameters are added to the URL after signing
-        let base_path = "/data/orders";
-        let headers =
-            create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
+        self.get_orders_page(&params, None).await
+    }
 
-        // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
-            base_path.to_string()
-        } else {
-            format!(
-                "{}?{}",
-                base_path,
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&")
-            )
-        };
+    /// Walk every page of open orders (L2 authentication required)
+    ///
+    /// # Arguments
+    /// * `params` - Query parameters to filter orders
+    pub fn paginate_orders(&self, params: OpenOrderParams) -> Paginated<'_, OpenOrder> {
+        Paginated::new(move |cursor| {
+            let params = params.clone();
+            async move { self.get_orders_page(&params, cursor).await }
+        })
+    }
 
-        self.http_client.get(&request_path, Some(headers)).await
+    async fn get_orders_page(
+        &self,
+        params: &OpenOrderParams,
+        cursor: Option<String>,
+    ) -> Result<OpenOrdersResponse> {
+        let query = params
+            .to_query_params()
+            .into_iter()
+            .map(|(k, v)| (k, v.clone()))
+            .collect();
+        self.get_signed("/data/orders", query, cursor).await
     }
 
     /// Get a specific order by ID
@@ -234,16 +454,40 @@ impl TradingClient {
             "/order",
             Some(&body),
         )?;
-        self.http_client
+        self.audit(AuditEventKind::CancelRequested, "/order", &body);
+        let result = self
+            .http_client
             .delete_with_body("/order", &body, Some(headers))
-            .await
+            .await;
+        if let Ok(response) = &result {
+            self.track_canceled(response);
+        }
+        self.audit_result(CANCEL_OUTCOMES, "/order", result)
     }
 
     /// Cancel multiple orders
     ///
+    /// Orders in a request that failed are listed in `not_canceled` with the
+    /// error; an error is returned only when every request failed.
+    ///
     /// # Arguments
     /// * `order_ids` - List of order IDs to cancel
     pub async fn cancel_orders(&self, order_ids: &[OrderId]) -> Result<CancelOrdersResponse> {
+        let config = self.batch_config;
+        let chunks = chunked(
+            order_ids,
+            config.max_cancels(),
+            config.max_concurrency(),
+            |chunk| async move { self.cancel_order_chunk(chunk).await.map(|r| vec![r]) },
+        )
+        .await;
+        let responses = partial_chunks(chunks, CancelOrdersResponse::failed)?;
+
+        Ok(CancelOrdersResponse::merge(responses))
+    }
+
+    /// Cancel one request's worth of orders via `DELETE /orders`
+    async fn cancel_order_chunk(&self, order_ids: &[OrderId]) -> Result<CancelOrdersResponse> {
         let ids: Vec<&str> = order_ids.iter().map(|id| id.as_str()).collect();
         let body = serde_json::json!(ids);
         let headers = create_l2_headers(
@@ -253,9 +497,15 @@ impl TradingClient {
             "/orders",
             Some(&body),
         )?;
-        self.http_client
+        self.audit(AuditEventKind::CancelRequested, "/orders", &body);
+        let result = self
+            .http_client
             .delete_with_body("/orders", &body, Some(headers))
-            .await
+            .await;
+        if let Ok(response) = &result {
+            self.track_canceled(response);
+        }
+        self.audit_result(CANCEL_OUTCOMES, "/orders", result)
     }
 
     /// Cancel all orders
@@ -268,9 +518,15 @@ impl TradingClient {
             "/cancel-all",
             Some(&body),
         )?;
-        self.http_client
+        self.audit(AuditEventKind::CancelRequested, "/cancel-all", &body);
+        let result = self
+            .http_client
             .delete_with_body("/cancel-all", &body, Some(headers))
-            .await
+            .await;
+        if result.is_ok() {
+            self.risk_state().set_resting_orders(&[]);
+        }
+        self.audit_result(CANCEL_OUTCOMES, "/cancel-all", result)
     }
 
     /// Cancel all orders for a specific market and/or asset
@@ -296,9 +552,19 @@ impl TradingClient {
             "/cancel-market-orders",
             Some(&body),
         )?;
-        self.http_client
+        self.audit(
+            AuditEventKind::CancelRequested,
+            "/cancel-market-orders",
+            &body,
+        );
+        let result = self
+            .http_client
             .delete_with_body("/cancel-market-orders", &body, Some(headers))
-            .await
+            .await;
+        if let Ok(response) = &result {
+            self.track_canceled(response);
+        }
+        self.audit_result(CANCEL_OUTCOMES, "/cancel-market-orders", result)
     }
 
     /// Get trade history (L2 authentication required)
@@ -306,22 +572,104 @@ impl TradingClient {
     /// # Arguments
     /// * `params` - Query parameters to filter trades
     pub async fn get_trades(&self, params: TradeParams) -> Result<serde_json::Value> {
+        self.get_signed("/data/trades", params.to_query_params(), None)
+            .await
+    }
+
+    /// Walk every page of trade history (L2 authentication required)
+    ///
+    /// # Arguments
+    /// * `params` - Query parameters to filter trades
+    pub fn paginate_trades(&self, params: TradeParams) -> Paginated<'_, serde_json::Value> {
+        Paginated::new(move |cursor| {
+            let params = params.clone();
+            async move {
+                self.get_signed("/data/trades", params.to_query_params(), cursor)
+                    .await
+            }
+        })
+    }
+
+    /// Count orders from post responses that are now resting on the book
+    ///
+    /// `terms` holds the terms of the posted orders, in the order of their
+    /// responses.
+    fn track_posted(&self, terms: &[Option<OrderTerms>], responses: &[PostOrderResponse]) {
+        let mut state = self.risk_state();
+        for (index, response) in responses.iter().enumerate() {
+            if !response.success || !response.status.eq_ignore_ascii_case("live") {
+                continue;
+            }
+            match terms.get(index).cloned().flatten() {
+                Some((token_id, side, _, size)) => {
+                    state.add_resting(response.order_id.clone(), token_id, side, size)
+                }
+                None => state.add_open_orders(1),
+            }
+        }
+    }
+
+    /// Stop counting orders a cancel response confirms as canceled
+    fn track_canceled(&self, response: &CancelOrdersResponse) {
+        self.risk_state().remove_resting(&response.canceled);
+    }
+
+    /// Append a record to the audit log, if one is configured
+    fn audit(&self, kind: AuditEventKind, endpoint: &str, payload: &impl Serialize) {
+        if let Some(audit_log) = &self.audit_log {
+            let payload = serde_json::to_value(payload).unwrap_or(serde_json::Value::Null);
+            let record = AuditRecord::new(kind, Some(endpoint), payload);
+            if let Err(e) = audit_log.record(&record) {
+                log::error!("Failed to write audit record for {}: {}", endpoint, e);
+            }
+        }
+    }
+
+    /// Audit the outcome of a request: the response if it succeeded, the error otherwise
+    fn audit_result<T: Serialize>(
+        &self,
+        (acknowledged, rejected): (AuditEventKind, AuditEventKind),
+        endpoint: &str,
+        result: Result<T>,
+    ) -> Result<T> {
+        match &result {
+            Ok(response) => self.audit(acknowledged, endpoint, response),
+            Err(e) => self.audit(
+                rejected,
+                endpoint,
+                &serde_json::json!({ "error": e.to_string() }),
+            ),
+        }
+        result
+    }
+
+    /// GET an L2-authenticated endpoint with query parameters and an optional page cursor
+    async fn get_signed<T>(
+        &self,
+        base_path: &str,
+        mut query: Vec<(&str, String)>,
+        cursor: Option<String>,
+    ) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
         // IMPORTANT: Sign the base path WITHOUT query parameters
-        let base_path = "/data/trades";
+        // Query parameters are added to the URL after signing
         let headers =
             create_l2_headers::<_, ()>(&self.signer, &self.api_creds, "GET", base_path, None)?;
 
-        // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
+        if let Some(cursor) = cursor {
+            query.push(("next_cursor", cursor));
+        }
+        let request_path = if query.is_empty() {
             base_path.to_string()
         } else {
             format!(
                 "{}?{}",
                 base_path,
-                query_params
+                query
                     .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
+                    .map(|(k, v)| format!("{}={}", k, encode_query_value(v)))
                     .collect::<Vec<_>>()
                     .join("&")
             )
@@ -359,3 +707,50 @@ impl TradingClient {
             .await
     }
 }
+
+/// Open-order capacity and position exposure held for orders being posted
+///
+/// Dropping it releases the hold, whether the post completed (and the
+/// resting orders were counted) or was abandoned.
+struct Reservation<'a> {
+    state: &'a Mutex<RiskState>,
+    orders: Vec<OrderTerms>,
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .release(&self.orders);
+    }
+}
+
+/// Token, side, price and size in shares of an order
+type OrderTerms = (TokenId, Side, Decimal, Decimal);
+
+/// The [`OrderTerms`] of a signed order
+fn order_terms(order: &SignedOrderRequest) -> Result<OrderTerms> {
+    let amount = |value: &str| {
+        value
+            .parse::<u64>()
+            .map(token_u64_to_decimal)
+            .map_err(|_| Error::InvalidParameter(format!("Invalid order amount: {}", value)))
+    };
+    let maker = amount(&order.maker_amount)?;
+    let taker = amount(&order.taker_amount)?;
+    // Buys pay USDC for shares, sells the reverse
+    let (usdc, shares) = match order.side {
+        Side::Buy => (maker, taker),
+        Side::Sell => (taker, maker),
+    };
+    if shares.is_zero() {
+        return Err(Error::InvalidParameter("Order size is zero".to_string()));
+    }
+    Ok((
+        TokenId::new(&order.token_id),
+        order.side,
+        usdc / shares,
+        shares,
+    ))
+}
@@ -0,0 +1,77 @@
+/// Item limits and parallelism for batch REST endpoints
+///
+/// The CLOB caps how many items one batch request may carry. Batch methods
+/// such as [`ClobClient::get_order_books`](crate::ClobClient::get_order_books)
+/// and [`TradingClient::cancel_orders`](crate::TradingClient::cancel_orders)
+/// split larger inputs into chunks within these limits, send up to
+/// `max_concurrency` chunks at once and merge the results in input order.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::config::BatchConfig;
+/// use polymarket_rs::ClobClient;
+///
+/// let client = ClobClient::new("https://clob.polymarket.com")
+///     .with_batch_config(BatchConfig::default().with_max_concurrency(8));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchConfig {
+    max_tokens: usize,
+    max_orders: usize,
+    max_cancels: usize,
+    max_concurrency: usize,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            max_tokens: 500,
+            max_orders: 15,
+            max_cancels: 500,
+            max_concurrency: 4,
+        }
+    }
+}
+
+impl BatchConfig {
+    /// Maximum token IDs per request to `/books`, `/prices`, `/midpoints` and `/spreads`
+    pub fn with_max_tokens(mut self, max: usize) -> Self {
+        self.max_tokens = max.max(1);
+        self
+    }
+
+    /// Maximum orders per request to `POST /orders`
+    pub fn with_max_orders(mut self, max: usize) -> Self {
+        self.max_orders = max.max(1);
+        self
+    }
+
+    /// Maximum order IDs per request to `DELETE /orders`
+    pub fn with_max_cancels(mut self, max: usize) -> Self {
+        self.max_cancels = max.max(1);
+        self
+    }
+
+    /// Maximum chunks of one batch in flight at once
+    pub fn with_max_concurrency(mut self, max: usize) -> Self {
+        self.max_concurrency = max.max(1);
+        self
+    }
+
+    pub(crate) fn max_tokens(&self) -> usize {
+        self.max_tokens
+    }
+
+    pub(crate) fn max_orders(&self) -> usize {
+        self.max_orders
+    }
+
+    pub(crate) fn max_cancels(&self) -> usize {
+        self.max_cancels
+    }
+
+    pub(crate) fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+}
@@ -6,6 +6,8 @@ pub struct ContractConfig {
     pub exchange: String,
     pub collateral: String,
     pub conditional_tokens: String,
+    /// NegRiskAdapter, or `None` where no deployment is known
+    pub neg_risk_adapter: Option<String>,
 }
 
 /// Chain IDs for supported networks
@@ -14,6 +16,8 @@ pub mod chains {
     pub const POLYGON_AMOY_TESTNET: u64 = 80002;
 }
 
+const MAINNET_NEG_RISK_ADAPTER: &str = "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296";
+
 /// Get contract configuration for a specific chain and market type
 ///
 /// # Arguments
@@ -30,24 +34,28 @@ pub fn get_contract_config(chain_id: u64, neg_risk: bool) -> Result<ContractConf
             exchange: "0xC5d563A36AE78145C45a50134d48A1215220f80a".to_owned(),
             collateral: "0x2791bca1f2de4661ed88a30c99a7a9449aa84174".to_owned(),
             conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_owned(),
+            neg_risk_adapter: Some(MAINNET_NEG_RISK_ADAPTER.to_owned()),
         }),
         // Polygon Mainnet - Standard
         (chains::POLYGON_MAINNET, false) => Ok(ContractConfig {
             exchange: "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E".to_owned(),
             collateral: "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174".to_owned(),
             conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_owned(),
+            neg_risk_adapter: Some(MAINNET_NEG_RISK_ADAPTER.to_owned()),
         }),
         // Polygon Amoy Testnet - NEG_RISK
         (chains::POLYGON_AMOY_TESTNET, true) => Ok(ContractConfig {
             exchange: "0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296".to_owned(),
             collateral: "0x9c4e1703476e875070ee25b56a58b008cfb8fa78".to_owned(),
             conditional_tokens: "0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB".to_owned(),
+            neg_risk_adapter: None,
         }),
         // Polygon Amoy Testnet - Standard
         (chains::POLYGON_AMOY_TESTNET, false) => Ok(ContractConfig {
             exchange: "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40".to_owned(),
             collateral: "0x9c4e1703476e875070ee25b56a58b008cfb8fa78".to_owned(),
             conditional_tokens: "0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB".to_owned(),
+            neg_risk_adapter: None,
         }),
         // Unsupported chain
         _ => Err(Error::Config(format!(
@@ -79,6 +87,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_neg_risk_adapter_is_not_an_exchange() {
+        for chain_id in [chains::POLYGON_MAINNET, chains::POLYGON_AMOY_TESTNET] {
+            let exchanges = [false, true]
+                .map(|neg_risk| get_contract_config(chain_id, neg_risk).unwrap().exchange);
+            for neg_risk in [false, true] {
+                let config = get_contract_config(chain_id, neg_risk).unwrap();
+                if let Some(adapter) = config.neg_risk_adapter {
+                    assert!(!exchanges.contains(&adapter), "chain {}", chain_id);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_unsupported_chain() {
         let result = get_contract_config(999, false);
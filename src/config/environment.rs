@@ -0,0 +1,107 @@
+use super::contracts::{chains, get_contract_config, ContractConfig};
+use crate::error::Result;
+
+/// A Polymarket deployment: endpoints, chain ID and contracts that belong together
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::config::Environment;
+/// use polymarket_rs::ClobClient;
+/// use polymarket_rs::websocket::MarketWsClient;
+///
+/// let env = Environment::Mainnet;
+/// let client = ClobClient::new(env.clob_url());
+/// let ws = MarketWsClient::with_url(env.market_ws_url());
+/// assert_eq!(env.chain_id(), 137);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Environment {
+    /// Production on Polygon mainnet (chain 137)
+    #[default]
+    Mainnet,
+    /// Staging CLOB on the Polygon Amoy testnet (chain 80002)
+    Amoy,
+}
+
+impl Environment {
+    /// The environment whose contracts live on `chain_id`, if any
+    pub fn from_chain_id(chain_id: u64) -> Option<Self> {
+        match chain_id {
+            chains::POLYGON_MAINNET => Some(Self::Mainnet),
+            chains::POLYGON_AMOY_TESTNET => Some(Self::Amoy),
+            _ => None,
+        }
+    }
+
+    /// Chain ID orders are signed for and contracts live on
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            Self::Mainnet => chains::POLYGON_MAINNET,
+            Self::Amoy => chains::POLYGON_AMOY_TESTNET,
+        }
+    }
+
+    /// Base URL of the CLOB REST API
+    pub fn clob_url(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "https://clob.polymarket.com",
+            Self::Amoy => "https://clob-staging.polymarket.com",
+        }
+    }
+
+    /// WebSocket URL for public market data
+    pub fn market_ws_url(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "wss://ws-subscriptions-clob.polymarket.com/ws/market",
+            Self::Amoy => "wss://ws-subscriptions-clob-staging.polymarket.com/ws/market",
+        }
+    }
+
+    /// WebSocket URL for authenticated user events
+    pub fn user_ws_url(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "wss://ws-subscriptions-clob.polymarket.com/ws/user",
+            Self::Amoy => "wss://ws-subscriptions-clob-staging.polymarket.com/ws/user",
+        }
+    }
+
+    /// Base URL of the Data API (positions, trades, activity)
+    ///
+    /// The Data API only indexes mainnet, so both environments share it.
+    pub fn data_url(&self) -> &'static str {
+        "https://data-api.polymarket.com"
+    }
+
+    /// Base URL of the Gamma API (market and event metadata)
+    ///
+    /// The Gamma API only serves mainnet, so both environments share it.
+    pub fn gamma_url(&self) -> &'static str {
+        "https://gamma-api.polymarket.com"
+    }
+
+    /// Contract addresses for standard or negative-risk markets
+    pub fn contracts(&self, neg_risk: bool) -> Result<ContractConfig> {
+        get_contract_config(self.chain_id(), neg_risk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_environment_presets() {
+        assert_eq!(Environment::default(), Environment::Mainnet);
+        assert_eq!(Environment::Amoy.chain_id(), chains::POLYGON_AMOY_TESTNET);
+        assert_eq!(Environment::from_chain_id(137), Some(Environment::Mainnet));
+        assert_eq!(Environment::from_chain_id(1), None);
+
+        let contracts = Environment::Amoy.contracts(false).unwrap();
+        assert_eq!(
+            contracts.exchange,
+            "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40"
+        );
+        assert!(Environment::Mainnet.contracts(true).is_ok());
+    }
+}
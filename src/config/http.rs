@@ -0,0 +1,168 @@
+use crate::error::Result;
+use std::time::Duration;
+
+/// Connection pool and protocol settings for the REST clients
+///
+/// Every REST client owns a `reqwest::Client`, which pools connections per
+/// host. Build one client from an `HttpConfig` and hand it to each REST
+/// client with `with_http_client` so they share the pool and skip repeated
+/// TCP and TLS handshakes.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::config::HttpConfig;
+/// use polymarket_rs::{ClobClient, GammaClient};
+/// use std::time::Duration;
+///
+/// # fn main() -> polymarket_rs::Result<()> {
+/// let http = HttpConfig::new()
+///     .with_pool_max_idle_per_host(64)
+///     .with_pool_idle_timeout(Duration::from_secs(120))
+///     .build()?;
+///
+/// let clob = ClobClient::new("https://clob.polymarket.com").with_http_client(http.clone());
+/// let gamma = GammaClient::new("https://gamma-api.polymarket.com").with_http_client(http);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HttpConfig {
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    http2_prior_knowledge: bool,
+    http2_keep_alive_interval: Option<Duration>,
+}
+
+impl HttpConfig {
+    /// Create a config with reqwest's defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum idle connections kept open per host
+    ///
+    /// Raise this to the number of requests you run concurrently against
+    /// one API, so bursts don't open fresh connections.
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// How long an idle pooled connection is kept before it is closed
+    pub fn with_pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Interval of TCP keep-alive probes on open connections
+    pub fn with_tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Timeout for establishing a connection
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Default timeout for a whole request, from connecting to reading the
+    /// body
+    ///
+    /// Expired requests fail with [`Error::Timeout`](crate::Error::Timeout).
+    /// Individual calls can be given a tighter budget with
+    /// [`client::with_timeout`](crate::client::with_timeout).
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Speak HTTP/2 without negotiating it first
+    ///
+    /// Multiplexes every request to a host over one connection. Only enable
+    /// this against servers known to accept HTTP/2; over TLS, HTTP/2 is
+    /// already negotiated automatically where the server supports it.
+    pub fn with_http2_prior_knowledge(mut self) -> Self {
+        self.http2_prior_knowledge = true;
+        self
+    }
+
+    /// Interval of HTTP/2 PING frames that keep idle connections alive
+    pub fn with_http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Build a `reqwest::Client` with these settings
+    ///
+    /// The client is cheap to clone; clones share one connection pool.
+    pub fn build(&self) -> Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder();
+
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(interval) = self.http2_keep_alive_interval {
+            builder = builder
+                .http2_keep_alive_interval(interval)
+                .http2_keep_alive_while_idle(true);
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{AuthenticatedClient, ClobClient};
+    use crate::test_util::{fixtures, MockHttpServer};
+    use crate::types::TokenId;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[tokio::test]
+    async fn test_clients_share_connection_pool() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        let http = HttpConfig::new()
+            .with_pool_max_idle_per_host(4)
+            .with_tcp_keepalive(Duration::from_secs(30))
+            .build()
+            .unwrap();
+
+        let clob = ClobClient::new(server.url()).with_http_client(http.clone());
+        let auth = AuthenticatedClient::builder()
+            .with_host(server.url())
+            .with_signer(PrivateKeySigner::random())
+            .with_http_client(http)
+            .build()
+            .unwrap();
+
+        let token_id = TokenId::new(fixtures::TOKEN_ID);
+        for _ in 0..3 {
+            clob.get_midpoint(&token_id).await.unwrap();
+        }
+        auth.derive_api_key().await.unwrap();
+
+        assert_eq!(server.requests().len(), 4);
+        assert_eq!(server.connection_count(), 1);
+    }
+}
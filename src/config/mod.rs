@@ -1,3 +1,11 @@
+mod batch;
 mod contracts;
+mod environment;
+mod http;
+mod retry;
 
+pub use batch::BatchConfig;
 pub use contracts::{chains, get_contract_config, ContractConfig};
+pub use environment::Environment;
+pub use http::HttpConfig;
+pub use retry::RetryPolicy;
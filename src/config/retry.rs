@@ -0,0 +1,247 @@
+use crate::error::{Error, Result};
+use rand::Rng;
+use std::future::Future;
+use std::time::Duration;
+
+/// When and how often to retry failed requests and connections
+///
+/// One policy configures both the REST clients (`with_retry_policy`) and
+/// websocket reconnection ([`ReconnectingStream`](crate::websocket::ReconnectingStream)
+/// and [`MarketWsClient::subscribe_reconnecting`](crate::websocket::MarketWsClient::subscribe_reconnecting)
+/// accept a policy wherever they take a
+/// [`ReconnectConfig`](crate::websocket::ReconnectConfig)).
+///
+/// Delays grow exponentially from `initial_delay` by `multiplier` up to
+/// `max_delay`. With jitter, each delay is shortened by a random fraction of
+/// up to `jitter`, so many clients recovering from the same outage don't
+/// retry in lockstep. Only errors the classifier accepts are retried; the
+/// default, [`is_transient`](Self::is_transient), retries timeouts,
+/// connection failures, rate limiting and server errors.
+///
+/// REST clients retry `GET` and `DELETE` requests only. `POST`s, including
+/// order submissions, are never retried automatically since a request that
+/// timed out may still have been executed; wrap them in
+/// [`retry`](Self::retry) where a duplicate is harmless.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::config::RetryPolicy;
+/// use polymarket_rs::websocket::MarketWsClient;
+/// use polymarket_rs::{ClobClient, TokenId};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new()
+///     .with_max_attempts(5)
+///     .with_backoff(Duration::from_millis(200), Duration::from_secs(10), 2.0)
+///     .with_jitter(0.5);
+///
+/// let clob = ClobClient::new("https://clob.polymarket.com").with_retry_policy(policy.clone());
+/// let (stream, handle) =
+///     MarketWsClient::new().subscribe_reconnecting(vec![TokenId::new("123")], policy);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: Option<u32>,
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+    jitter: f64,
+    retryable: fn(&Error) -> bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: Some(3),
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.0,
+            retryable: Self::is_transient,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Three attempts, backing off from 500ms, retrying transient errors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Give up after `max_attempts` attempts, the first one included
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts.max(1));
+        self
+    }
+
+    /// Keep retrying for as long as errors are retryable
+    pub fn with_unlimited_attempts(mut self) -> Self {
+        self.max_attempts = None;
+        self
+    }
+
+    /// Exponential backoff from `initial_delay`, growing by `multiplier`
+    /// per attempt up to `max_delay`
+    pub fn with_backoff(
+        mut self,
+        initial_delay: Duration,
+        max_delay: Duration,
+        multiplier: f64,
+    ) -> Self {
+        self.initial_delay = initial_delay;
+        self.max_delay = max_delay.max(initial_delay);
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Shorten each delay by a random fraction of up to `jitter` (0 to 1)
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Decide which errors are worth retrying
+    pub fn with_retryable(mut self, retryable: fn(&Error) -> bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
+    /// Maximum attempts, or `None` for no limit
+    pub fn max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    /// The default classifier: timeouts, connection failures, websocket
+    /// drops, rate limiting (HTTP 429) and server errors (HTTP 5xx)
+    ///
+    /// Client errors, access restrictions and local failures such as
+    /// signing or invalid parameters are not retried.
+    pub fn is_transient(error: &Error) -> bool {
+        match error {
+            Error::Timeout | Error::WebSocket(_) | Error::ConnectionClosed => true,
+            Error::Http(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            Error::Api { status, .. } => *status == 429 || *status >= 500,
+            _ => false,
+        }
+    }
+
+    /// Whether the policy retries `error`
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        (self.retryable)(error)
+    }
+
+    /// Whether another attempt is allowed after `attempts` have failed
+    pub(crate) fn allows(&self, attempts: u32) -> bool {
+        self.max_attempts.is_none_or(|max| attempts < max)
+    }
+
+    /// Delay before the retry following the `attempt`th failure (from 1),
+    /// before jitter
+    pub fn base_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(64) as i32;
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::from_secs_f64(delay.min(self.max_delay.as_secs_f64()))
+    }
+
+    /// Apply the policy's jitter to a delay
+    fn jittered(&self, delay: Duration) -> Duration {
+        if self.jitter == 0.0 {
+            return delay;
+        }
+        let cut = rand::thread_rng().gen_range(0.0..=self.jitter);
+        delay.mul_f64(1.0 - cut)
+    }
+
+    /// Delay before the retry following the `attempt`th failure (from 1)
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.jittered(self.base_delay(attempt))
+    }
+
+    /// Run `call`, retrying it while it fails with retryable errors
+    ///
+    /// Returns the first success, the first non-retryable error, or the
+    /// last error once the attempts run out.
+    pub async fn retry<T, F, Fut>(&self, mut call: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempts = 0;
+        loop {
+            match call().await {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    attempts += 1;
+                    if !self.is_retryable(&e) || !self.allows(attempts) {
+                        return Err(e);
+                    }
+                    let delay = self.delay(attempts);
+                    log::debug!("Retrying after {:?} (attempt {}): {}", delay, attempts, e);
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::MockHttpServer;
+    use crate::{ClobClient, TokenId};
+
+    #[test]
+    fn test_backoff_curve_and_jitter() {
+        let policy = RetryPolicy::new().with_backoff(
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+            2.0,
+        );
+        let delays: Vec<_> = (1..=5).map(|attempt| policy.delay(attempt)).collect();
+        assert_eq!(delays, [100, 200, 400, 500, 500].map(Duration::from_millis));
+
+        let policy = policy.with_jitter(0.5);
+        for _ in 0..20 {
+            let delay = policy.delay(2);
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn test_default_classifier() {
+        let retryable = |status| {
+            RetryPolicy::is_transient(&Error::Api {
+                status,
+                message: String::new(),
+            })
+        };
+        assert!(retryable(503) && retryable(429));
+        assert!(!retryable(400) && !retryable(404));
+        assert!(RetryPolicy::is_transient(&Error::Timeout));
+        assert!(!RetryPolicy::is_transient(&Error::InvalidParameter(
+            String::new()
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_rest_requests_are_retried() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        server.mock("GET", "/midpoint", 503, "unavailable");
+        let policy = RetryPolicy::new().with_max_attempts(3).with_backoff(
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+            1.0,
+        );
+        let client = ClobClient::new(server.url()).with_retry_policy(policy);
+
+        let result = client.get_midpoint(&TokenId::new("123")).await;
+        assert!(matches!(result, Err(Error::Api { status: 503, .. })));
+        assert_eq!(server.requests().len(), 3);
+
+        // Client errors fail on the first attempt
+        server.mock("GET", "/midpoint", 400, "bad request");
+        let _ = client.get_midpoint(&TokenId::new("123")).await;
+        assert_eq!(server.requests().len(), 4);
+    }
+}
@@ -47,6 +47,31 @@ pub enum Error {
         attempts: u32,
         last_error: String,
     },
+
+    /// JSON-RPC error returned by the Ethereum node (including reverted calls)
+    Rpc { code: i64, message: String },
+
+    /// On-chain transaction was mined but reverted
+    TransactionReverted(String),
+
+    /// Timed out waiting for an on-chain transaction to be mined
+    TransactionTimeout(String),
+
+    /// Reading or writing a local file failed
+    Io(std::io::Error),
+
+    /// Order rejected client-side by the configured risk limits
+    RiskLimit(crate::orders::RiskViolation),
+
+    /// Request blocked before reaching the API: a geoblock (HTTP 403/451
+    /// with a regional block message) or a Cloudflare challenge page
+    AccessRestricted { status: u16, message: String },
+
+    /// A broadcast subscriber fell behind and missed this many events
+    Lagged(u64),
+
+    /// A request timeout or caller deadline expired before the call finished
+    Timeout,
 }
 
 impl fmt::Display for Error {
@@ -74,6 +99,18 @@ impl fmt::Display for Error {
                 "Reconnection failed after {} attempts: {}",
                 attempts, last_error
             ),
+            Error::Rpc { code, message } => write!(f, "RPC error (code {}): {}", code, message),
+            Error::TransactionReverted(hash) => write!(f, "Transaction reverted: {}", hash),
+            Error::TransactionTimeout(hash) => {
+                write!(f, "Timed out waiting for transaction: {}", hash)
+            }
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::RiskLimit(violation) => write!(f, "Risk limit violated: {}", violation),
+            Error::AccessRestricted { status, message } => {
+                write!(f, "Access restricted (HTTP {}): {}", status, message)
+            }
+            Error::Lagged(skipped) => write!(f, "Subscriber lagged, {} events skipped", skipped),
+            Error::Timeout => write!(f, "Request timed out"),
         }
     }
 }
@@ -84,6 +121,7 @@ impl std::error::Error for Error {
             Error::Http(e) => Some(e),
             Error::Json(e) => Some(e),
             Error::Decimal(e) => Some(e),
+            Error::Io(e) => Some(e),
             _ => None,
         }
     }
@@ -101,6 +139,18 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<crate::orders::RiskViolation> for Error {
+    fn from(violation: crate::orders::RiskViolation) -> Self {
+        Error::RiskLimit(violation)
+    }
+}
+
 impl From<rust_decimal::Error> for Error {
     fn from(err: rust_decimal::Error) -> Self {
         Error::Decimal(err)
@@ -115,6 +165,20 @@ impl From<alloy_signer::Error> for Error {
 
 impl From<tokio_tungstenite::tungstenite::Error> for Error {
     fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        // Geoblocks and challenges reject the handshake with an HTTP response
+        if let tokio_tungstenite::tungstenite::Error::Http(response) = &err {
+            let body = response
+                .body()
+                .as_deref()
+                .map(String::from_utf8_lossy)
+                .unwrap_or_default();
+            let status = response.status().as_u16();
+            if let Some(message) =
+                crate::http::access_restriction(status, response.headers(), &body)
+            {
+                return Error::AccessRestricted { status, message };
+            }
+        }
         Error::WebSocket(err.to_string())
     }
 }
@@ -1,14 +1,26 @@
+use super::restriction::access_restriction;
+use crate::config::RetryPolicy;
 use crate::error::{Error, Result};
-use reqwest::{Client, Response};
+use reqwest::{Client, RequestBuilder, Response};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::HashMap;
 
+/// Convert a reqwest error, reporting expired timeouts as [`Error::Timeout`]
+fn request_error(err: reqwest::Error) -> Error {
+    if err.is_timeout() {
+        Error::Timeout
+    } else {
+        Error::Http(err)
+    }
+}
+
 /// HTTP client wrapper for making API requests
 #[derive(Clone)]
 pub struct HttpClient {
     client: Client,
     base_url: String,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl HttpClient {
@@ -16,9 +28,27 @@ impl HttpClient {
         Self {
             client: Client::new(),
             base_url: base_url.into(),
+            retry_policy: None,
         }
     }
 
+    /// Retry failed `GET` and `DELETE` requests according to `policy`
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Replace the underlying client, e.g. to share its connection pool
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// The underlying client
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
     /// Make a GET request
     pub async fn get<T>(&self, path: &str, headers: Option<HashMap<&str, String>>) -> Result<T>
     where
@@ -33,8 +63,7 @@ impl HttpClient {
             }
         }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send_idempotent(request).await
     }
 
     /// Make a POST request with JSON body
@@ -57,8 +86,7 @@ impl HttpClient {
             }
         }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(request).await
     }
 
     /// Make a DELETE request with optional JSON body
@@ -75,8 +103,7 @@ impl HttpClient {
             }
         }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send_idempotent(request).await
     }
 
     /// Make a DELETE request with JSON body
@@ -99,7 +126,34 @@ impl HttpClient {
             }
         }
 
-        let response = request.send().await?;
+        self.send_idempotent(request).await
+    }
+
+    /// Send a request that is safe to repeat, retrying it under the retry policy
+    async fn send_idempotent<T>(&self, request: RequestBuilder) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let Some(policy) = &self.retry_policy else {
+            return self.send(request).await;
+        };
+        if request.try_clone().is_none() {
+            return self.send(request).await;
+        }
+        policy
+            .retry(|| {
+                let request = request.try_clone().expect("request body is cloneable");
+                self.send(request)
+            })
+            .await
+    }
+
+    /// Send a request once and handle its response
+    async fn send<T>(&self, request: RequestBuilder) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let response = request.send().await.map_err(request_error)?;
         self.handle_response(response).await
     }
 
@@ -109,15 +163,32 @@ impl HttpClient {
         T: DeserializeOwned,
     {
         let status = response.status();
+        let headers = response.headers().clone();
 
         if status.is_success() {
-            response.json().await.map_err(|e| e.into())
+            let body = response.text().await.map_err(request_error)?;
+            serde_json::from_str(&body).map_err(|e| {
+                // A challenge page served with 200 would otherwise surface as a parse error
+                match access_restriction(status.as_u16(), &headers, &body) {
+                    Some(message) => Error::AccessRestricted {
+                        status: status.as_u16(),
+                        message,
+                    },
+                    None => e.into(),
+                }
+            })
         } else {
             let message = response
                 .text()
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
 
+            if let Some(message) = access_restriction(status.as_u16(), &headers, &message) {
+                return Err(Error::AccessRestricted {
+                    status: status.as_u16(),
+                    message,
+                });
+            }
             Err(Error::Api {
                 status: status.as_u16(),
                 message,
@@ -13,6 +13,10 @@ const POLY_TS_HEADER: &str = "POLY_TIMESTAMP";
 const POLY_NONCE_HEADER: &str = "POLY_NONCE";
 const POLY_API_KEY_HEADER: &str = "POLY_API_KEY";
 const POLY_PASS_HEADER: &str = "POLY_PASSPHRASE";
+const POLY_BUILDER_API_KEY_HEADER: &str = "POLY_BUILDER_API_KEY";
+const POLY_BUILDER_PASS_HEADER: &str = "POLY_BUILDER_PASSPHRASE";
+const POLY_BUILDER_SIG_HEADER: &str = "POLY_BUILDER_SIGNATURE";
+const POLY_BUILDER_TS_HEADER: &str = "POLY_BUILDER_TIMESTAMP";
 
 pub type Headers = HashMap<&'static str, String>;
 
@@ -67,6 +71,32 @@ where
     ]))
 }
 
+/// Create builder headers for relayer requests (HMAC based)
+///
+/// The relayer authenticates with builder API credentials, which are signed
+/// the same way as L2 credentials but sent under their own header names.
+pub fn create_builder_headers<T>(
+    builder_creds: &ApiCreds,
+    method: &str,
+    req_path: &str,
+    body: Option<&T>,
+) -> Result<Headers>
+where
+    T: ?Sized + Serialize,
+{
+    let timestamp = get_current_unix_time_secs()?;
+
+    let hmac_signature =
+        build_hmac_signature(&builder_creds.secret, timestamp, method, req_path, body)?;
+
+    Ok(HashMap::from([
+        (POLY_BUILDER_API_KEY_HEADER, builder_creds.api_key.clone()),
+        (POLY_BUILDER_PASS_HEADER, builder_creds.passphrase.clone()),
+        (POLY_BUILDER_SIG_HEADER, hmac_signature),
+        (POLY_BUILDER_TS_HEADER, timestamp.to_string()),
+    ]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
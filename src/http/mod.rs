@@ -1,5 +1,7 @@
 mod client;
 mod headers;
+mod restriction;
 
 pub use client::HttpClient;
-pub use headers::{create_l1_headers, create_l2_headers};
+pub use headers::{create_builder_headers, create_l1_headers, create_l2_headers};
+pub(crate) use restriction::access_restriction;
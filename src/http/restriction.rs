@@ -0,0 +1,139 @@
+use reqwest::header::HeaderMap;
+
+/// Markers of a Cloudflare challenge or block page in a response body
+const CHALLENGE_MARKERS: [&str; 4] = [
+    "<title>Just a moment...</title>",
+    "cf-chl-",
+    "/cdn-cgi/challenge-platform/",
+    "Attention Required! | Cloudflare",
+];
+
+/// Phrases of a regional block in a response body, matched case-insensitively:
+/// the CLOB's geoblock error ("Trading restricted in your region, please
+/// refer to available regions"), the link to its geoblock docs, and
+/// Cloudflare's country block page (error 1009)
+const GEOBLOCK_MARKERS: [&str; 3] = ["restricted in your region", "/geoblock", "error code: 1009"];
+
+/// Explain a response that was blocked before reaching the API
+///
+/// Returns `None` for ordinary API responses. Cloudflare challenge pages are
+/// recognised by the `cf-mitigated` header or their markup, whatever the
+/// status. Polymarket rejects requests from restricted regions with 403 or
+/// 451, but so do other checks, so those count only when the body carries a
+/// known geoblock message.
+pub(crate) fn access_restriction(status: u16, headers: &HeaderMap, body: &str) -> Option<String> {
+    let challenged = headers
+        .get("cf-mitigated")
+        .is_some_and(|value| value.as_bytes().eq_ignore_ascii_case(b"challenge"))
+        || CHALLENGE_MARKERS.iter().any(|marker| body.contains(marker));
+    if challenged {
+        return Some(
+            "Cloudflare returned a challenge page instead of the API response; \
+             the request was flagged as automated traffic, retry later or from another network"
+                .to_string(),
+        );
+    }
+
+    match status {
+        403 | 451 if geoblocked(body) => Some(format!(
+            "{}; Polymarket restricts access from some regions",
+            api_message(body).unwrap_or_else(|| "Forbidden".to_string())
+        )),
+        _ => None,
+    }
+}
+
+/// Whether the body carries a regional block message
+fn geoblocked(body: &str) -> bool {
+    let body = body.to_ascii_lowercase();
+    GEOBLOCK_MARKERS.iter().any(|marker| body.contains(marker))
+}
+
+/// The error message of a JSON error body, if it has one
+fn api_message(body: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(body).ok()?;
+    ["error", "message"]
+        .iter()
+        .find_map(|key| value.get(key)?.as_str())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::MockHttpServer;
+    use crate::{ClobClient, Error, Side, TokenId};
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_geoblock_keeps_api_message() {
+        let body = r#"{"error":"Trading restricted in your region, please refer to available regions - https://docs.polymarket.com/developers/CLOB/geoblock"}"#;
+        let message = access_restriction(403, &HeaderMap::new(), body).unwrap();
+        assert!(message.starts_with("Trading restricted in your region,"));
+
+        assert!(access_restriction(400, &HeaderMap::new(), body).is_none());
+        assert!(access_restriction(451, &HeaderMap::new(), "error code: 1009").is_some());
+    }
+
+    #[test]
+    fn test_other_forbidden_responses_are_not_geoblocks() {
+        // Credentials, banned wallets and restricted markets stay API errors
+        for body in [
+            r#"{"error":"Unauthorized/Invalid api key"}"#,
+            r#"{"error":"address is restricted, closed only mode"}"#,
+            r#"{"error":"this market is restricted"}"#,
+            r#"{"error":"not allowed in this country's market"}"#,
+            "",
+        ] {
+            assert!(access_restriction(403, &HeaderMap::new(), body).is_none());
+        }
+
+        // Header values never count, whatever they mention
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-amz-cf-pop",
+            HeaderValue::from_static("restricted-region-1"),
+        );
+        assert!(access_restriction(451, &headers, "").is_none());
+    }
+
+    #[test]
+    fn test_challenge_detection() {
+        let page = "<!DOCTYPE html><html><head><title>Just a moment...</title>";
+        let message = access_restriction(200, &HeaderMap::new(), page).unwrap();
+        assert!(message.contains("Cloudflare"));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("cf-mitigated", HeaderValue::from_static("challenge"));
+        assert!(access_restriction(503, &headers, "").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_client_surfaces_access_restricted() {
+        let server = MockHttpServer::start().await.unwrap();
+        server.mock(
+            "GET",
+            "/midpoint",
+            403,
+            r#"{"error":"Trading restricted in your region"}"#,
+        );
+        server.mock(
+            "GET",
+            "/book",
+            200,
+            "<html><head><title>Just a moment...</title></head></html>",
+        );
+        server.mock("GET", "/price", 403, r#"{"error":"Forbidden"}"#);
+        let client = ClobClient::new(server.url());
+        let token_id = TokenId::new("123");
+
+        let err = client.get_midpoint(&token_id).await.unwrap_err();
+        assert!(matches!(err, Error::AccessRestricted { status: 403, .. }));
+
+        let err = client.get_order_book(&token_id).await.unwrap_err();
+        assert!(matches!(err, Error::AccessRestricted { status: 200, .. }));
+
+        let err = client.get_price(&token_id, Side::Buy).await.unwrap_err();
+        assert!(matches!(err, Error::Api { status: 403, .. }));
+    }
+}
@@ -8,6 +8,7 @@
 //! - Account and balance operations (authenticated)
 //! - Position tracking
 //! - Real-time WebSocket streaming for market data and user events
+//! - On-chain Conditional Tokens operations (split and merge)
 //!
 //! ## Features
 //!
@@ -19,15 +20,22 @@
 //!
 
 // Public modules
+pub mod audit;
+pub mod cashflow;
 pub mod client;
 pub mod config;
 pub mod error;
+pub mod onchain;
 pub mod orders;
+pub mod reconcile;
 pub mod request;
 pub mod signing;
 pub mod types;
 pub mod websocket;
 
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
 // Internal modules
 mod http;
 mod utils;
@@ -42,12 +50,22 @@ pub use types::{
     OrderArgs, OrderId, OrderType, PostOrderArgs, Side, SignatureType, TokenId,
 };
 
+// Re-export the decimal type used for prices, sizes and amounts
+pub use rust_decimal::Decimal;
+pub use rust_decimal_macros::dec;
+
+// Re-export deployment presets
+pub use config::Environment;
+
 // Re-export clients
 pub use client::{AuthenticatedClient, ClobClient, DataClient, GammaClient, TradingClient};
 
 // Re-export websocket clients
 pub use websocket::{MarketWsClient, UserWsClient};
 
+// Re-export on-chain client
+pub use onchain::OnchainClient;
+
 // Re-export order builder
 pub use orders::OrderBuilder;
 
@@ -0,0 +1,210 @@
+use super::client::{ContractCall, OnchainClient};
+use super::contracts::{IERC1155, IERC20};
+use super::rpc::TransactionReceipt;
+use crate::error::{Error, Result};
+use crate::types::SignatureType;
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_sol_types::SolCall;
+
+/// Allowances below this are treated as missing and re-approved to `U256::MAX`
+///
+/// Unlimited approvals are only decremented by actual fills, so anything in
+/// the upper half of the range is still effectively unlimited.
+const MIN_ALLOWANCE: U256 = U256::from_limbs([0, 0, 0, 1 << 63]);
+
+/// Approval state of a single spender contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpenderApproval {
+    /// The contract that needs the approvals
+    pub spender: Address,
+    /// USDC the spender may transfer on the owner's behalf
+    pub usdc_allowance: U256,
+    /// Whether the spender is an approved operator of the owner's outcome tokens
+    pub ctf_approved: bool,
+}
+
+impl SpenderApproval {
+    /// Whether the USDC allowance is effectively unlimited
+    pub fn usdc_approved(&self) -> bool {
+        self.usdc_allowance >= MIN_ALLOWANCE
+    }
+
+    /// Whether both approvals are in place
+    pub fn is_ready(&self) -> bool {
+        self.usdc_approved() && self.ctf_approved
+    }
+}
+
+/// Approval state of a wallet against every Polymarket contract that trades on its behalf
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalStatus {
+    /// The wallet holding the funds
+    pub owner: Address,
+    /// One entry per spender: Exchange, NegRiskExchange and, where deployed,
+    /// NegRiskAdapter
+    pub spenders: Vec<SpenderApproval>,
+}
+
+impl ApprovalStatus {
+    /// Whether the wallet can trade both standard and negative-risk markets
+    pub fn is_ready(&self) -> bool {
+        self.spenders.iter().all(SpenderApproval::is_ready)
+    }
+}
+
+impl OnchainClient {
+    /// Contracts that must be approved before a wallet can trade
+    fn spenders(&self) -> Vec<Address> {
+        let exchanges = [self.exchange, self.neg_risk_exchange];
+        exchanges.into_iter().chain(self.neg_risk_adapter).collect()
+    }
+
+    /// Check the USDC and outcome-token approvals a wallet has granted
+    ///
+    /// # Arguments
+    /// * `owner` - The wallet holding the funds: the signer's address for EOA
+    ///   accounts, or the proxy/Safe address for proxy wallets
+    pub async fn check_approvals(&self, owner: Address) -> Result<ApprovalStatus> {
+        let mut spenders = Vec::with_capacity(3);
+
+        for spender in self.spenders() {
+            let call = IERC20::allowanceCall { owner, spender };
+            let data = self
+                .rpc
+                .call(self.collateral, &Bytes::from(call.abi_encode()))
+                .await?;
+            let usdc_allowance = IERC20::allowanceCall::abi_decode_returns(&data, true)
+                .map_err(|e| Error::InvalidParameter(format!("Invalid allowance response: {}", e)))?
+                ._0;
+
+            let call = IERC1155::isApprovedForAllCall {
+                account: owner,
+                operator: spender,
+            };
+            let data = self
+                .rpc
+                .call(self.conditional_tokens, &Bytes::from(call.abi_encode()))
+                .await?;
+            let ctf_approved = IERC1155::isApprovedForAllCall::abi_decode_returns(&data, true)
+                .map_err(|e| {
+                    Error::InvalidParameter(format!("Invalid isApprovedForAll response: {}", e))
+                })?
+                ._0;
+
+            spenders.push(SpenderApproval {
+                spender,
+                usdc_allowance,
+                ctf_approved,
+            });
+        }
+
+        Ok(ApprovalStatus { owner, spenders })
+    }
+
+    /// Build the calls that grant every approval missing from `status`
+    ///
+    /// The calls must be sent from `status.owner`. For proxy wallets, submit
+    /// them through the wallet itself rather than [`OnchainClient::send`].
+    pub fn approval_calls(&self, status: &ApprovalStatus) -> Vec<ContractCall> {
+        let mut calls = Vec::new();
+
+        for approval in &status.spenders {
+            if !approval.usdc_approved() {
+                let call = IERC20::approveCall {
+                    spender: approval.spender,
+                    amount: U256::MAX,
+                };
+                calls.push(ContractCall::new(self.collateral, call.abi_encode()));
+            }
+            if !approval.ctf_approved {
+                let call = IERC1155::setApprovalForAllCall {
+                    operator: approval.spender,
+                    approved: true,
+                };
+                calls.push(ContractCall::new(
+                    self.conditional_tokens,
+                    call.abi_encode(),
+                ));
+            }
+        }
+
+        calls
+    }
+
+    /// Check and grant every approval the signer's wallet needs to trade
+    ///
+    /// Only EOA wallets can be set up directly, since the approvals must come
+    /// from the wallet that holds the funds. For proxy and Safe wallets, use
+    /// [`OnchainClient::check_approvals`] and [`OnchainClient::approval_calls`]
+    /// and submit the calls through the wallet.
+    ///
+    /// # Returns
+    /// Receipts of the approval transactions sent; empty if the wallet was already ready
+    pub async fn ensure_approvals(
+        &self,
+        signature_type: SignatureType,
+    ) -> Result<Vec<TransactionReceipt>> {
+        if signature_type != SignatureType::Eoa {
+            return Err(Error::InvalidParameter(format!(
+                "Approvals for {:?} wallets must be sent through the wallet itself",
+                signature_type
+            )));
+        }
+
+        let status = self.check_approvals(self.address()).await?;
+        let mut receipts = Vec::new();
+        for call in self.approval_calls(&status) {
+            receipts.push(self.send(&call).await?);
+        }
+        Ok(receipts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[test]
+    fn test_approval_calls_cover_missing_approvals() {
+        let client =
+            OnchainClient::new("http://localhost:8545", PrivateKeySigner::random(), 137).unwrap();
+        let spenders = client.spenders();
+
+        let status = ApprovalStatus {
+            owner: client.address(),
+            spenders: vec![
+                SpenderApproval {
+                    spender: spenders[0],
+                    usdc_allowance: U256::MAX - U256::from(1_000_000u64),
+                    ctf_approved: true,
+                },
+                SpenderApproval {
+                    spender: spenders[1],
+                    usdc_allowance: U256::ZERO,
+                    ctf_approved: true,
+                },
+                SpenderApproval {
+                    spender: spenders[2],
+                    usdc_allowance: U256::MAX,
+                    ctf_approved: false,
+                },
+            ],
+        };
+        assert!(!status.is_ready());
+        assert!(status.spenders[0].is_ready());
+
+        let calls = client.approval_calls(&status);
+        assert_eq!(calls.len(), 2);
+
+        assert_eq!(calls[0].to, client.collateral);
+        let approve = IERC20::approveCall::abi_decode(&calls[0].data, true).unwrap();
+        assert_eq!(approve.spender, spenders[1]);
+        assert_eq!(approve.amount, U256::MAX);
+
+        assert_eq!(calls[1].to, client.conditional_tokens);
+        let set = IERC1155::setApprovalForAllCall::abi_decode(&calls[1].data, true).unwrap();
+        assert_eq!(set.operator, spenders[2]);
+        assert!(set.approved);
+    }
+}
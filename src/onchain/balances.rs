@@ -0,0 +1,146 @@
+use super::client::{amount_to_units, units_to_amount, ContractCall, OnchainClient};
+use super::contracts::{IERC1155, IERC20};
+use crate::error::{Error, Result};
+use crate::types::TokenId;
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_sol_types::SolCall;
+use rust_decimal::Decimal;
+
+/// Parse a decimal CLOB token ID into its ERC1155 position ID
+pub(crate) fn token_id_to_u256(token_id: &TokenId) -> Result<U256> {
+    U256::from_str_radix(token_id.as_str(), 10)
+        .map_err(|e| Error::InvalidParameter(format!("Invalid token_id {}: {}", token_id, e)))
+}
+
+impl OnchainClient {
+    /// Get the USDC balance of an address
+    ///
+    /// # Arguments
+    /// * `owner` - The wallet to query (signer, proxy or Safe address)
+    ///
+    /// # Returns
+    /// The balance in USDC (e.g. `12.5` for $12.50)
+    pub async fn usdc_balance(&self, owner: Address) -> Result<Decimal> {
+        let call = IERC20::balanceOfCall { account: owner };
+        let data = self
+            .rpc
+            .call(self.collateral, &Bytes::from(call.abi_encode()))
+            .await?;
+        let balance = IERC20::balanceOfCall::abi_decode_returns(&data, true)
+            .map_err(|e| Error::InvalidParameter(format!("Invalid balanceOf response: {}", e)))?
+            ._0;
+        units_to_amount(balance)
+    }
+
+    /// Get the outcome-token balance of an address for a single token ID
+    ///
+    /// # Returns
+    /// The number of shares held
+    pub async fn token_balance(&self, owner: Address, token_id: &TokenId) -> Result<Decimal> {
+        let call = IERC1155::balanceOfCall {
+            account: owner,
+            id: token_id_to_u256(token_id)?,
+        };
+        let data = self
+            .rpc
+            .call(self.conditional_tokens, &Bytes::from(call.abi_encode()))
+            .await?;
+        let balance = IERC1155::balanceOfCall::abi_decode_returns(&data, true)
+            .map_err(|e| Error::InvalidParameter(format!("Invalid balanceOf response: {}", e)))?
+            ._0;
+        units_to_amount(balance)
+    }
+
+    /// Get the outcome-token balances of an address for several token IDs in one call
+    ///
+    /// # Returns
+    /// One balance per token ID, in the same order
+    pub async fn token_balances(
+        &self,
+        owner: Address,
+        token_ids: &[TokenId],
+    ) -> Result<Vec<Decimal>> {
+        if token_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let call = IERC1155::balanceOfBatchCall {
+            accounts: vec![owner; token_ids.len()],
+            ids: token_ids
+                .iter()
+                .map(token_id_to_u256)
+                .collect::<Result<Vec<_>>>()?,
+        };
+        let data = self
+            .rpc
+            .call(self.conditional_tokens, &Bytes::from(call.abi_encode()))
+            .await?;
+        let balances = IERC1155::balanceOfBatchCall::abi_decode_returns(&data, true)
+            .map_err(|e| {
+                Error::InvalidParameter(format!("Invalid balanceOfBatch response: {}", e))
+            })?
+            ._0;
+
+        balances.into_iter().map(units_to_amount).collect()
+    }
+
+    /// Build the call that transfers `amount` USDC to `to`
+    pub fn usdc_transfer_call(&self, to: Address, amount: Decimal) -> Result<ContractCall> {
+        let call = IERC20::transferCall {
+            to,
+            amount: amount_to_units(amount)?,
+        };
+        Ok(ContractCall::new(self.collateral, call.abi_encode()))
+    }
+
+    /// Build the call that transfers `amount` outcome tokens from `from` to `to`
+    ///
+    /// `from` must be the address that sends the call (the signer for EOA
+    /// wallets, the proxy wallet when relayed).
+    pub fn token_transfer_call(
+        &self,
+        from: Address,
+        to: Address,
+        token_id: &TokenId,
+        amount: Decimal,
+    ) -> Result<ContractCall> {
+        let call = IERC1155::safeTransferFromCall {
+            from,
+            to,
+            id: token_id_to_u256(token_id)?,
+            amount: amount_to_units(amount)?,
+            data: Bytes::new(),
+        };
+        Ok(ContractCall::new(
+            self.conditional_tokens,
+            call.abi_encode(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_token_id_to_u256() {
+        let token_id = TokenId::new(
+            "71321045679252212594626385532706912750332728571942532289631379312455583992563",
+        );
+        let id = token_id_to_u256(&token_id).unwrap();
+        assert_eq!(id.to_string(), token_id.as_str());
+
+        assert!(token_id_to_u256(&TokenId::new("0xabc")).is_err());
+    }
+
+    #[test]
+    fn test_units_to_amount() {
+        assert_eq!(
+            units_to_amount(U256::from(12_500_000u64)).unwrap(),
+            dec!(12.5)
+        );
+        assert_eq!(units_to_amount(U256::ZERO).unwrap(), Decimal::ZERO);
+        assert!(units_to_amount(U256::MAX).is_err());
+    }
+}
@@ -0,0 +1,640 @@
+use super::contracts::{IConditionalTokens, INegRiskAdapter};
+use super::rpc::{RpcClient, TransactionReceipt};
+use crate::config::get_contract_config;
+use crate::error::{Error, Result};
+use crate::orders::{try_decimal_to_token_u64, TOKEN_DECIMALS};
+use crate::signing::EthSigner;
+use crate::types::ConditionId;
+use alloy_consensus::{SignableTransaction, TxEip1559, TxEnvelope};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{Address, Bytes, TxKind, B256, U256};
+use alloy_sol_types::SolCall;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Binary partition of a condition into its two outcome slots (index sets 0b01 and 0b10)
+const BINARY_PARTITION: [u8; 2] = [1, 2];
+
+/// Interval between receipt polls while waiting for a transaction to be mined
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Maximum time to wait for a transaction to be mined
+const RECEIPT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// An encoded contract call, ready to be estimated or sent
+#[derive(Debug, Clone)]
+pub struct ContractCall {
+    /// Contract address
+    pub to: Address,
+    /// ABI-encoded calldata
+    pub data: Bytes,
+    /// Native token value sent with the call
+    pub value: U256,
+}
+
+impl ContractCall {
+    pub fn new(to: Address, data: impl Into<Bytes>) -> Self {
+        Self {
+            to,
+            data: data.into(),
+            value: U256::ZERO,
+        }
+    }
+}
+
+/// Parse a contract address from the contract config
+fn parse_address(address: &str, name: &str) -> Result<Address> {
+    Address::from_str(address)
+        .map_err(|e| Error::Config(format!("Invalid {} address: {}", name, e)))
+}
+
+/// Parse a 0x-prefixed condition ID into its bytes32 form
+pub(crate) fn condition_id_to_b256(condition_id: &ConditionId) -> Result<B256> {
+    B256::from_str(condition_id.as_str()).map_err(|e| {
+        Error::InvalidParameter(format!("Invalid condition_id {}: {}", condition_id, e))
+    })
+}
+
+/// Convert a USDC (or outcome share) amount into 6-decimal base units
+pub(crate) fn amount_to_units(amount: Decimal) -> Result<U256> {
+    if amount.is_sign_negative() {
+        return Err(Error::InvalidParameter(format!(
+            "Amount must not be negative, got {}",
+            amount
+        )));
+    }
+    try_decimal_to_token_u64(amount).map(U256::from)
+}
+
+/// Convert 6-decimal base units back into a USDC (or outcome share) amount
+pub(crate) fn units_to_amount(units: U256) -> Result<Decimal> {
+    u128::try_from(units)
+        .ok()
+        .and_then(|units| i128::try_from(units).ok())
+        .and_then(|units| Decimal::try_from_i128_with_scale(units, TOKEN_DECIMALS).ok())
+        .ok_or_else(|| Error::InvalidParameter(format!("Token amount out of range: {}", units)))
+}
+
+/// Convert a strictly positive amount into 6-decimal base units
+fn positive_amount_to_units(amount: Decimal) -> Result<U256> {
+    if amount.is_zero() {
+        return Err(Error::InvalidParameter(
+            "Amount must be positive, got 0".to_string(),
+        ));
+    }
+    amount_to_units(amount)
+}
+
+/// A resolved condition whose winning positions should be redeemed
+#[derive(Debug, Clone)]
+pub struct RedeemRequest {
+    /// The condition ID of the resolved market
+    pub condition_id: ConditionId,
+    /// For negative-risk markets, the (YES, NO) share amounts to redeem;
+    /// `None` for standard markets, which redeem the full balance
+    pub neg_risk_amounts: Option<(Decimal, Decimal)>,
+}
+
+impl RedeemRequest {
+    /// Redeem the full balance of a standard market
+    pub fn new(condition_id: impl Into<ConditionId>) -> Self {
+        Self {
+            condition_id: condition_id.into(),
+            neg_risk_amounts: None,
+        }
+    }
+
+    /// Redeem the given YES and NO amounts of a negative-risk market
+    pub fn neg_risk(condition_id: impl Into<ConditionId>, yes: Decimal, no: Decimal) -> Self {
+        Self {
+            condition_id: condition_id.into(),
+            neg_risk_amounts: Some((yes, no)),
+        }
+    }
+}
+
+/// Result of [`OnchainClient::redeem_positions_batch`]
+#[derive(Debug)]
+pub struct RedeemBatchOutcome {
+    /// Hashes of the broadcast transactions, in request order
+    pub sent: Vec<B256>,
+    /// Receipt of each broadcast transaction, in the same order
+    pub receipts: Vec<Result<TransactionReceipt>>,
+    /// Why the requests after the last sent one weren't broadcast
+    pub error: Option<Error>,
+}
+
+impl RedeemBatchOutcome {
+    /// Whether every request was broadcast and mined successfully
+    pub fn is_complete(&self) -> bool {
+        self.error.is_none() && self.receipts.iter().all(Result::is_ok)
+    }
+}
+
+/// Client for on-chain Conditional Tokens operations
+///
+/// This client signs and submits Polygon transactions from the signer's own
+/// address (EOA), using a JSON-RPC endpoint for gas estimation and broadcast.
+///
+/// Splitting converts USDC into a complete set of outcome tokens (one YES and
+/// one NO per dollar); merging burns a complete set back into USDC. For
+/// negative-risk markets the calls are routed through the NegRiskAdapter.
+///
+/// The signer must have approved the ConditionalTokens contract (or the
+/// NegRiskAdapter) to spend its USDC before splitting; see
+/// [`OnchainClient::ensure_approvals`].
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::onchain::OnchainClient;
+/// use polymarket_rs::{ConditionId, PrivateKeySigner};
+/// use rust_decimal_macros::dec;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let signer = PrivateKeySigner::random();
+/// let client = OnchainClient::new("https://polygon-rpc.com", signer, 137)?;
+///
+/// let condition_id = ConditionId::new("0x...");
+/// let receipt = client.split_position(&condition_id, dec!(10), false).await?;
+/// println!("Split in tx {}", receipt.transaction_hash);
+/// # Ok(())
+/// # }
+/// ```
+pub struct OnchainClient {
+    pub(super) rpc: RpcClient,
+    signer: Box<dyn EthSigner>,
+    pub(super) chain_id: u64,
+    pub(super) collateral: Address,
+    pub(super) conditional_tokens: Address,
+    pub(super) neg_risk_adapter: Option<Address>,
+    pub(super) exchange: Address,
+    pub(super) neg_risk_exchange: Address,
+}
+
+impl OnchainClient {
+    /// Create a new OnchainClient
+    ///
+    /// # Arguments
+    /// * `rpc_url` - JSON-RPC endpoint of a Polygon node
+    /// * `signer` - The Ethereum signer that sends transactions
+    /// * `chain_id` - The chain ID (137 for Polygon, 80002 for Amoy testnet)
+    pub fn new(
+        rpc_url: impl Into<String>,
+        signer: impl EthSigner + 'static,
+        chain_id: u64,
+    ) -> Result<Self> {
+        let config = get_contract_config(chain_id, false)?;
+        let neg_risk_config = get_contract_config(chain_id, true)?;
+
+        Ok(Self {
+            rpc: RpcClient::new(rpc_url),
+            signer: Box::new(signer),
+            chain_id,
+            collateral: parse_address(&config.collateral, "collateral")?,
+            conditional_tokens: parse_address(&config.conditional_tokens, "conditional tokens")?,
+            neg_risk_adapter: config
+                .neg_risk_adapter
+                .as_deref()
+                .map(|address| parse_address(address, "neg risk adapter"))
+                .transpose()?,
+            exchange: parse_address(&config.exchange, "exchange")?,
+            neg_risk_exchange: parse_address(&neg_risk_config.exchange, "neg risk exchange")?,
+        })
+    }
+
+    /// Get the address that signs and sends transactions
+    pub fn address(&self) -> Address {
+        self.signer.address()
+    }
+
+    /// Get the underlying JSON-RPC client
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    /// The NegRiskAdapter, which negative-risk operations go through
+    pub(super) fn neg_risk_adapter(&self) -> Result<Address> {
+        self.neg_risk_adapter.ok_or_else(|| {
+            Error::Config(format!(
+                "No NegRiskAdapter address is known for chain {}",
+                self.chain_id
+            ))
+        })
+    }
+
+    /// Build the call that splits `amount` USDC into a complete set of outcome tokens
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID of the market
+    /// * `amount` - Amount of USDC to split (e.g. `10` for $10)
+    /// * `neg_risk` - Whether the market uses the negative-risk adapter
+    pub fn split_position_call(
+        &self,
+        condition_id: &ConditionId,
+        amount: Decimal,
+        neg_risk: bool,
+    ) -> Result<ContractCall> {
+        let condition_id = condition_id_to_b256(condition_id)?;
+        let amount = positive_amount_to_units(amount)?;
+
+        if neg_risk {
+            let call = INegRiskAdapter::splitPositionCall {
+                conditionId: condition_id,
+                amount,
+            };
+            Ok(ContractCall::new(
+                self.neg_risk_adapter()?,
+                call.abi_encode(),
+            ))
+        } else {
+            let call = IConditionalTokens::splitPositionCall {
+                collateralToken: self.collateral,
+                parentCollectionId: B256::ZERO,
+                conditionId: condition_id,
+                partition: BINARY_PARTITION.iter().map(|&i| U256::from(i)).collect(),
+                amount,
+            };
+            Ok(ContractCall::new(
+                self.conditional_tokens,
+                call.abi_encode(),
+            ))
+        }
+    }
+
+    /// Build the call that merges `amount` complete sets back into USDC
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID of the market
+    /// * `amount` - Number of complete sets (YES + NO pairs) to merge
+    /// * `neg_risk` - Whether the market uses the negative-risk adapter
+    pub fn merge_positions_call(
+        &self,
+        condition_id: &ConditionId,
+        amount: Decimal,
+        neg_risk: bool,
+    ) -> Result<ContractCall> {
+        let condition_id = condition_id_to_b256(condition_id)?;
+        let amount = positive_amount_to_units(amount)?;
+
+        if neg_risk {
+            let call = INegRiskAdapter::mergePositionsCall {
+                conditionId: condition_id,
+                amount,
+            };
+            Ok(ContractCall::new(
+                self.neg_risk_adapter()?,
+                call.abi_encode(),
+            ))
+        } else {
+            let call = IConditionalTokens::mergePositionsCall {
+                collateralToken: self.collateral,
+                parentCollectionId: B256::ZERO,
+                conditionId: condition_id,
+                partition: BINARY_PARTITION.iter().map(|&i| U256::from(i)).collect(),
+                amount,
+            };
+            Ok(ContractCall::new(
+                self.conditional_tokens,
+                call.abi_encode(),
+            ))
+        }
+    }
+
+    /// Build the call that redeems winning positions of a resolved condition
+    ///
+    /// Standard markets redeem the signer's entire balance of both outcomes
+    /// through the ConditionalTokens contract. Negative-risk markets go
+    /// through the NegRiskAdapter, which needs the amounts to redeem.
+    pub fn redeem_positions_call(&self, request: &RedeemRequest) -> Result<ContractCall> {
+        let condition_id = condition_id_to_b256(&request.condition_id)?;
+
+        match request.neg_risk_amounts {
+            Some((yes, no)) => {
+                let call = INegRiskAdapter::redeemPositionsCall {
+                    conditionId: condition_id,
+                    amounts: vec![amount_to_units(yes)?, amount_to_units(no)?],
+                };
+                Ok(ContractCall::new(
+                    self.neg_risk_adapter()?,
+                    call.abi_encode(),
+                ))
+            }
+            None => {
+                let call = IConditionalTokens::redeemPositionsCall {
+                    collateralToken: self.collateral,
+                    parentCollectionId: B256::ZERO,
+                    conditionId: condition_id,
+                    indexSets: BINARY_PARTITION.iter().map(|&i| U256::from(i)).collect(),
+                };
+                Ok(ContractCall::new(
+                    self.conditional_tokens,
+                    call.abi_encode(),
+                ))
+            }
+        }
+    }
+
+    /// Split USDC into a complete set of outcome tokens and wait for the receipt
+    pub async fn split_position(
+        &self,
+        condition_id: &ConditionId,
+        amount: Decimal,
+        neg_risk: bool,
+    ) -> Result<TransactionReceipt> {
+        let call = self.split_position_call(condition_id, amount, neg_risk)?;
+        self.send(&call).await
+    }
+
+    /// Merge complete sets of outcome tokens back into USDC and wait for the receipt
+    pub async fn merge_positions(
+        &self,
+        condition_id: &ConditionId,
+        amount: Decimal,
+        neg_risk: bool,
+    ) -> Result<TransactionReceipt> {
+        let call = self.merge_positions_call(condition_id, amount, neg_risk)?;
+        self.send(&call).await
+    }
+
+    /// Redeem winning positions of a resolved condition and wait for the receipt
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID of the resolved market
+    /// * `neg_risk_amounts` - (YES, NO) amounts for negative-risk markets, `None` otherwise
+    pub async fn redeem_positions(
+        &self,
+        condition_id: &ConditionId,
+        neg_risk_amounts: Option<(Decimal, Decimal)>,
+    ) -> Result<TransactionReceipt> {
+        let request = RedeemRequest {
+            condition_id: condition_id.clone(),
+            neg_risk_amounts,
+        };
+        let call = self.redeem_positions_call(&request)?;
+        self.send(&call).await
+    }
+
+    /// Redeem several resolved conditions at once
+    ///
+    /// An EOA cannot bundle calls to the ConditionalTokens contract into one
+    /// transaction (the contract only redeems for `msg.sender`), so each
+    /// redemption is its own transaction. All calls are encoded and validated
+    /// up front, broadcast back-to-back with consecutive nonces from a single
+    /// nonce lookup, and then awaited together, so the batch confirms in
+    /// roughly one block time.
+    ///
+    /// An error is returned only when nothing was broadcast. If a later
+    /// transaction fails to send, the batch stops there and the outcome
+    /// carries the hashes already broadcast alongside the error.
+    pub async fn redeem_positions_batch(
+        &self,
+        requests: &[RedeemRequest],
+    ) -> Result<RedeemBatchOutcome> {
+        let calls = requests
+            .iter()
+            .map(|request| self.redeem_positions_call(request))
+            .collect::<Result<Vec<_>>>()?;
+
+        let first_nonce = self.rpc.transaction_count(self.address()).await?;
+        let mut sent = Vec::with_capacity(calls.len());
+        let mut error = None;
+        for (nonce, call) in (first_nonce..).zip(&calls) {
+            match self.broadcast(call, nonce).await {
+                Ok(hash) => sent.push(hash),
+                Err(e) if sent.is_empty() => return Err(e),
+                Err(e) => {
+                    error = Some(e);
+                    break;
+                }
+            }
+        }
+
+        let mut receipts = Vec::with_capacity(sent.len());
+        for hash in &sent {
+            receipts.push(self.wait_for_receipt(*hash).await);
+        }
+        Ok(RedeemBatchOutcome {
+            sent,
+            receipts,
+            error,
+        })
+    }
+
+    /// Execute a read-only contract call and decode its return values
+    pub(super) async fn view<C: SolCall>(&self, to: Address, call: C) -> Result<C::Return> {
+        let data = self.rpc.call(to, &Bytes::from(call.abi_encode())).await?;
+        C::abi_decode_returns(&data, true).map_err(|e| {
+            Error::InvalidParameter(format!("Invalid {} response: {}", C::SIGNATURE, e))
+        })
+    }
+
+    /// Estimate the gas a call would use when sent from the signer's address
+    ///
+    /// A call that would revert (e.g. missing approval or insufficient balance)
+    /// fails here with [`Error::Rpc`] before any transaction is broadcast.
+    pub async fn estimate_gas(&self, call: &ContractCall) -> Result<u64> {
+        self.rpc
+            .estimate_gas(self.address(), call.to, &call.data, call.value)
+            .await
+    }
+
+    /// Sign and broadcast a call, then wait for it to be mined
+    ///
+    /// The gas limit is the node's estimate plus 20% headroom. Returns
+    /// [`Error::TransactionReverted`] if the transaction is mined but fails.
+    pub async fn send(&self, call: &ContractCall) -> Result<TransactionReceipt> {
+        let hash = self.send_without_confirmation(call).await?;
+        self.wait_for_receipt(hash).await
+    }
+
+    /// Sign and broadcast a call without waiting for it to be mined
+    pub async fn send_without_confirmation(&self, call: &ContractCall) -> Result<B256> {
+        let nonce = self.rpc.transaction_count(self.address()).await?;
+        self.broadcast(call, nonce).await
+    }
+
+    /// Sign and broadcast a call with the given nonce
+    async fn broadcast(&self, call: &ContractCall, nonce: u64) -> Result<B256> {
+        let gas_limit = self.estimate_gas(call).await?;
+        let base_fee = self.rpc.base_fee().await?;
+        let priority_fee = self.rpc.max_priority_fee().await?;
+
+        let tx = TxEip1559 {
+            chain_id: self.chain_id,
+            nonce,
+            gas_limit: gas_limit + gas_limit / 5,
+            max_fee_per_gas: base_fee * 2 + priority_fee,
+            max_priority_fee_per_gas: priority_fee,
+            to: TxKind::Call(call.to),
+            value: call.value,
+            access_list: Default::default(),
+            input: call.data.clone(),
+        };
+
+        let signature = self.signer.sign_hash_sync(&tx.signature_hash())?;
+        let envelope: TxEnvelope = tx.into_signed(signature).into();
+
+        self.rpc
+            .send_raw_transaction(&envelope.encoded_2718())
+            .await
+    }
+
+    /// Poll for a transaction receipt until it is mined or the wait times out
+    pub async fn wait_for_receipt(&self, hash: B256) -> Result<TransactionReceipt> {
+        let deadline = tokio::time::Instant::now() + RECEIPT_TIMEOUT;
+
+        loop {
+            if let Some(receipt) = self.rpc.transaction_receipt(hash).await? {
+                if !receipt.status {
+                    return Err(Error::TransactionReverted(hash.to_string()));
+                }
+                return Ok(receipt);
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return Err(Error::TransactionTimeout(hash.to_string()));
+            }
+            tokio::time::sleep(RECEIPT_POLL_INTERVAL).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockHttpServer, RecordedRequest};
+    use alloy_consensus::Transaction;
+    use alloy_eips::eip2718::Decodable2718;
+    use alloy_primitives::hex;
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    const CONDITION_ID: &str = "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1";
+
+    fn client() -> OnchainClient {
+        OnchainClient::new("http://localhost:8545", PrivateKeySigner::random(), 137).unwrap()
+    }
+
+    #[test]
+    fn test_split_call_targets_ctf() {
+        let client = client();
+        let call = client
+            .split_position_call(&ConditionId::new(CONDITION_ID), dec!(10), false)
+            .unwrap();
+
+        assert_eq!(call.to, client.conditional_tokens);
+        assert_eq!(
+            &call.data[..4],
+            &IConditionalTokens::splitPositionCall::SELECTOR[..]
+        );
+
+        let decoded = IConditionalTokens::splitPositionCall::abi_decode(&call.data, true).unwrap();
+        assert_eq!(decoded.amount, U256::from(10_000_000u64));
+        assert_eq!(decoded.partition, vec![U256::from(1), U256::from(2)]);
+    }
+
+    #[test]
+    fn test_merge_call_targets_adapter_for_neg_risk() {
+        let client = client();
+        let call = client
+            .merge_positions_call(&ConditionId::new(CONDITION_ID), dec!(2.5), true)
+            .unwrap();
+
+        assert_eq!(call.to, client.neg_risk_adapter().unwrap());
+        let decoded = INegRiskAdapter::mergePositionsCall::abi_decode(&call.data, true).unwrap();
+        assert_eq!(decoded.amount, U256::from(2_500_000u64));
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let client = client();
+        assert!(client
+            .split_position_call(&ConditionId::new("not-hex"), dec!(1), false)
+            .is_err());
+        assert!(client
+            .split_position_call(&ConditionId::new(CONDITION_ID), dec!(0), false)
+            .is_err());
+        // No adapter is configured on the testnet
+        let amoy =
+            OnchainClient::new("http://localhost:8545", PrivateKeySigner::random(), 80002).unwrap();
+        assert!(matches!(
+            amoy.merge_positions_call(&ConditionId::new(CONDITION_ID), dec!(1), true),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[test]
+    fn test_redeem_calls() {
+        let client = client();
+
+        let call = client
+            .redeem_positions_call(&RedeemRequest::new(CONDITION_ID))
+            .unwrap();
+        assert_eq!(call.to, client.conditional_tokens);
+        let decoded =
+            IConditionalTokens::redeemPositionsCall::abi_decode(&call.data, true).unwrap();
+        assert_eq!(decoded.indexSets, vec![U256::from(1), U256::from(2)]);
+
+        let call = client
+            .redeem_positions_call(&RedeemRequest::neg_risk(CONDITION_ID, dec!(5), dec!(0)))
+            .unwrap();
+        assert_eq!(call.to, client.neg_risk_adapter().unwrap());
+        let decoded = INegRiskAdapter::redeemPositionsCall::abi_decode(&call.data, true).unwrap();
+        assert_eq!(decoded.amounts, vec![U256::from(5_000_000u64), U256::ZERO]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_keeps_sent_hashes_when_a_send_fails() {
+        let server = MockHttpServer::start().await.unwrap();
+        let hash = B256::repeat_byte(0x11);
+        server.mock_rpc("eth_getTransactionCount", &"0x7");
+        server.mock_rpc("eth_estimateGas", &"0x5208");
+        server.mock_rpc("eth_getBlockByNumber", &json!({ "baseFeePerGas": "0x1" }));
+        server.mock_rpc("eth_maxPriorityFeePerGas", &"0x1");
+        server.mock_rpc_error("eth_sendRawTransaction", -32000, "connection lost");
+        server.mock_rpc_once("eth_sendRawTransaction", &hash);
+        server.mock_rpc(
+            "eth_getTransactionReceipt",
+            &json!({ "transactionHash": hash, "blockNumber": "0x1", "gasUsed": "0x5208", "status": "0x1" }),
+        );
+        let client = OnchainClient::new(server.url(), PrivateKeySigner::random(), 137).unwrap();
+
+        let requests = [
+            RedeemRequest::new(CONDITION_ID),
+            RedeemRequest::new(CONDITION_ID),
+        ];
+        let outcome = client.redeem_positions_batch(&requests).await.unwrap();
+        assert_eq!(outcome.sent, [hash]);
+        assert!(outcome.receipts[0].is_ok());
+        assert!(matches!(
+            outcome.error,
+            Some(Error::Rpc { code: -32000, .. })
+        ));
+        assert!(!outcome.is_complete());
+
+        // One nonce lookup, then consecutive nonces
+        let requests = server.requests();
+        let rpc = |request: &RecordedRequest| request.json::<serde_json::Value>().unwrap();
+        let nonces: Vec<u64> = requests
+            .iter()
+            .map(rpc)
+            .filter(|body| body["method"] == "eth_sendRawTransaction")
+            .map(|body| {
+                let raw = hex::decode(body["params"][0].as_str().unwrap()).unwrap();
+                TxEnvelope::decode_2718(&mut raw.as_slice())
+                    .unwrap()
+                    .nonce()
+            })
+            .collect();
+        assert_eq!(nonces, [7, 8]);
+        let lookups = requests
+            .iter()
+            .filter(|request| rpc(request)["method"] == "eth_getTransactionCount")
+            .count();
+        assert_eq!(lookups, 1);
+    }
+}
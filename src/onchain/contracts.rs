@@ -0,0 +1,115 @@
+use alloy_sol_types::sol;
+
+// Gnosis Conditional Tokens Framework (ERC1155 outcome tokens)
+sol! {
+    interface IConditionalTokens {
+        function splitPosition(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+
+        function mergePositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+
+        function redeemPositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] indexSets
+        ) external;
+
+        function getOutcomeSlotCount(bytes32 conditionId) external view returns (uint256);
+
+        function payoutNumerators(bytes32 conditionId, uint256 index) external view returns (uint256);
+
+        function payoutDenominator(bytes32 conditionId) external view returns (uint256);
+    }
+}
+
+// Polymarket adapter that wraps the CTF for negative-risk (multi-outcome) markets
+sol! {
+    interface INegRiskAdapter {
+        function splitPosition(bytes32 conditionId, uint256 amount) external;
+
+        function mergePositions(bytes32 conditionId, uint256 amount) external;
+
+        function redeemPositions(bytes32 conditionId, uint256[] amounts) external;
+    }
+}
+
+// Collateral token (USDC) balances, transfers and approvals
+sol! {
+    interface IERC20 {
+        function balanceOf(address account) external view returns (uint256);
+
+        function transfer(address to, uint256 amount) external returns (bool);
+
+        function allowance(address owner, address spender) external view returns (uint256);
+
+        function approve(address spender, uint256 amount) external returns (bool);
+    }
+}
+
+// Outcome token (ERC1155) balances, transfers and operator approvals
+sol! {
+    interface IERC1155 {
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+
+        function balanceOfBatch(
+            address[] accounts,
+            uint256[] ids
+        ) external view returns (uint256[]);
+
+        function safeTransferFrom(
+            address from,
+            address to,
+            uint256 id,
+            uint256 amount,
+            bytes data
+        ) external;
+
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+
+        function setApprovalForAll(address operator, bool approved) external;
+    }
+}
+
+// Polymarket proxy wallet factory, which forwards batched calls to a user's proxy wallet
+sol! {
+    interface IProxyWalletFactory {
+        struct ProxyCall {
+            uint8 typeCode;
+            address to;
+            uint256 value;
+            bytes data;
+        }
+
+        function proxy(ProxyCall[] calls) external payable returns (bytes[] returnValues);
+    }
+}
+
+// Uniswap V3 SwapRouter, used to convert between native USDC and bridged USDC.e
+sol! {
+    interface ISwapRouter {
+        struct ExactInputSingleParams {
+            address tokenIn;
+            address tokenOut;
+            uint24 fee;
+            address recipient;
+            uint256 deadline;
+            uint256 amountIn;
+            uint256 amountOutMinimum;
+            uint160 sqrtPriceLimitX96;
+        }
+
+        function exactInputSingle(ExactInputSingleParams params) external payable returns (uint256 amountOut);
+    }
+}
@@ -0,0 +1,226 @@
+use super::client::{amount_to_units, units_to_amount, ContractCall, OnchainClient};
+use super::contracts::{ISwapRouter, IERC20};
+use super::rpc::TransactionReceipt;
+use crate::config::chains;
+use crate::error::{Error, Result};
+use alloy_primitives::aliases::{U160, U24};
+use alloy_primitives::{address, Address, U256};
+use alloy_sol_types::SolCall;
+use rust_decimal::Decimal;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Circle's native USDC on Polygon mainnet
+///
+/// Exchanges and bridges increasingly pay out native USDC, while the CLOB
+/// settles in bridged USDC.e (the configured collateral).
+const NATIVE_USDC: Address = address!("3c499c542cEF5E3811e1192ce70d8cC03d5c3359");
+
+/// Uniswap V3 SwapRouter on Polygon mainnet
+const SWAP_ROUTER: Address = address!("E592427A0AEce92De3Edee1F18E0157C05861564");
+
+/// Fee tier of the USDC/USDC.e pool, in hundredths of a basis point (0.01%)
+const USDC_POOL_FEE: u32 = 100;
+
+/// How long a conversion may wait in the mempool before the router rejects it
+const SWAP_DEADLINE: Duration = Duration::from_secs(600);
+
+/// Direction of a conversion between the two USDC tokens on Polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsdcConversion {
+    /// Native USDC into bridged USDC.e, the collateral the CLOB trades in
+    NativeToBridged,
+    /// Bridged USDC.e back into native USDC, e.g. before withdrawing to an exchange
+    BridgedToNative,
+}
+
+impl OnchainClient {
+    /// Address of native USDC, on chains where it can be converted to collateral
+    fn native_usdc(&self) -> Result<Address> {
+        if self.chain_id != chains::POLYGON_MAINNET {
+            return Err(Error::Config(format!(
+                "Native USDC conversion is only available on Polygon mainnet, not chain {}",
+                self.chain_id
+            )));
+        }
+        Ok(NATIVE_USDC)
+    }
+
+    /// Get the native (Circle) USDC balance of an address
+    ///
+    /// Only supported on Polygon mainnet. See [`usdc_balance`](Self::usdc_balance)
+    /// for the bridged USDC.e the CLOB trades in.
+    pub async fn native_usdc_balance(&self, owner: Address) -> Result<Decimal> {
+        let call = IERC20::balanceOfCall { account: owner };
+        let balance = self.view(self.native_usdc()?, call).await?._0;
+        units_to_amount(balance)
+    }
+
+    /// Build the calls that convert `amount` USDC in the given direction
+    ///
+    /// Returns an approval of the swap router for exactly `amount` followed
+    /// by the swap through the 0.01% USDC/USDC.e pool. The converted tokens
+    /// go to `recipient`; the swap reverts if fewer than `min_received`
+    /// would arrive.
+    pub fn usdc_conversion_calls(
+        &self,
+        conversion: UsdcConversion,
+        amount: Decimal,
+        min_received: Decimal,
+        recipient: Address,
+    ) -> Result<Vec<ContractCall>> {
+        let native = self.native_usdc()?;
+        let (token_in, token_out) = match conversion {
+            UsdcConversion::NativeToBridged => (native, self.collateral),
+            UsdcConversion::BridgedToNative => (self.collateral, native),
+        };
+        let amount_in = amount_to_units(amount)?;
+        if amount_in.is_zero() {
+            return Err(Error::InvalidParameter(
+                "Amount must be positive, got 0".to_string(),
+            ));
+        }
+
+        let deadline = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            + SWAP_DEADLINE;
+
+        let approve = IERC20::approveCall {
+            spender: SWAP_ROUTER,
+            amount: amount_in,
+        };
+        let swap = ISwapRouter::exactInputSingleCall {
+            params: ISwapRouter::ExactInputSingleParams {
+                tokenIn: token_in,
+                tokenOut: token_out,
+                fee: U24::from(USDC_POOL_FEE),
+                recipient,
+                deadline: U256::from(deadline.as_secs()),
+                amountIn: amount_in,
+                amountOutMinimum: amount_to_units(min_received)?,
+                sqrtPriceLimitX96: U160::ZERO,
+            },
+        };
+
+        Ok(vec![
+            ContractCall::new(token_in, approve.abi_encode()),
+            ContractCall::new(SWAP_ROUTER, swap.abi_encode()),
+        ])
+    }
+
+    /// Convert `amount` USDC in the given direction and wait for the receipts
+    ///
+    /// The converted tokens stay with the signer. The swap reverts if the
+    /// pool would pay out less than `amount × (1 − max_slippage)`, e.g. with
+    /// `max_slippage` of `0.001` for 0.1%.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::onchain::{OnchainClient, UsdcConversion};
+    /// # use rust_decimal_macros::dec;
+    /// # async fn example(client: &OnchainClient) -> polymarket_rs::Result<()> {
+    /// let native = client.native_usdc_balance(client.address()).await?;
+    /// client
+    ///     .convert_usdc(UsdcConversion::NativeToBridged, native, dec!(0.001))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_usdc(
+        &self,
+        conversion: UsdcConversion,
+        amount: Decimal,
+        max_slippage: Decimal,
+    ) -> Result<Vec<TransactionReceipt>> {
+        if max_slippage.is_sign_negative() || max_slippage >= Decimal::ONE {
+            return Err(Error::InvalidParameter(format!(
+                "max_slippage must be in [0, 1), got {}",
+                max_slippage
+            )));
+        }
+        let min_received = amount * (Decimal::ONE - max_slippage);
+
+        let calls = self.usdc_conversion_calls(conversion, amount, min_received, self.address())?;
+        let mut receipts = Vec::with_capacity(calls.len());
+        for call in &calls {
+            receipts.push(self.send(call).await?);
+        }
+        Ok(receipts)
+    }
+
+    /// Transfer `amount` USDC.e from the signer to the wallet that trades
+    ///
+    /// For proxy and Safe accounts, `funder` is the proxy/Safe address the
+    /// CLOB account holds its balance in (the `funder` passed to
+    /// [`OrderBuilder`](crate::OrderBuilder)).
+    pub async fn fund_wallet(
+        &self,
+        funder: Address,
+        amount: Decimal,
+    ) -> Result<TransactionReceipt> {
+        if funder == self.address() {
+            return Err(Error::InvalidParameter(
+                "The signer already holds its own funds; pass the proxy or Safe address"
+                    .to_string(),
+            ));
+        }
+        let call = self.usdc_transfer_call(funder, amount)?;
+        self.send(&call).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_conversion_calls_approve_then_swap() {
+        let client =
+            OnchainClient::new("http://localhost:8545", PrivateKeySigner::random(), 137).unwrap();
+        let recipient = client.address();
+        let calls = client
+            .usdc_conversion_calls(
+                UsdcConversion::NativeToBridged,
+                dec!(25),
+                dec!(24.9),
+                recipient,
+            )
+            .unwrap();
+        assert_eq!(calls.len(), 2);
+
+        assert_eq!(calls[0].to, NATIVE_USDC);
+        let approve = IERC20::approveCall::abi_decode(&calls[0].data, true).unwrap();
+        assert_eq!(approve.spender, SWAP_ROUTER);
+        assert_eq!(approve.amount, U256::from(25_000_000u64));
+
+        assert_eq!(calls[1].to, SWAP_ROUTER);
+        let swap = ISwapRouter::exactInputSingleCall::abi_decode(&calls[1].data, true)
+            .unwrap()
+            .params;
+        assert_eq!(swap.tokenIn, NATIVE_USDC);
+        assert_eq!(swap.tokenOut, client.collateral);
+        assert_eq!(swap.recipient, recipient);
+        assert_eq!(swap.amountOutMinimum, U256::from(24_900_000u64));
+
+        let calls = client
+            .usdc_conversion_calls(UsdcConversion::BridgedToNative, dec!(1), dec!(1), recipient)
+            .unwrap();
+        assert_eq!(calls[0].to, client.collateral);
+    }
+
+    #[test]
+    fn test_conversion_is_mainnet_only() {
+        let client =
+            OnchainClient::new("http://localhost:8545", PrivateKeySigner::random(), 80002).unwrap();
+        let result = client.usdc_conversion_calls(
+            UsdcConversion::NativeToBridged,
+            dec!(1),
+            dec!(1),
+            client.address(),
+        );
+        assert!(matches!(result, Err(Error::Config(_))));
+    }
+}
@@ -0,0 +1,37 @@
+//! On-chain operations against the Polymarket contracts on Polygon.
+//!
+//! [`OnchainClient`] signs and submits transactions directly from the signer's
+//! address through a JSON-RPC endpoint. It covers the Conditional Tokens
+//! operations that the CLOB API cannot perform:
+//! - Splitting USDC into complete sets of outcome tokens
+//! - Merging complete sets back into USDC
+//! - Redeeming winning positions after a market resolves
+//! - Checking and granting the token approvals needed to trade
+//! - Reading USDC and outcome-token balances straight from chain state
+//! - Funding accounts: converting native USDC into USDC.e collateral and
+//!   moving it to a proxy or Safe wallet
+//! - Querying and watching market resolution (payout vectors)
+//!
+//! Proxy wallets (email/magic logins) cannot send transactions themselves;
+//! [`RelayerClient`] executes the same calls for them gaslessly through the
+//! Polymarket relayer.
+//!
+//! Negative-risk markets are routed through the NegRiskAdapter automatically
+//! when `neg_risk` is set.
+
+mod approvals;
+mod balances;
+mod client;
+mod contracts;
+mod funding;
+mod relayer;
+mod resolution;
+mod rpc;
+
+pub use approvals::{ApprovalStatus, SpenderApproval};
+pub use client::{ContractCall, OnchainClient, RedeemBatchOutcome, RedeemRequest};
+pub use contracts::{IConditionalTokens, INegRiskAdapter, ISwapRouter, IERC1155, IERC20};
+pub use funding::UsdcConversion;
+pub use relayer::{RelayPayload, RelayerClient, RelayerTransaction, RelayerTransactionState};
+pub use resolution::Resolution;
+pub use rpc::{RpcClient, TransactionReceipt};
@@ -0,0 +1,484 @@
+use super::client::{ContractCall, OnchainClient, RedeemRequest};
+use super::contracts::IProxyWalletFactory;
+use crate::config::chains;
+use crate::error::{Error, Result};
+use crate::http::{create_builder_headers, HttpClient};
+use crate::request::encode_query_value;
+use crate::signing::EthSigner;
+use crate::types::ApiCreds;
+use alloy_primitives::hex::encode_prefixed;
+use alloy_primitives::{address, keccak256, Address, Bytes, B256, U256};
+use alloy_sol_types::SolCall;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Default Polymarket relayer endpoint
+const DEFAULT_RELAYER_URL: &str = "https://relayer-v2.polymarket.com";
+
+/// Proxy wallet factory on Polygon mainnet; relayed transactions are sent to it
+const PROXY_WALLET_FACTORY: Address = address!("aB45c5A4B0c941a2F231C04C3f49182e1A254052");
+
+/// Relay hub that pays gas for relayed proxy transactions on Polygon mainnet
+const RELAY_HUB: Address = address!("D216153c06E857cD7f72665E0aF1d7D82172F494");
+
+/// Gas limit signed into relayed transactions
+const DEFAULT_GAS_LIMIT: u64 = 10_000_000;
+
+/// Proxy call type code for a plain CALL
+const CALL_TYPE_CODE: u8 = 1;
+
+/// Interval between status polls while waiting for a relayed transaction
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Maximum time to wait for a relayed transaction to be mined
+const POLL_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Relayer transaction type for Polymarket proxy (email/magic) wallets
+const PROXY_TRANSACTION_TYPE: &str = "PROXY";
+
+/// Relay address and nonce to sign a relayed transaction against
+#[derive(Debug, Clone, Deserialize)]
+pub struct RelayPayload {
+    /// Relay that will submit the transaction
+    pub address: Address,
+    /// Next relay nonce of the signer
+    pub nonce: String,
+}
+
+/// Gas parameters signed into a relayed transaction
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SignatureParams {
+    gas_price: String,
+    gas_limit: String,
+    relayer_fee: String,
+    relay_hub: Address,
+    relay: Address,
+}
+
+/// Body of a relayer submission
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionRequest {
+    #[serde(rename = "type")]
+    transaction_type: &'static str,
+    from: Address,
+    to: Address,
+    proxy_wallet: Address,
+    data: String,
+    nonce: String,
+    signature: String,
+    signature_params: SignatureParams,
+    metadata: String,
+}
+
+/// Lifecycle state of a relayed transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum RelayerTransactionState {
+    #[serde(rename = "STATE_NEW")]
+    New,
+    #[serde(rename = "STATE_EXECUTED")]
+    Executed,
+    #[serde(rename = "STATE_MINED")]
+    Mined,
+    #[serde(rename = "STATE_CONFIRMED")]
+    Confirmed,
+    #[serde(rename = "STATE_FAILED")]
+    Failed,
+    #[serde(rename = "STATE_INVALID")]
+    Invalid,
+}
+
+impl RelayerTransactionState {
+    /// Whether the transaction has been included on chain
+    pub fn is_mined(&self) -> bool {
+        matches!(self, Self::Mined | Self::Confirmed)
+    }
+
+    /// Whether the relayer gave up on the transaction
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed | Self::Invalid)
+    }
+}
+
+/// A transaction tracked by the relayer
+#[derive(Debug, Clone, Deserialize)]
+pub struct RelayerTransaction {
+    /// Relayer-assigned transaction ID
+    #[serde(rename = "transactionID")]
+    pub transaction_id: String,
+    /// Current state of the transaction
+    pub state: RelayerTransactionState,
+    /// On-chain transaction hash, once broadcast
+    #[serde(rename = "transactionHash", default)]
+    pub transaction_hash: Option<String>,
+}
+
+/// Client for the Polymarket relayer
+///
+/// Polymarket proxy wallets (email/magic logins) hold funds in a contract
+/// wallet controlled by the user's signer. The relayer submits calls for
+/// those wallets and pays the gas, so approvals, redemptions and transfers
+/// work without the signer holding any MATIC.
+///
+/// Calls are built with [`OnchainClient`] (e.g.
+/// [`OnchainClient::usdc_transfer_call`]) and executed here in a single
+/// relayed transaction. Requests are authenticated with builder API
+/// credentials.
+///
+/// Only proxy wallets on Polygon mainnet are supported; Gnosis Safe wallets
+/// use a different relay flow.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::onchain::{OnchainClient, RelayerClient};
+/// use polymarket_rs::{Address, ApiCreds, PrivateKeySigner};
+/// use rust_decimal_macros::dec;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let signer = PrivateKeySigner::random();
+/// let proxy_wallet: Address = "0x0000000000000000000000000000000000000001".parse().unwrap();
+/// let builder_creds = ApiCreds::new("key".into(), "secret".into(), "pass".into());
+///
+/// let onchain = OnchainClient::new("https://polygon-rpc.com", signer.clone(), 137)?;
+/// let relayer = RelayerClient::new(signer, 137, proxy_wallet, builder_creds)?;
+///
+/// // Make the proxy wallet trade-ready without paying gas
+/// relayer.ensure_approvals(&onchain).await?;
+///
+/// // Withdraw $5 of USDC from the proxy wallet
+/// let to: Address = "0x0000000000000000000000000000000000000002".parse().unwrap();
+/// let call = onchain.usdc_transfer_call(to, dec!(5))?;
+/// let tx = relayer.execute(&[call], "withdraw").await?;
+/// relayer.wait_for_transaction(&tx.transaction_id).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RelayerClient {
+    http_client: HttpClient,
+    signer: Box<dyn EthSigner>,
+    proxy_wallet: Address,
+    builder_creds: ApiCreds,
+}
+
+impl RelayerClient {
+    /// Create a new RelayerClient against the default relayer endpoint
+    ///
+    /// # Arguments
+    /// * `signer` - The signer that controls the proxy wallet
+    /// * `chain_id` - The chain ID (only 137, Polygon mainnet, is supported)
+    /// * `proxy_wallet` - The proxy wallet address (the funder address)
+    /// * `builder_creds` - Builder API credentials for the relayer
+    pub fn new(
+        signer: impl EthSigner + 'static,
+        chain_id: u64,
+        proxy_wallet: Address,
+        builder_creds: ApiCreds,
+    ) -> Result<Self> {
+        Self::with_url(
+            DEFAULT_RELAYER_URL,
+            signer,
+            chain_id,
+            proxy_wallet,
+            builder_creds,
+        )
+    }
+
+    /// Create a new RelayerClient against a custom relayer endpoint
+    pub fn with_url(
+        host: impl Into<String>,
+        signer: impl EthSigner + 'static,
+        chain_id: u64,
+        proxy_wallet: Address,
+        builder_creds: ApiCreds,
+    ) -> Result<Self> {
+        if chain_id != chains::POLYGON_MAINNET {
+            return Err(Error::Config(format!(
+                "Relayer is only supported on Polygon mainnet, got chain_id {}",
+                chain_id
+            )));
+        }
+
+        Ok(Self {
+            http_client: HttpClient::new(host),
+            signer: Box::new(signer),
+            proxy_wallet,
+            builder_creds,
+        })
+    }
+
+    /// Get the proxy wallet the relayer executes calls from
+    pub fn proxy_wallet(&self) -> Address {
+        self.proxy_wallet
+    }
+
+    /// Get the relay address and nonce for the next transaction
+    pub async fn get_relay_payload(&self) -> Result<RelayPayload> {
+        let path = format!(
+            "/relay-payload?address={}&type={}",
+            self.signer.address(),
+            PROXY_TRANSACTION_TYPE
+        );
+        self.http_client.get(&path, None).await
+    }
+
+    /// Get the current state of a relayed transaction
+    pub async fn get_transaction(&self, transaction_id: &str) -> Result<RelayerTransaction> {
+        let path = format!("/transaction?id={}", encode_query_value(transaction_id));
+        let transactions: Vec<RelayerTransaction> = self.http_client.get(&path, None).await?;
+        transactions
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::MissingField(format!("relayer transaction {}", transaction_id)))
+    }
+
+    /// Execute calls from the proxy wallet in a single relayed transaction
+    ///
+    /// # Arguments
+    /// * `calls` - The calls to execute, in order
+    /// * `metadata` - Free-form label stored with the transaction
+    ///
+    /// # Returns
+    /// The submitted transaction; use [`RelayerClient::wait_for_transaction`] to await it
+    pub async fn execute(
+        &self,
+        calls: &[ContractCall],
+        metadata: &str,
+    ) -> Result<RelayerTransaction> {
+        if calls.is_empty() {
+            return Err(Error::InvalidParameter(
+                "At least one call is required".to_string(),
+            ));
+        }
+
+        let payload = self.get_relay_payload().await?;
+        let request = self.build_request(calls, &payload, metadata)?;
+
+        let headers =
+            create_builder_headers(&self.builder_creds, "POST", "/submit", Some(&request))?;
+        self.http_client
+            .post("/submit", &request, Some(headers))
+            .await
+    }
+
+    /// Poll a relayed transaction until it is mined or the wait times out
+    ///
+    /// Returns [`Error::TransactionReverted`] if the relayer reports the
+    /// transaction as failed or invalid.
+    pub async fn wait_for_transaction(&self, transaction_id: &str) -> Result<RelayerTransaction> {
+        let deadline = tokio::time::Instant::now() + POLL_TIMEOUT;
+
+        loop {
+            let transaction = self.get_transaction(transaction_id).await?;
+            if transaction.state.is_mined() {
+                return Ok(transaction);
+            }
+            if transaction.state.is_failed() {
+                return Err(Error::TransactionReverted(transaction_id.to_string()));
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return Err(Error::TransactionTimeout(transaction_id.to_string()));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Grant every approval the proxy wallet is missing in one relayed transaction
+    ///
+    /// # Returns
+    /// The mined transaction, or `None` if the wallet was already ready
+    pub async fn ensure_approvals(
+        &self,
+        onchain: &OnchainClient,
+    ) -> Result<Option<RelayerTransaction>> {
+        let status = onchain.check_approvals(self.proxy_wallet).await?;
+        let calls = onchain.approval_calls(&status);
+        if calls.is_empty() {
+            return Ok(None);
+        }
+
+        let transaction = self.execute(&calls, "approve").await?;
+        self.wait_for_transaction(&transaction.transaction_id)
+            .await
+            .map(Some)
+    }
+
+    /// Redeem several resolved conditions from the proxy wallet in one relayed transaction
+    pub async fn redeem_positions(
+        &self,
+        onchain: &OnchainClient,
+        requests: &[RedeemRequest],
+    ) -> Result<RelayerTransaction> {
+        let calls = requests
+            .iter()
+            .map(|request| onchain.redeem_positions_call(request))
+            .collect::<Result<Vec<_>>>()?;
+
+        let transaction = self.execute(&calls, "redeem").await?;
+        self.wait_for_transaction(&transaction.transaction_id).await
+    }
+
+    /// Encode and sign a proxy transaction for submission
+    fn build_request(
+        &self,
+        calls: &[ContractCall],
+        payload: &RelayPayload,
+        metadata: &str,
+    ) -> Result<TransactionRequest> {
+        let from = self.signer.address();
+        let data = encode_proxy_calls(calls);
+        let nonce = U256::from_str_radix(&payload.nonce, 10).map_err(|e| {
+            Error::InvalidParameter(format!("Invalid relay nonce {}: {}", payload.nonce, e))
+        })?;
+
+        let hash = proxy_struct_hash(
+            from,
+            PROXY_WALLET_FACTORY,
+            &data,
+            U256::from(DEFAULT_GAS_LIMIT),
+            nonce,
+            payload.address,
+        );
+        let signature = self
+            .signer
+            .sign_message_sync(hash.as_slice())
+            .map_err(|e| Error::Signing(format!("Failed to sign relay transaction: {}", e)))?;
+
+        Ok(TransactionRequest {
+            transaction_type: PROXY_TRANSACTION_TYPE,
+            from,
+            to: PROXY_WALLET_FACTORY,
+            proxy_wallet: self.proxy_wallet,
+            data: encode_prefixed(&data),
+            nonce: payload.nonce.clone(),
+            signature: encode_prefixed(signature.as_bytes()),
+            signature_params: SignatureParams {
+                gas_price: "0".to_string(),
+                gas_limit: DEFAULT_GAS_LIMIT.to_string(),
+                relayer_fee: "0".to_string(),
+                relay_hub: RELAY_HUB,
+                relay: payload.address,
+            },
+            metadata: metadata.to_string(),
+        })
+    }
+}
+
+/// Encode calls as a `proxy(ProxyCall[])` call on the proxy wallet factory
+fn encode_proxy_calls(calls: &[ContractCall]) -> Bytes {
+    let call = IProxyWalletFactory::proxyCall {
+        calls: calls
+            .iter()
+            .map(|call| IProxyWalletFactory::ProxyCall {
+                typeCode: CALL_TYPE_CODE,
+                to: call.to,
+                value: call.value,
+                data: call.data.clone(),
+            })
+            .collect(),
+    };
+    call.abi_encode().into()
+}
+
+/// Hash of a relayed proxy transaction, as signed by the wallet owner
+///
+/// `keccak256("rlx:" ‖ from ‖ to ‖ data ‖ relayerFee ‖ gasPrice ‖ gasLimit ‖ nonce ‖ relayHub ‖ relay)`,
+/// with fee and gas price fixed at zero since the relayer pays the gas.
+fn proxy_struct_hash(
+    from: Address,
+    to: Address,
+    data: &[u8],
+    gas_limit: U256,
+    nonce: U256,
+    relay: Address,
+) -> B256 {
+    let mut message = Vec::with_capacity(4 + 20 * 4 + data.len() + 32 * 4);
+    message.extend_from_slice(b"rlx:");
+    message.extend_from_slice(from.as_slice());
+    message.extend_from_slice(to.as_slice());
+    message.extend_from_slice(data);
+    message.extend_from_slice(&U256::ZERO.to_be_bytes::<32>());
+    message.extend_from_slice(&U256::ZERO.to_be_bytes::<32>());
+    message.extend_from_slice(&gas_limit.to_be_bytes::<32>());
+    message.extend_from_slice(&nonce.to_be_bytes::<32>());
+    message.extend_from_slice(RELAY_HUB.as_slice());
+    message.extend_from_slice(relay.as_slice());
+    keccak256(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_signer_local::PrivateKeySigner;
+
+    fn relayer(signer: PrivateKeySigner) -> RelayerClient {
+        let creds = ApiCreds::new(
+            "key".to_string(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_string(),
+            "pass".to_string(),
+        );
+        RelayerClient::new(signer, 137, Address::repeat_byte(0x11), creds).unwrap()
+    }
+
+    #[test]
+    fn test_rejects_non_mainnet() {
+        let creds = ApiCreds::new("k".into(), "s".into(), "p".into());
+        assert!(
+            RelayerClient::new(PrivateKeySigner::random(), 80002, Address::ZERO, creds).is_err()
+        );
+    }
+
+    #[test]
+    fn test_build_request_is_signed_by_owner() {
+        let signer = PrivateKeySigner::random();
+        let relayer = relayer(signer.clone());
+        let calls = [ContractCall::new(
+            Address::repeat_byte(0x22),
+            vec![0xde, 0xad],
+        )];
+        let payload = RelayPayload {
+            address: Address::repeat_byte(0x33),
+            nonce: "7".to_string(),
+        };
+
+        let request = relayer.build_request(&calls, &payload, "test").unwrap();
+        assert_eq!(request.from, signer.address());
+        assert_eq!(request.to, PROXY_WALLET_FACTORY);
+
+        let data = encode_proxy_calls(&calls);
+        let decoded = IProxyWalletFactory::proxyCall::abi_decode(&data, true).unwrap();
+        assert_eq!(decoded.calls.len(), 1);
+        assert_eq!(decoded.calls[0].to, Address::repeat_byte(0x22));
+
+        let hash = proxy_struct_hash(
+            signer.address(),
+            PROXY_WALLET_FACTORY,
+            &data,
+            U256::from(DEFAULT_GAS_LIMIT),
+            U256::from(7),
+            payload.address,
+        );
+        let signature: alloy_primitives::PrimitiveSignature = request.signature.parse().unwrap();
+        assert_eq!(
+            signature.recover_address_from_msg(hash).unwrap(),
+            signer.address()
+        );
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["type"], "PROXY");
+        assert_eq!(json["signatureParams"]["gasLimit"], "10000000");
+        assert!(json.get("proxyWallet").is_some());
+    }
+
+    #[test]
+    fn test_deserialize_transaction() {
+        let json =
+            r#"[{"transactionID": "abc", "state": "STATE_MINED", "transactionHash": "0x01"}]"#;
+        let transactions: Vec<RelayerTransaction> = serde_json::from_str(json).unwrap();
+        assert_eq!(transactions[0].transaction_id, "abc");
+        assert!(transactions[0].state.is_mined());
+    }
+}
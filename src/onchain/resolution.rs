@@ -0,0 +1,191 @@
+use super::client::{condition_id_to_b256, OnchainClient};
+use super::contracts::IConditionalTokens;
+use crate::error::{Error, Result};
+use crate::types::ConditionId;
+use alloy_primitives::U256;
+use futures_util::stream::{self, Stream};
+use rust_decimal::Decimal;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// Resolution state of a condition, as recorded by the ConditionalTokens contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolution {
+    /// The condition ID
+    pub condition_id: ConditionId,
+    /// Fraction of collateral each outcome slot pays out, in outcome order;
+    /// empty while the condition is unresolved
+    pub payouts: Vec<Decimal>,
+}
+
+impl Resolution {
+    /// Whether the oracle has reported a payout vector
+    pub fn is_resolved(&self) -> bool {
+        !self.payouts.is_empty()
+    }
+
+    /// Index of the outcome that pays out in full, if any
+    ///
+    /// Returns `None` while unresolved or for split resolutions (e.g. 50/50).
+    pub fn winning_outcome(&self) -> Option<usize> {
+        self.payouts
+            .iter()
+            .position(|payout| *payout == Decimal::ONE)
+    }
+}
+
+/// Convert a payout numerator into a fraction of the denominator
+fn payout_fraction(numerator: U256, denominator: U256) -> Result<Decimal> {
+    let to_decimal = |value: U256| {
+        u64::try_from(value)
+            .map(Decimal::from)
+            .map_err(|_| Error::InvalidParameter(format!("Payout out of range: {}", value)))
+    };
+    Ok(to_decimal(numerator)? / to_decimal(denominator)?)
+}
+
+/// State carried between polls of [`OnchainClient::watch_resolutions`]
+struct WatchState<'a> {
+    client: &'a OnchainClient,
+    pending: Vec<ConditionId>,
+    ready: VecDeque<Result<Resolution>>,
+    poll_interval: Duration,
+    first_poll: bool,
+}
+
+impl OnchainClient {
+    /// Get the resolution state of a condition
+    ///
+    /// Reads the payout vector the oracle (the UMA adapter for Polymarket
+    /// markets) reported to the ConditionalTokens contract. This is the
+    /// authoritative source for redemption, unlike the API `closed` flags
+    /// which can flip before the payout is reported.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::{ConditionId, OnchainClient, PrivateKeySigner};
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// # let client = OnchainClient::new("https://polygon-rpc.com", PrivateKeySigner::random(), 137)?;
+    /// let resolution = client.get_resolution(&ConditionId::new("0x...")).await?;
+    /// if let Some(outcome) = resolution.winning_outcome() {
+    ///     println!("Outcome {} won", outcome);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_resolution(&self, condition_id: &ConditionId) -> Result<Resolution> {
+        let id = condition_id_to_b256(condition_id)?;
+
+        let call = IConditionalTokens::payoutDenominatorCall { conditionId: id };
+        let denominator = self.view(self.conditional_tokens, call).await?._0;
+
+        let mut payouts = Vec::new();
+        if !denominator.is_zero() {
+            let call = IConditionalTokens::getOutcomeSlotCountCall { conditionId: id };
+            let slots = self.view(self.conditional_tokens, call).await?._0;
+
+            for index in 0..slots.saturating_to::<u64>() {
+                let call = IConditionalTokens::payoutNumeratorsCall {
+                    conditionId: id,
+                    index: U256::from(index),
+                };
+                let numerator = self.view(self.conditional_tokens, call).await?._0;
+                payouts.push(payout_fraction(numerator, denominator)?);
+            }
+        }
+
+        Ok(Resolution {
+            condition_id: condition_id.clone(),
+            payouts,
+        })
+    }
+
+    /// Watch conditions until they resolve
+    ///
+    /// Polls every pending condition once per `poll_interval` and yields a
+    /// [`Resolution`] as soon as each one is resolved. The stream ends once
+    /// all conditions have resolved. RPC errors are yielded and the affected
+    /// conditions are retried on the next poll.
+    pub fn watch_resolutions(
+        &self,
+        condition_ids: Vec<ConditionId>,
+        poll_interval: Duration,
+    ) -> Pin<Box<dyn Stream<Item = Result<Resolution>> + Send + '_>> {
+        let state = WatchState {
+            client: self,
+            pending: condition_ids,
+            ready: VecDeque::new(),
+            poll_interval,
+            first_poll: true,
+        };
+
+        Box::pin(stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.ready.pop_front() {
+                    return Some((item, state));
+                }
+                if state.pending.is_empty() {
+                    return None;
+                }
+
+                if !state.first_poll {
+                    tokio::time::sleep(state.poll_interval).await;
+                }
+                state.first_poll = false;
+
+                let mut still_pending = Vec::with_capacity(state.pending.len());
+                for condition_id in state.pending.drain(..) {
+                    match state.client.get_resolution(&condition_id).await {
+                        Ok(resolution) if resolution.is_resolved() => {
+                            state.ready.push_back(Ok(resolution))
+                        }
+                        Ok(_) => still_pending.push(condition_id),
+                        Err(e) => {
+                            state.ready.push_back(Err(e));
+                            still_pending.push(condition_id);
+                        }
+                    }
+                }
+                state.pending = still_pending;
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_winning_outcome() {
+        let resolution = Resolution {
+            condition_id: ConditionId::new("0x01"),
+            payouts: vec![
+                payout_fraction(U256::ZERO, U256::from(1)).unwrap(),
+                payout_fraction(U256::from(1), U256::from(1)).unwrap(),
+            ],
+        };
+        assert!(resolution.is_resolved());
+        assert_eq!(resolution.winning_outcome(), Some(1));
+
+        let split = Resolution {
+            condition_id: ConditionId::new("0x01"),
+            payouts: vec![
+                payout_fraction(U256::from(1), U256::from(2)).unwrap(),
+                payout_fraction(U256::from(1), U256::from(2)).unwrap(),
+            ],
+        };
+        assert_eq!(split.payouts[0], dec!(0.5));
+        assert_eq!(split.winning_outcome(), None);
+
+        let unresolved = Resolution {
+            condition_id: ConditionId::new("0x01"),
+            payouts: Vec::new(),
+        };
+        assert!(!unresolved.is_resolved());
+    }
+}
@@ -0,0 +1,241 @@
+use crate::error::{Error, Result};
+use alloy_primitives::hex::encode_prefixed;
+use alloy_primitives::{Address, Bytes, B256, U256};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// JSON-RPC response envelope
+#[derive(Debug, Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<RpcErrorObject>,
+}
+
+/// JSON-RPC error object
+#[derive(Debug, Deserialize)]
+struct RpcErrorObject {
+    code: i64,
+    message: String,
+    #[serde(default)]
+    data: Option<Value>,
+}
+
+/// Receipt of a mined transaction
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    /// Hash of the transaction
+    pub transaction_hash: B256,
+    /// Block the transaction was included in
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub block_number: u64,
+    /// Gas consumed by the transaction
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub gas_used: u64,
+    /// Whether the transaction succeeded (false means it reverted)
+    #[serde(deserialize_with = "deserialize_status")]
+    pub status: bool,
+}
+
+/// Deserialize a hex-encoded JSON-RPC quantity (e.g. "0x1a") into a u64
+fn deserialize_quantity<'de, D>(deserializer: D) -> std::result::Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse_quantity(&s).map_err(serde::de::Error::custom)
+}
+
+/// Deserialize a receipt status quantity ("0x1" = success, "0x0" = reverted)
+fn deserialize_status<'de, D>(deserializer: D) -> std::result::Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(deserialize_quantity(deserializer)? == 1)
+}
+
+/// Parse a hex-encoded JSON-RPC quantity into an integer
+pub(crate) fn parse_quantity<T>(s: &str) -> Result<T>
+where
+    T: TryFrom<u128>,
+{
+    let digits = s.strip_prefix("0x").unwrap_or(s);
+    let value = u128::from_str_radix(digits, 16)
+        .map_err(|e| Error::InvalidParameter(format!("Invalid quantity {}: {}", s, e)))?;
+    T::try_from(value).map_err(|_| Error::InvalidParameter(format!("Quantity out of range: {}", s)))
+}
+
+/// Minimal Ethereum JSON-RPC client
+///
+/// Covers the handful of methods needed to read contract state and submit
+/// signed transactions; it is not a general-purpose provider.
+#[derive(Clone)]
+pub struct RpcClient {
+    client: Client,
+    url: String,
+    next_id: Arc<AtomicU64>,
+}
+
+impl RpcClient {
+    /// Create a new RpcClient for the given node URL
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: Client::new(),
+            url: url.into(),
+            next_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+
+    /// Send a JSON-RPC request and decode its result
+    pub async fn request<T>(&self, method: &str, params: Value) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.request_optional(method, params)
+            .await?
+            .ok_or_else(|| Error::MissingField(format!("result of {}", method)))
+    }
+
+    /// Send a JSON-RPC request whose result may legitimately be `null`
+    async fn request_optional<T>(&self, method: &str, params: Value) -> Result<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": self.next_id.fetch_add(1, Ordering::Relaxed),
+            "method": method,
+            "params": params,
+        });
+
+        let response = self.client.post(&self.url).json(&body).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(Error::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        let response: RpcResponse<T> = response.json().await?;
+        match response.error {
+            Some(error) => {
+                let message = match error.data {
+                    Some(data) => format!("{} ({})", error.message, data),
+                    None => error.message,
+                };
+                Err(Error::Rpc {
+                    code: error.code,
+                    message,
+                })
+            }
+            None => Ok(response.result),
+        }
+    }
+
+    /// Get the chain ID reported by the node
+    pub async fn chain_id(&self) -> Result<u64> {
+        let id: String = self.request("eth_chainId", json!([])).await?;
+        parse_quantity(&id)
+    }
+
+    /// Execute a read-only call against the latest block
+    pub async fn call(&self, to: Address, data: &Bytes) -> Result<Bytes> {
+        self.request(
+            "eth_call",
+            json!([{ "to": to, "data": encode_prefixed(data) }, "latest"]),
+        )
+        .await
+    }
+
+    /// Estimate the gas required for a transaction
+    pub async fn estimate_gas(
+        &self,
+        from: Address,
+        to: Address,
+        data: &Bytes,
+        value: U256,
+    ) -> Result<u64> {
+        let gas: String = self
+            .request(
+                "eth_estimateGas",
+                json!([{ "from": from, "to": to, "data": encode_prefixed(data), "value": value }]),
+            )
+            .await?;
+        parse_quantity(&gas)
+    }
+
+    /// Get the pending transaction count (next nonce) for an address
+    pub async fn transaction_count(&self, address: Address) -> Result<u64> {
+        let nonce: String = self
+            .request("eth_getTransactionCount", json!([address, "pending"]))
+            .await?;
+        parse_quantity(&nonce)
+    }
+
+    /// Get the base fee of the latest block
+    pub async fn base_fee(&self) -> Result<u128> {
+        let block: Value = self
+            .request("eth_getBlockByNumber", json!(["latest", false]))
+            .await?;
+        let base_fee = block
+            .get("baseFeePerGas")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::MissingField("baseFeePerGas".to_string()))?;
+        parse_quantity(base_fee)
+    }
+
+    /// Get the node's suggested priority fee
+    pub async fn max_priority_fee(&self) -> Result<u128> {
+        let fee: String = self.request("eth_maxPriorityFeePerGas", json!([])).await?;
+        parse_quantity(&fee)
+    }
+
+    /// Broadcast a signed, EIP-2718 encoded transaction
+    pub async fn send_raw_transaction(&self, raw: &[u8]) -> Result<B256> {
+        self.request("eth_sendRawTransaction", json!([encode_prefixed(raw)]))
+            .await
+    }
+
+    /// Get the receipt of a transaction, if it has been mined
+    pub async fn transaction_receipt(&self, hash: B256) -> Result<Option<TransactionReceipt>> {
+        self.request_optional("eth_getTransactionReceipt", json!([hash]))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_quantity() {
+        assert_eq!(parse_quantity::<u64>("0x0").unwrap(), 0);
+        assert_eq!(parse_quantity::<u64>("0x1a").unwrap(), 26);
+        assert_eq!(parse_quantity::<u128>("0x3b9aca00").unwrap(), 1_000_000_000);
+        assert!(parse_quantity::<u64>("0xzz").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_receipt() {
+        let json = r#"{
+            "transactionHash": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "blockNumber": "0x10",
+            "gasUsed": "0x5208",
+            "status": "0x0",
+            "logs": []
+        }"#;
+        let receipt: TransactionReceipt = serde_json::from_str(json).unwrap();
+        assert_eq!(receipt.block_number, 16);
+        assert_eq!(receipt.gas_used, 21000);
+        assert!(!receipt.status);
+    }
+}
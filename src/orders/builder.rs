@@ -255,7 +255,7 @@ impl OrderBuilder {
             expiration: expiration.to_string(),
             nonce: extras.nonce.to_string(),
             fee_rate_bps: extras.fee_rate_bps.to_string(),
-            side: side.as_str().to_string(),
+            side,
             signature_type: self.sig_type.to_u8(),
             signature,
         })
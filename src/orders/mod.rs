@@ -1,7 +1,18 @@
 mod builder;
+pub mod odds;
 mod price;
+mod risk;
 mod rounding;
+mod slicing;
 
 pub use builder::OrderBuilder;
 pub use price::calculate_market_price;
-pub use rounding::{decimal_to_token_u64, fix_amount_rounding, RoundConfig, ROUNDING_CONFIG};
+pub use risk::{RiskLimits, RiskState, RiskViolation};
+pub use rounding::{
+    decimal_to_token_u64, fix_amount_rounding, token_u64_to_decimal, try_decimal_to_token_u64,
+    RoundConfig, ROUNDING_CONFIG, TOKEN_DECIMALS,
+};
+pub use slicing::{ChildOrder, OrderSlicer, SlicePlan};
+
+#[cfg(feature = "f64")]
+pub use rounding::{decimal_from_f64, decimal_to_f64};
@@ -0,0 +1,166 @@
+//! Conversions between prices, implied probabilities and betting odds.
+//!
+//! A Polymarket share pays out 1 USDC if its outcome happens, so its price
+//! is the market's implied probability of that outcome. These helpers
+//! translate that into the decimal and American odds used by sportsbooks,
+//! and work out the expected value of a trade after fees.
+
+use crate::error::{Error, Result};
+use crate::Side;
+use rust_decimal::Decimal;
+
+const HUNDRED: Decimal = Decimal::ONE_HUNDRED;
+
+/// Check that a price or probability lies strictly between 0 and 1
+fn check_probability(value: Decimal, name: &str) -> Result<Decimal> {
+    if value <= Decimal::ZERO || value >= Decimal::ONE {
+        return Err(Error::InvalidParameter(format!(
+            "{} must be between 0 and 1 exclusive, got {}",
+            name, value
+        )));
+    }
+    Ok(value)
+}
+
+/// Decimal odds of a price: the total payout per unit staked, e.g. 0.25 is 4.0
+pub fn price_to_decimal_odds(price: Decimal) -> Result<Decimal> {
+    Ok(Decimal::ONE / check_probability(price, "price")?)
+}
+
+/// The price implied by decimal odds, which must be greater than 1
+pub fn decimal_odds_to_price(odds: Decimal) -> Result<Decimal> {
+    if odds <= Decimal::ONE {
+        return Err(Error::InvalidParameter(format!(
+            "Decimal odds must be greater than 1, got {}",
+            odds
+        )));
+    }
+    Ok(Decimal::ONE / odds)
+}
+
+/// American (moneyline) odds of a price
+///
+/// Favourites (price above 0.5) get negative odds, the stake needed to win
+/// 100; underdogs get positive odds, the winnings on a 100 stake. A price of
+/// exactly 0.5 is +100.
+pub fn price_to_american_odds(price: Decimal) -> Result<Decimal> {
+    let price = check_probability(price, "price")?;
+    let against = Decimal::ONE - price;
+    Ok(if price > Decimal::new(5, 1) {
+        -HUNDRED * price / against
+    } else {
+        HUNDRED * against / price
+    })
+}
+
+/// The price implied by American odds, which must be at least 100 in size
+pub fn american_odds_to_price(odds: Decimal) -> Result<Decimal> {
+    if odds.abs() < HUNDRED {
+        return Err(Error::InvalidParameter(format!(
+            "American odds must be +100 or more, or -100 or less, got {}",
+            odds
+        )));
+    }
+    Ok(if odds > Decimal::ZERO {
+        HUNDRED / (odds + HUNDRED)
+    } else {
+        -odds / (-odds + HUNDRED)
+    })
+}
+
+/// Fee per share at `price` for a fee rate in basis points
+///
+/// Polymarket charges the rate on the cheaper side of the trade,
+/// `rate × min(price, 1 - price)`, so fees shrink near 0 and 1.
+pub fn fee_per_share(price: Decimal, fee_rate_bps: u32) -> Decimal {
+    let rate = Decimal::from(fee_rate_bps) / Decimal::from(10_000);
+    rate * price.min(Decimal::ONE - price)
+}
+
+/// Expected profit per share of trading at `price` if the outcome's true
+/// probability is `probability`, net of fees
+///
+/// Buying pays `price` for a share worth `probability` on average; selling
+/// the reverse. Positive values mean the trade has an edge.
+pub fn expected_value(
+    side: Side,
+    price: Decimal,
+    probability: Decimal,
+    fee_rate_bps: u32,
+) -> Result<Decimal> {
+    let price = check_probability(price, "price")?;
+    if probability < Decimal::ZERO || probability > Decimal::ONE {
+        return Err(Error::InvalidParameter(format!(
+            "probability must be between 0 and 1, got {}",
+            probability
+        )));
+    }
+    let edge = match side {
+        Side::Buy => probability - price,
+        Side::Sell => price - probability,
+    };
+    Ok(edge - fee_per_share(price, fee_rate_bps))
+}
+
+/// The bookmaker's margin in a set of mutually exclusive outcome prices
+///
+/// The amount by which the prices sum past 1; negative when they sum to
+/// less, e.g. across the asks of a neg-risk event.
+pub fn overround(prices: &[Decimal]) -> Decimal {
+    prices.iter().sum::<Decimal>() - Decimal::ONE
+}
+
+/// Scale mutually exclusive outcome prices to probabilities summing to 1
+///
+/// Removes the [`overround`] proportionally. Returns `None` if the prices
+/// sum to zero.
+pub fn normalize_probabilities(prices: &[Decimal]) -> Option<Vec<Decimal>> {
+    let total: Decimal = prices.iter().sum();
+    if total.is_zero() {
+        return None;
+    }
+    Some(prices.iter().map(|price| price / total).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_decimal_odds_round_trip() {
+        assert_eq!(price_to_decimal_odds(dec!(0.25)).unwrap(), dec!(4));
+        assert_eq!(decimal_odds_to_price(dec!(4)).unwrap(), dec!(0.25));
+        assert!(price_to_decimal_odds(dec!(1)).is_err());
+        assert!(decimal_odds_to_price(dec!(1)).is_err());
+    }
+
+    #[test]
+    fn test_american_odds() {
+        assert_eq!(price_to_american_odds(dec!(0.8)).unwrap(), dec!(-400));
+        assert_eq!(price_to_american_odds(dec!(0.2)).unwrap(), dec!(400));
+        assert_eq!(price_to_american_odds(dec!(0.5)).unwrap(), dec!(100));
+        assert_eq!(american_odds_to_price(dec!(-400)).unwrap(), dec!(0.8));
+        assert_eq!(american_odds_to_price(dec!(400)).unwrap(), dec!(0.2));
+        assert!(american_odds_to_price(dec!(50)).is_err());
+    }
+
+    #[test]
+    fn test_expected_value_after_fees() {
+        // 200 bps on min(0.4, 0.6) = 0.008 per share
+        assert_eq!(fee_per_share(dec!(0.4), 200), dec!(0.008));
+        let ev = expected_value(Side::Buy, dec!(0.4), dec!(0.5), 200).unwrap();
+        assert_eq!(ev, dec!(0.092));
+        let ev = expected_value(Side::Sell, dec!(0.4), dec!(0.5), 0).unwrap();
+        assert_eq!(ev, dec!(-0.1));
+    }
+
+    #[test]
+    fn test_overround_and_normalization() {
+        let prices = [dec!(0.55), dec!(0.50)];
+        assert_eq!(overround(&prices), dec!(0.05));
+        let probabilities = normalize_probabilities(&prices).unwrap();
+        assert_eq!(probabilities.iter().sum::<Decimal>().round_dp(10), dec!(1));
+        assert!(normalize_probabilities(&[]).is_none());
+    }
+}
@@ -0,0 +1,685 @@
+use crate::types::{OpenOrder, OrderBookSummary, OrderId, TokenId};
+use crate::Side;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Client-side guardrails checked before an order is signed and posted
+///
+/// Every limit is optional; an empty `RiskLimits` accepts everything.
+/// Limits are evaluated against a [`RiskState`] that the caller keeps up to
+/// date with fills and mid prices (the [`TradingClient`] tracks its open
+/// orders and their sizes itself).
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::orders::{RiskLimits, RiskState, RiskViolation};
+/// use polymarket_rs::{Side, TokenId};
+/// use rust_decimal_macros::dec;
+///
+/// let limits = RiskLimits::new()
+///     .with_max_order_notional(dec!(100))
+///     .with_price_collar(dec!(0.05));
+///
+/// let token = TokenId::new("123");
+/// let mut state = RiskState::new();
+/// state.set_mid(token.clone(), dec!(0.50));
+///
+/// assert!(limits.check(&state, &token, Side::Buy, dec!(0.52), dec!(50)).is_ok());
+/// assert!(matches!(
+///     limits.check(&state, &token, Side::Buy, dec!(0.60), dec!(50)),
+///     Err(RiskViolation::PriceCollar { .. })
+/// ));
+/// ```
+///
+/// [`TradingClient`]: crate::client::TradingClient
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RiskLimits {
+    max_order_notional: Option<Decimal>,
+    max_token_position: Option<Decimal>,
+    max_open_orders: Option<usize>,
+    price_collar: Option<Decimal>,
+}
+
+impl RiskLimits {
+    /// Limits that accept every order
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject orders whose price × size exceeds `notional` USDC
+    pub fn with_max_order_notional(mut self, notional: Decimal) -> Self {
+        self.max_order_notional = Some(notional);
+        self
+    }
+
+    /// Reject orders that could take the net position in a token beyond ±`shares`
+    ///
+    /// The limit is per token, not per market: the two outcomes of a market
+    /// are limited separately. An order counts together with the orders
+    /// resting or in flight on the same side of the token, as if they all
+    /// filled, so stacking orders can't get around the limit. Orders that
+    /// reduce the position are always accepted.
+    pub fn with_max_token_position(mut self, shares: Decimal) -> Self {
+        self.max_token_position = Some(shares);
+        self
+    }
+
+    /// Reject new orders once `count` orders are resting
+    pub fn with_max_open_orders(mut self, count: usize) -> Self {
+        self.max_open_orders = Some(count);
+        self
+    }
+
+    /// Reject orders priced more than `collar` away from the token's mid
+    ///
+    /// Orders for tokens without a known mid are rejected with
+    /// [`RiskViolation::NoReferencePrice`].
+    pub fn with_price_collar(mut self, collar: Decimal) -> Self {
+        self.price_collar = Some(collar);
+        self
+    }
+
+    /// Check an order of `size` shares at `price` against every limit
+    pub fn check(
+        &self,
+        state: &RiskState,
+        token_id: &TokenId,
+        side: Side,
+        price: Decimal,
+        size: Decimal,
+    ) -> Result<(), RiskViolation> {
+        self.check_batch(state, [(token_id, side, price, size)])
+    }
+
+    /// Check `(token, side, price, size)` orders placed together against every limit
+    ///
+    /// The batch counts towards the open-order limit as a whole, on top of the
+    /// orders resting and in flight, and orders on the same side of a token
+    /// add up against the position limit.
+    pub fn check_batch<'a>(
+        &self,
+        state: &RiskState,
+        orders: impl IntoIterator<Item = (&'a TokenId, Side, Decimal, Decimal)>,
+    ) -> Result<(), RiskViolation> {
+        let orders: Vec<_> = orders.into_iter().collect();
+
+        if let Some(limit) = self.max_open_orders {
+            let open = state.open_orders() + state.in_flight();
+            if open + orders.len() > limit {
+                return Err(RiskViolation::OpenOrders { open, limit });
+            }
+        }
+
+        // Worst-case long and short position per token
+        let mut exposures: HashMap<&TokenId, (Decimal, Decimal)> = HashMap::new();
+        for (token_id, side, price, size) in orders {
+            if let Some(limit) = self.max_order_notional {
+                let notional = price * size;
+                if notional > limit {
+                    return Err(RiskViolation::OrderNotional { notional, limit });
+                }
+            }
+
+            if let Some(limit) = self.max_token_position {
+                let (long, short) = exposures.entry(token_id).or_insert_with(|| {
+                    let position = state.position(token_id);
+                    (
+                        position + state.open_size(token_id, Side::Buy),
+                        position - state.open_size(token_id, Side::Sell),
+                    )
+                });
+                let (resulting, beyond) = match side {
+                    Side::Buy => {
+                        *long += size;
+                        (*long, *long > limit)
+                    }
+                    Side::Sell => {
+                        *short -= size;
+                        (*short, *short < -limit)
+                    }
+                };
+                if beyond {
+                    return Err(RiskViolation::Position {
+                        token_id: token_id.clone(),
+                        resulting,
+                        limit,
+                    });
+                }
+            }
+
+            if let Some(collar) = self.price_collar {
+                let mid = state
+                    .mid(token_id)
+                    .ok_or_else(|| RiskViolation::NoReferencePrice(token_id.clone()))?;
+                if (price - mid).abs() > collar {
+                    return Err(RiskViolation::PriceCollar { price, mid, collar });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Positions, mids and open orders the [`RiskLimits`] are checked against
+#[derive(Debug, Clone, Default)]
+pub struct RiskState {
+    positions: HashMap<TokenId, Decimal>,
+    mids: HashMap<TokenId, Decimal>,
+    open_orders: usize,
+    resting: HashMap<OrderId, RestingOrder>,
+    in_flight: usize,
+    in_flight_sizes: HashMap<TokenId, (Decimal, Decimal)>,
+}
+
+/// Unfilled size of a resting order, held against the position limit
+#[derive(Debug, Clone)]
+struct RestingOrder {
+    token_id: TokenId,
+    side: Side,
+    size: Decimal,
+}
+
+/// Buy or sell component of a `(buy, sell)` pair
+fn side_mut(sizes: &mut (Decimal, Decimal), side: Side) -> &mut Decimal {
+    match side {
+        Side::Buy => &mut sizes.0,
+        Side::Sell => &mut sizes.1,
+    }
+}
+
+impl RiskState {
+    /// Flat state with no known mids
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Net shares held in a token (negative when short)
+    pub fn position(&self, token_id: &TokenId) -> Decimal {
+        self.positions
+            .get(token_id)
+            .copied()
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    /// Overwrite the net position in a token, e.g. from the Data API on startup
+    pub fn set_position(&mut self, token_id: TokenId, shares: Decimal) {
+        self.positions.insert(token_id, shares);
+    }
+
+    /// Apply a fill of `size` shares to the position in a token
+    ///
+    /// For fills of orders the state tracks as resting, use
+    /// [`record_order_fill`](Self::record_order_fill) so the filled shares
+    /// stop counting as open.
+    pub fn record_fill(&mut self, token_id: TokenId, side: Side, size: Decimal) {
+        let position = self.positions.entry(token_id).or_default();
+        match side {
+            Side::Buy => *position += size,
+            Side::Sell => *position -= size,
+        }
+    }
+
+    /// Apply a fill of `size` shares of a resting order to the position
+    ///
+    /// The shares move from the order's open size into the position, and a
+    /// fully filled order stops counting as open. Returns `false`, changing
+    /// nothing, if the order isn't tracked as resting.
+    pub fn record_order_fill(&mut self, order_id: &OrderId, size: Decimal) -> bool {
+        let Some(order) = self.resting.get_mut(order_id) else {
+            return false;
+        };
+        let (token_id, side) = (order.token_id.clone(), order.side);
+        order.size -= size;
+        if order.size <= Decimal::ZERO {
+            self.resting.remove(order_id);
+            self.remove_open_orders(1);
+        }
+        self.record_fill(token_id, side, size);
+        true
+    }
+
+    /// Last mid recorded for a token
+    pub fn mid(&self, token_id: &TokenId) -> Option<Decimal> {
+        self.mids.get(token_id).copied()
+    }
+
+    /// Record the current mid of a token, the reference for price collars
+    pub fn set_mid(&mut self, token_id: TokenId, mid: Decimal) {
+        self.mids.insert(token_id, mid);
+    }
+
+    /// Record the mid of a book's best bid and ask, if both sides are quoted
+    pub fn update_mid_from_book(&mut self, book: &OrderBookSummary) {
+        let best_bid = book.bids.iter().map(|level| level.price).max();
+        let best_ask = book.asks.iter().map(|level| level.price).min();
+        if let (Some(bid), Some(ask)) = (best_bid, best_ask) {
+            self.set_mid(book.asset_id.clone(), (bid + ask) / Decimal::TWO);
+        }
+    }
+
+    /// Orders currently resting on the book
+    pub fn open_orders(&self) -> usize {
+        self.open_orders
+    }
+
+    /// Overwrite the open-order count, e.g. after listing orders on startup
+    ///
+    /// Prefer [`set_resting_orders`](Self::set_resting_orders), which also
+    /// records their sizes for the position limit.
+    pub fn set_open_orders(&mut self, count: usize) {
+        self.open_orders = count;
+    }
+
+    /// Overwrite the resting orders with a fresh listing
+    pub fn set_resting_orders(&mut self, orders: &[OpenOrder]) {
+        self.open_orders = orders.len();
+        self.resting = orders
+            .iter()
+            .map(|order| {
+                let resting = RestingOrder {
+                    token_id: order.asset_id.clone(),
+                    side: order.side,
+                    size: order.original_size - order.size_matched,
+                };
+                (order.id.clone(), resting)
+            })
+            .collect();
+    }
+
+    /// Shares resting or being posted on one side of a token
+    ///
+    /// They count against the position limit as if they had filled.
+    pub fn open_size(&self, token_id: &TokenId, side: Side) -> Decimal {
+        let resting: Decimal = self
+            .resting
+            .values()
+            .filter(|order| &order.token_id == token_id && order.side == side)
+            .map(|order| order.size)
+            .sum();
+        let in_flight = self
+            .in_flight_sizes
+            .get(token_id)
+            .map(|sizes| match side {
+                Side::Buy => sizes.0,
+                Side::Sell => sizes.1,
+            })
+            .unwrap_or_default();
+        resting + in_flight
+    }
+
+    pub(crate) fn add_open_orders(&mut self, count: usize) {
+        self.open_orders += count;
+    }
+
+    pub(crate) fn remove_open_orders(&mut self, count: usize) {
+        self.open_orders = self.open_orders.saturating_sub(count);
+    }
+
+    /// Count a posted order as resting with `size` shares unfilled
+    pub(crate) fn add_resting(
+        &mut self,
+        order_id: OrderId,
+        token_id: TokenId,
+        side: Side,
+        size: Decimal,
+    ) {
+        self.add_open_orders(1);
+        self.resting.insert(
+            order_id,
+            RestingOrder {
+                token_id,
+                side,
+                size,
+            },
+        );
+    }
+
+    /// Stop counting canceled orders
+    pub(crate) fn remove_resting(&mut self, order_ids: &[OrderId]) {
+        self.remove_open_orders(order_ids.len());
+        for order_id in order_ids {
+            self.resting.remove(order_id);
+        }
+    }
+
+    /// Orders being posted whose responses haven't arrived yet
+    ///
+    /// They count towards the open-order limit until the response says
+    /// whether they rest.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight
+    }
+
+    /// Hold `(token, side, price, size)` orders being posted against the limits
+    pub(crate) fn reserve(&mut self, orders: &[(TokenId, Side, Decimal, Decimal)]) {
+        self.in_flight += orders.len();
+        for (token_id, side, _, size) in orders {
+            let sizes = self.in_flight_sizes.entry(token_id.clone()).or_default();
+            *side_mut(sizes, *side) += size;
+        }
+    }
+
+    /// Release orders held by [`reserve`](Self::reserve)
+    pub(crate) fn release(&mut self, orders: &[(TokenId, Side, Decimal, Decimal)]) {
+        self.in_flight = self.in_flight.saturating_sub(orders.len());
+        for (token_id, side, _, size) in orders {
+            if let Some(sizes) = self.in_flight_sizes.get_mut(token_id) {
+                let open = side_mut(sizes, *side);
+                *open = (*open - size).max(Decimal::ZERO);
+                if sizes.0.is_zero() && sizes.1.is_zero() {
+                    self.in_flight_sizes.remove(token_id);
+                }
+            }
+        }
+    }
+}
+
+/// Why an order was rejected by [`RiskLimits`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RiskViolation {
+    /// Price × size exceeds the per-order notional limit
+    OrderNotional { notional: Decimal, limit: Decimal },
+
+    /// The order, with the orders resting or in flight on its side, could
+    /// take the net position in its token beyond the limit
+    Position {
+        token_id: TokenId,
+        resulting: Decimal,
+        limit: Decimal,
+    },
+
+    /// The open-order limit has been reached
+    OpenOrders { open: usize, limit: usize },
+
+    /// The price is further from mid than the collar allows
+    PriceCollar {
+        price: Decimal,
+        mid: Decimal,
+        collar: Decimal,
+    },
+
+    /// A price collar is configured but no mid is known for the token
+    NoReferencePrice(TokenId),
+}
+
+impl fmt::Display for RiskViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RiskViolation::OrderNotional { notional, limit } => {
+                write!(f, "order notional {} exceeds {}", notional, limit)
+            }
+            RiskViolation::Position {
+                token_id,
+                resulting,
+                limit,
+            } => write!(
+                f,
+                "position in {} could reach {}, beyond ±{}",
+                token_id, resulting, limit
+            ),
+            RiskViolation::OpenOrders { open, limit } => {
+                write!(f, "{} orders open, limit is {}", open, limit)
+            }
+            RiskViolation::PriceCollar { price, mid, collar } => write!(
+                f,
+                "price {} is more than {} away from mid {}",
+                price, collar, mid
+            ),
+            RiskViolation::NoReferencePrice(token_id) => {
+                write!(f, "no mid price known for {}", token_id)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::MockHttpServer;
+    use crate::types::{CreateOrderOptions, OrderArgs, OrderId, OrderType, PostOrderArgs};
+    use crate::Error;
+    use rust_decimal_macros::dec;
+
+    fn token() -> TokenId {
+        TokenId::new("123")
+    }
+
+    #[test]
+    fn test_empty_limits_accept_everything() {
+        let state = RiskState::new();
+        assert!(RiskLimits::new()
+            .check(&state, &token(), Side::Buy, dec!(0.99), dec!(1000000))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_notional_and_open_orders() {
+        let limits = RiskLimits::new()
+            .with_max_order_notional(dec!(50))
+            .with_max_open_orders(2);
+        let mut state = RiskState::new();
+
+        assert!(limits
+            .check(&state, &token(), Side::Buy, dec!(0.5), dec!(100))
+            .is_ok());
+        assert_eq!(
+            limits.check(&state, &token(), Side::Sell, dec!(0.5), dec!(101)),
+            Err(RiskViolation::OrderNotional {
+                notional: dec!(50.5),
+                limit: dec!(50)
+            })
+        );
+
+        state.add_open_orders(2);
+        assert_eq!(
+            limits.check(&state, &token(), Side::Buy, dec!(0.5), dec!(1)),
+            Err(RiskViolation::OpenOrders { open: 2, limit: 2 })
+        );
+        state.remove_open_orders(5);
+        assert_eq!(state.open_orders(), 0);
+    }
+
+    #[test]
+    fn test_position_limit_is_net_per_token() {
+        let limits = RiskLimits::new().with_max_token_position(dec!(100));
+        let mut state = RiskState::new();
+        state.record_fill(token(), Side::Buy, dec!(80));
+
+        // Reducing the position is always fine, growing it is capped
+        assert!(limits
+            .check(&state, &token(), Side::Sell, dec!(0.5), dec!(150))
+            .is_ok());
+        assert!(matches!(
+            limits.check(&state, &token(), Side::Buy, dec!(0.5), dec!(30)),
+            Err(RiskViolation::Position { resulting, .. }) if resulting == dec!(110)
+        ));
+        assert!(limits
+            .check(&state, &TokenId::new("456"), Side::Buy, dec!(0.5), dec!(30))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_batch_counts_as_a_whole() {
+        let limits = RiskLimits::new()
+            .with_max_open_orders(3)
+            .with_max_token_position(dec!(100));
+        let mut state = RiskState::new();
+        state.add_open_orders(1);
+        let in_flight = [(token(), Side::Sell, dec!(0.5), dec!(10))];
+        state.reserve(&in_flight);
+
+        let token = token();
+        let buy = |size| (&token, Side::Buy, dec!(0.5), size);
+        assert!(limits.check_batch(&state, [buy(dec!(10))]).is_ok());
+        assert_eq!(
+            limits.check_batch(&state, [buy(dec!(10)), buy(dec!(10))]),
+            Err(RiskViolation::OpenOrders { open: 2, limit: 3 })
+        );
+
+        state.release(&in_flight);
+        assert_eq!(state.open_size(&token, Side::Sell), Decimal::ZERO);
+        assert!(matches!(
+            limits.check_batch(&state, [buy(dec!(60)), buy(dec!(60))]),
+            Err(RiskViolation::Position { resulting, .. }) if resulting == dec!(120)
+        ));
+    }
+
+    #[test]
+    fn test_open_orders_count_towards_position() {
+        let limits = RiskLimits::new().with_max_token_position(dec!(100));
+        let mut state = RiskState::new();
+        let buy = |state: &RiskState| limits.check(state, &token(), Side::Buy, dec!(0.5), dec!(10));
+
+        // Ten resting 10 share bids use up the whole limit
+        for i in 0..10 {
+            assert!(buy(&state).is_ok());
+            let order_id = OrderId::new(format!("0x{}", i));
+            state.add_resting(order_id, token(), Side::Buy, dec!(10));
+        }
+        assert_eq!(state.open_size(&token(), Side::Buy), dec!(100));
+        assert!(matches!(
+            buy(&state),
+            Err(RiskViolation::Position { resulting, .. }) if resulting == dec!(110)
+        ));
+        // Selling is unaffected by resting bids
+        assert!(limits
+            .check(&state, &token(), Side::Sell, dec!(0.5), dec!(100))
+            .is_ok());
+
+        // Filled shares move from the order into the position
+        assert!(state.record_order_fill(&OrderId::new("0x0"), dec!(10)));
+        assert_eq!(state.position(&token()), dec!(10));
+        assert_eq!(state.open_size(&token(), Side::Buy), dec!(90));
+        assert_eq!(state.open_orders(), 9);
+
+        state.remove_resting(&[OrderId::new("0x1")]);
+        assert!(buy(&state).is_ok());
+    }
+
+    #[test]
+    fn test_price_collar_needs_mid() {
+        let limits = RiskLimits::new().with_price_collar(dec!(0.05));
+        let mut state = RiskState::new();
+        assert_eq!(
+            limits.check(&state, &token(), Side::Buy, dec!(0.5), dec!(1)),
+            Err(RiskViolation::NoReferencePrice(token()))
+        );
+
+        state.set_mid(token(), dec!(0.40));
+        assert!(limits
+            .check(&state, &token(), Side::Sell, dec!(0.35), dec!(1))
+            .is_ok());
+        assert!(limits
+            .check(&state, &token(), Side::Sell, dec!(0.34), dec!(1))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_trading_client_tracks_open_orders() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        let client = server
+            .trading_client()
+            .with_risk_limits(RiskLimits::new().with_max_open_orders(1));
+
+        let args = OrderArgs::new("123", dec!(0.5), dec!(10), Side::Buy);
+        let options = CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+
+        let order = client
+            .create_order(&args, None, None, options.clone())
+            .unwrap();
+        client.post_order(order, OrderType::Gtc).await.unwrap();
+        assert_eq!(client.risk_state().open_orders(), 1);
+
+        // The fixture order rests on the book, so the limit is now reached
+        let err = client
+            .create_order(&args, None, None, options.clone())
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::RiskLimit(RiskViolation::OpenOrders { open: 1, limit: 1 })
+        ));
+
+        client.cancel(&OrderId::new("0xabc")).await.unwrap();
+        assert_eq!(client.risk_state().open_orders(), 0);
+        assert!(client.create_order(&args, None, None, options).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_stacked_resting_orders_are_rejected() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        let client = server
+            .trading_client()
+            .with_risk_limits(RiskLimits::new().with_max_token_position(dec!(100)));
+
+        let args = OrderArgs::new("123", dec!(0.5), dec!(60), Side::Buy);
+        let options = CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let order = client.create_order(&args, None, None, options).unwrap();
+
+        // The fixture order rests, so its 60 shares count towards the limit
+        client
+            .post_order(order.clone(), OrderType::Gtc)
+            .await
+            .unwrap();
+        assert_eq!(client.risk_state().open_size(&token(), Side::Buy), dec!(60));
+        assert!(matches!(
+            client.post_order(order, OrderType::Gtc).await,
+            Err(Error::RiskLimit(RiskViolation::Position { resulting, .. })) if resulting == dec!(120)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_limits_are_enforced_when_posting() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        let client = server
+            .trading_client()
+            .with_risk_limits(RiskLimits::new().with_max_open_orders(1));
+
+        let args = OrderArgs::new("123", dec!(0.5), dec!(10), Side::Buy);
+        let options = CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let order = client.create_order(&args, None, None, options).unwrap();
+
+        // Both orders pass the check at creation, but only one slot is free
+        let (first, second) = tokio::join!(
+            client.post_order(order.clone(), OrderType::Gtc),
+            client.post_order(order.clone(), OrderType::Gtc)
+        );
+        assert!(first.is_ok());
+        assert!(matches!(
+            second,
+            Err(Error::RiskLimit(RiskViolation::OpenOrders {
+                open: 1,
+                limit: 1
+            }))
+        ));
+        assert_eq!(client.risk_state().open_orders(), 1);
+        assert_eq!(client.risk_state().in_flight(), 0);
+
+        client.cancel(&OrderId::new("0xabc")).await.unwrap();
+        let batch = [
+            PostOrderArgs::new(order.clone(), OrderType::Gtc),
+            PostOrderArgs::new(order, OrderType::Gtc),
+        ];
+        assert!(matches!(
+            client.post_orders(&batch).await,
+            Err(Error::RiskLimit(RiskViolation::OpenOrders {
+                open: 0,
+                limit: 1
+            }))
+        ));
+        let posts = server
+            .requests()
+            .iter()
+            .filter(|request| request.method == "POST")
+            .count();
+        assert_eq!(posts, 1);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::error::{Error, Result};
 use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy::{AwayFromZero, MidpointTowardZero, ToZero};
 use std::collections::HashMap;
@@ -50,13 +51,67 @@ pub static ROUNDING_CONFIG: LazyLock<HashMap<Decimal, RoundConfig>> = LazyLock::
     ])
 });
 
+/// Decimal places of USDC and outcome-token amounts in signed orders and on-chain
+pub const TOKEN_DECIMALS: u32 = 6;
+
 /// Convert decimal amount to token units (multiply by 1e6 and round)
+///
+/// Panics if the amount is negative or too large; see
+/// [`try_decimal_to_token_u64`] for a fallible version.
 pub fn decimal_to_token_u64(amt: Decimal) -> u64 {
-    let mut amt = Decimal::from_scientific("1e6").expect("1e6 is not scientific") * amt;
-    if amt.scale() > 0 {
-        amt = amt.round_dp_with_strategy(0, MidpointTowardZero);
+    try_decimal_to_token_u64(amt).expect("Couldn't round decimal to integer")
+}
+
+/// Convert decimal amount to token units (multiply by 1e6 and round)
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::try_decimal_to_token_u64;
+/// use polymarket_rs::dec;
+///
+/// assert_eq!(try_decimal_to_token_u64(dec!(12.5)).unwrap(), 12_500_000);
+/// assert!(try_decimal_to_token_u64(dec!(-1)).is_err());
+/// ```
+pub fn try_decimal_to_token_u64(amt: Decimal) -> Result<u64> {
+    let mut units = amt
+        .checked_mul(Decimal::from(10u64.pow(TOKEN_DECIMALS)))
+        .ok_or_else(|| Error::InvalidParameter(format!("Token amount out of range: {}", amt)))?;
+    if units.scale() > 0 {
+        units = units.round_dp_with_strategy(0, MidpointTowardZero);
     }
-    amt.try_into().expect("Couldn't round decimal to integer")
+    units
+        .try_into()
+        .map_err(|_| Error::InvalidParameter(format!("Token amount out of range: {}", amt)))
+}
+
+/// Convert token units back into a decimal amount (divide by 1e6)
+///
+/// # Example
+/// ```
+/// use polymarket_rs::orders::token_u64_to_decimal;
+/// use polymarket_rs::dec;
+///
+/// assert_eq!(token_u64_to_decimal(12_500_000), dec!(12.5));
+/// ```
+pub fn token_u64_to_decimal(units: u64) -> Decimal {
+    Decimal::from_i128_with_scale(units as i128, TOKEN_DECIMALS)
+}
+
+/// Convert a float into a decimal amount
+///
+/// Uses the shortest decimal that round-trips to the same float, so `0.1`
+/// becomes exactly `0.1` rather than `0.1000000000000000055511151231`.
+#[cfg(feature = "f64")]
+pub fn decimal_from_f64(value: f64) -> Result<Decimal> {
+    Decimal::from_str(&value.to_string())
+        .map_err(|_| Error::InvalidParameter(format!("Cannot represent {} as a decimal", value)))
+}
+
+/// Convert a decimal amount into the nearest float
+#[cfg(feature = "f64")]
+pub fn decimal_to_f64(value: Decimal) -> f64 {
+    use rust_decimal::prelude::ToPrimitive;
+    value.to_f64().unwrap_or(f64::NAN)
 }
 
 /// Fix amount rounding to ensure proper precision
@@ -87,4 +142,26 @@ mod tests {
         let result = decimal_to_token_u64(Decimal::from_str("1.5").unwrap());
         assert_eq!(result, 1_500_000);
     }
+
+    #[test]
+    fn test_token_units_round_trip() {
+        let amount = Decimal::from_str("0.000001").unwrap();
+        assert_eq!(try_decimal_to_token_u64(amount).unwrap(), 1);
+        assert_eq!(token_u64_to_decimal(1), amount);
+        assert_eq!(token_u64_to_decimal(u64::MAX).scale(), TOKEN_DECIMALS);
+
+        assert!(try_decimal_to_token_u64(Decimal::from(-1)).is_err());
+        assert!(try_decimal_to_token_u64(Decimal::MAX).is_err());
+    }
+
+    #[cfg(feature = "f64")]
+    #[test]
+    fn test_decimal_from_f64() {
+        assert_eq!(
+            decimal_from_f64(0.1).unwrap(),
+            Decimal::from_str("0.1").unwrap()
+        );
+        assert_eq!(decimal_to_f64(Decimal::from_str("0.55").unwrap()), 0.55);
+        assert!(decimal_from_f64(f64::NAN).is_err());
+    }
 }
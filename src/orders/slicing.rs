@@ -0,0 +1,295 @@
+use crate::error::{Error, Result};
+use crate::types::PriceLevel;
+use crate::websocket::OrderBook;
+use crate::Side;
+use rust_decimal::Decimal;
+use std::time::Duration;
+
+/// One child order of a [`SlicePlan`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChildOrder {
+    /// Limit price of the child
+    pub price: Decimal,
+    /// Size in shares
+    pub size: Decimal,
+    /// When to send the child, relative to the start of execution
+    pub delay: Duration,
+}
+
+/// Child orders that together work a parent order
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlicePlan {
+    /// Side of the parent order
+    pub side: Side,
+    /// Children in the order they should be sent
+    pub children: Vec<ChildOrder>,
+    /// Part of the target that did not fit in the plan
+    pub unfilled: Decimal,
+}
+
+impl SlicePlan {
+    /// Total size of all children
+    pub fn planned_size(&self) -> Decimal {
+        self.children.iter().map(|child| child.size).sum()
+    }
+
+    /// Size-weighted average limit price of the children
+    pub fn average_price(&self) -> Option<Decimal> {
+        let size = self.planned_size();
+        if size.is_zero() {
+            return None;
+        }
+        let notional: Decimal = self
+            .children
+            .iter()
+            .map(|child| child.price * child.size)
+            .sum();
+        Some(notional / size)
+    }
+}
+
+/// Splits a large order across book levels, and across time when the book
+/// is too thin
+///
+/// The first wave takes every level within `max_impact` of the touch, one
+/// child per level. Whatever is left is sent in further waves, one per
+/// `wave_interval`, each at the impact limit and sized to the depth that
+/// was available within it, on the assumption the book refills at a
+/// similar rate. After `max_waves` the remainder is reported as
+/// [`SlicePlan::unfilled`]. So is all of it when no level lies within the
+/// impact limit, since there is no depth to size waves by.
+///
+/// The impact limit is rounded to the market's tick size, towards the best
+/// price, and kept inside the valid price range of one tick to one minus a
+/// tick.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::orders::OrderSlicer;
+/// use polymarket_rs::types::PriceLevel;
+/// use polymarket_rs::{dec, Side};
+///
+/// let asks = vec![
+///     PriceLevel { price: dec!(0.50), size: dec!(100) },
+///     PriceLevel { price: dec!(0.51), size: dec!(100) },
+///     PriceLevel { price: dec!(0.60), size: dec!(1000) },
+/// ];
+/// let plan = OrderSlicer::new(dec!(0.02)).plan(&asks, Side::Buy, dec!(500)).unwrap();
+/// assert_eq!(plan.children.len(), 4); // two levels now, two more waves of 200 and 100
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OrderSlicer {
+    max_impact: Decimal,
+    tick_size: Decimal,
+    wave_interval: Duration,
+    max_waves: usize,
+}
+
+impl OrderSlicer {
+    /// Create a slicer that never trades more than `max_impact` away from
+    /// the best price, e.g. `dec!(0.02)` for two cents
+    pub fn new(max_impact: Decimal) -> Self {
+        Self {
+            max_impact,
+            tick_size: Decimal::new(1, 2),
+            wave_interval: Duration::from_secs(1),
+            max_waves: 10,
+        }
+    }
+
+    /// Set the market's tick size, which wave prices are rounded to
+    /// (default: 0.01)
+    pub fn with_tick_size(mut self, tick_size: Decimal) -> Self {
+        self.tick_size = tick_size;
+        self
+    }
+
+    /// Set the pause between waves (default: 1 second)
+    pub fn with_wave_interval(mut self, wave_interval: Duration) -> Self {
+        self.wave_interval = wave_interval;
+        self
+    }
+
+    /// Set the maximum number of waves, including the first (default: 10)
+    pub fn with_max_waves(mut self, max_waves: usize) -> Self {
+        self.max_waves = max_waves.max(1);
+        self
+    }
+
+    /// Plan an order of `size` shares against the levels it would take
+    ///
+    /// `levels` are the asks when buying and the bids when selling, in any
+    /// order.
+    pub fn plan(&self, levels: &[PriceLevel], side: Side, size: Decimal) -> Result<SlicePlan> {
+        if size <= Decimal::ZERO {
+            return Err(Error::InvalidOrder(format!(
+                "Slice size must be positive, got {}",
+                size
+            )));
+        }
+
+        // Best price first: lowest ask when buying, highest bid when selling
+        let mut levels: Vec<&PriceLevel> = levels
+            .iter()
+            .filter(|level| level.size > Decimal::ZERO)
+            .collect();
+        match side {
+            Side::Buy => levels.sort_by(|a, b| a.price.cmp(&b.price)),
+            Side::Sell => levels.sort_by(|a, b| b.price.cmp(&a.price)),
+        }
+        let best = levels.first().map(|level| level.price).ok_or_else(|| {
+            Error::InvalidOrder("No liquidity to slice the order against".to_string())
+        })?;
+        if self.tick_size <= Decimal::ZERO {
+            return Err(Error::InvalidOrder(format!(
+                "Tick size must be positive, got {}",
+                self.tick_size
+            )));
+        }
+        let tick = self.tick_size;
+        let limit = match side {
+            Side::Buy => ((best + self.max_impact) / tick).floor() * tick,
+            Side::Sell => ((best - self.max_impact) / tick).ceil() * tick,
+        }
+        .clamp(tick, Decimal::ONE - tick);
+        let within_limit = |price: Decimal| match side {
+            Side::Buy => price <= limit,
+            Side::Sell => price >= limit,
+        };
+
+        let mut children = Vec::new();
+        let mut remaining = size;
+        let mut depth = Decimal::ZERO;
+        for level in levels
+            .into_iter()
+            .take_while(|level| within_limit(level.price))
+        {
+            depth += level.size;
+            if remaining > Decimal::ZERO {
+                let take = remaining.min(level.size);
+                children.push(ChildOrder {
+                    price: level.price,
+                    size: take,
+                    delay: Duration::ZERO,
+                });
+                remaining -= take;
+            }
+        }
+
+        // Without depth inside the limit, waves would all be empty
+        let mut wave = 1;
+        while remaining > Decimal::ZERO && !depth.is_zero() && wave < self.max_waves {
+            let take = remaining.min(depth);
+            children.push(ChildOrder {
+                price: limit,
+                size: take,
+                delay: self.wave_interval * wave as u32,
+            });
+            remaining -= take;
+            wave += 1;
+        }
+
+        Ok(SlicePlan {
+            side,
+            children,
+            unfilled: remaining,
+        })
+    }
+
+    /// Plan an order against a locally maintained [`OrderBook`]
+    pub fn plan_from_book(&self, book: &OrderBook, side: Side, size: Decimal) -> Result<SlicePlan> {
+        let levels: Vec<PriceLevel> = match side {
+            Side::Buy => book.asks().collect(),
+            Side::Sell => book.bids().collect(),
+        };
+        self.plan(&levels, side, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal, size: Decimal) -> PriceLevel {
+        PriceLevel { price, size }
+    }
+
+    #[test]
+    fn test_fits_within_impact() {
+        let bids = vec![
+            level(dec!(0.48), dec!(100)),
+            level(dec!(0.50), dec!(40)),
+            level(dec!(0.49), dec!(100)),
+        ];
+        let plan = OrderSlicer::new(dec!(0.01))
+            .plan(&bids, Side::Sell, dec!(90))
+            .unwrap();
+
+        let prices: Vec<_> = plan.children.iter().map(|child| child.price).collect();
+        assert_eq!(prices, [dec!(0.50), dec!(0.49)]);
+        assert_eq!(plan.planned_size(), dec!(90));
+        assert!(plan.unfilled.is_zero());
+        assert!(plan.children.iter().all(|child| child.delay.is_zero()));
+    }
+
+    #[test]
+    fn test_thin_book_spills_into_waves() {
+        let asks = vec![level(dec!(0.50), dec!(30)), level(dec!(0.70), dec!(1000))];
+        let plan = OrderSlicer::new(dec!(0.05))
+            .with_wave_interval(Duration::from_secs(2))
+            .with_max_waves(3)
+            .plan(&asks, Side::Buy, dec!(100))
+            .unwrap();
+
+        assert_eq!(plan.children.len(), 3);
+        assert_eq!(plan.children[2].price, dec!(0.55));
+        assert_eq!(plan.children[2].delay, Duration::from_secs(4));
+        assert_eq!(plan.planned_size(), dec!(90));
+        assert_eq!(plan.unfilled, dec!(10));
+    }
+
+    #[test]
+    fn test_wave_price_stays_on_tick_and_in_range() {
+        let asks = vec![level(dec!(0.97), dec!(10))];
+        let plan = OrderSlicer::new(dec!(0.05))
+            .plan(&asks, Side::Buy, dec!(30))
+            .unwrap();
+        assert_eq!(plan.children[1].price, dec!(0.99));
+
+        let bids = vec![level(dec!(0.50), dec!(10))];
+        let plan = OrderSlicer::new(dec!(0.0125))
+            .with_tick_size(dec!(0.005))
+            .plan(&bids, Side::Sell, dec!(30))
+            .unwrap();
+        assert_eq!(plan.children[1].price, dec!(0.490));
+
+        let bids = vec![level(dec!(0.02), dec!(10))];
+        let plan = OrderSlicer::new(dec!(0.05))
+            .plan(&bids, Side::Sell, dec!(30))
+            .unwrap();
+        assert_eq!(plan.children[1].price, dec!(0.01));
+    }
+
+    #[test]
+    fn test_no_depth_within_limit_is_unfilled() {
+        // The impact limit is clamped to 0.99, below the only ask
+        let asks = vec![level(dec!(0.995), dec!(100))];
+        let plan = OrderSlicer::new(dec!(0.05))
+            .with_tick_size(dec!(0.01))
+            .plan(&asks, Side::Buy, dec!(30))
+            .unwrap();
+
+        assert!(plan.children.is_empty());
+        assert_eq!(plan.unfilled, dec!(30));
+    }
+
+    #[test]
+    fn test_rejects_empty_book() {
+        let slicer = OrderSlicer::new(dec!(0.01));
+        assert!(slicer.plan(&[], Side::Buy, dec!(10)).is_err());
+        let asks = vec![level(dec!(0.50), dec!(10))];
+        assert!(slicer.plan(&asks, Side::Buy, Decimal::ZERO).is_err());
+    }
+}
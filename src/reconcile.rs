@@ -0,0 +1,443 @@
+//! Reconciliation of recorded order activity against the exchange
+//!
+//! An [`OrderLedger`] replays what a session saw — order acknowledgements
+//! and cancels from the [`audit`](crate::audit) log, order updates and fills
+//! from the user websocket — into the set of orders it believes are open and
+//! the fills it received. [`OrderLedger::reconcile`] compares that against
+//! the exchange's open orders and trade history and reports every entry
+//! that is missing, extra or out of sync, for end-of-day checks.
+//!
+//! [`TradingClient::reconcile`](crate::TradingClient::reconcile) fetches the
+//! exchange side and runs the comparison in one call.
+
+use crate::audit::{AuditEventKind, AuditRecord};
+use crate::error::Result;
+use crate::types::{OpenOrder, OrderId, Side, TokenId, UserWsEvent};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// What the ledger knows about one order
+#[derive(Debug, Clone, PartialEq)]
+pub struct LedgerOrder {
+    pub order_id: OrderId,
+    /// Whether the order is believed to be resting on the book
+    pub open: bool,
+    /// Matched size from the latest order update, if one was seen
+    pub size_matched: Option<Decimal>,
+}
+
+/// A trade, as recorded locally or reported by the exchange
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fill {
+    /// Trade ID
+    pub id: String,
+    pub asset_id: TokenId,
+    pub side: Side,
+    pub price: Decimal,
+    pub size: Decimal,
+    /// Trade status, e.g. `MATCHED` or `CONFIRMED`
+    pub status: String,
+}
+
+impl Fill {
+    /// Parse a trade from [`get_trades`](crate::TradingClient::get_trades)
+    /// or a user websocket trade event payload
+    pub fn from_trade(trade: &serde_json::Value) -> Result<Self> {
+        let raw = RawTrade::deserialize(trade)?;
+        Ok(Self {
+            id: raw.id,
+            asset_id: raw.asset_id,
+            side: raw.side,
+            price: raw.price,
+            size: raw.size,
+            status: raw.status,
+        })
+    }
+}
+
+/// Fields shared by REST trades and websocket trade events
+#[derive(Deserialize)]
+struct RawTrade {
+    id: String,
+    asset_id: TokenId,
+    side: Side,
+    #[serde(with = "rust_decimal::serde::str")]
+    price: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    size: Decimal,
+    status: String,
+}
+
+/// Fields of a websocket order event the ledger tracks
+#[derive(Deserialize)]
+struct RawOrderUpdate {
+    id: OrderId,
+    #[serde(with = "rust_decimal::serde::str")]
+    original_size: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    size_matched: Decimal,
+    #[serde(rename = "type")]
+    update_type: String,
+}
+
+/// Fields of an order submission acknowledgement
+#[derive(Deserialize)]
+struct RawAcknowledgement {
+    #[serde(rename = "orderID")]
+    order_id: OrderId,
+    status: String,
+    success: bool,
+}
+
+/// Orders and fills as recorded by this session
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::audit::JsonlAuditLog;
+/// use polymarket_rs::reconcile::OrderLedger;
+/// use polymarket_rs::types::TradeParams;
+///
+/// # async fn example(client: polymarket_rs::TradingClient, params: TradeParams) -> polymarket_rs::Result<()> {
+/// let ledger = OrderLedger::from_audit_records(&JsonlAuditLog::read("audit/session.jsonl")?);
+/// let report = client.reconcile(&ledger, params).await?;
+/// if !report.is_clean() {
+///     eprintln!("{:#?}", report);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OrderLedger {
+    orders: HashMap<OrderId, LedgerOrder>,
+    fills: HashMap<String, Fill>,
+}
+
+impl OrderLedger {
+    /// Create an empty ledger
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replay an audit log, oldest record first
+    pub fn from_audit_records<'a>(records: impl IntoIterator<Item = &'a AuditRecord>) -> Self {
+        let mut ledger = Self::new();
+        for record in records {
+            ledger.record(record);
+        }
+        ledger
+    }
+
+    /// Apply one audit record
+    ///
+    /// Acknowledged orders resting on the book become open; acknowledged
+    /// cancels close them. Fills and order updates are applied as by
+    /// [`record_user_event`](Self::record_user_event). Submissions,
+    /// rejections and records with unrecognised payloads are ignored.
+    pub fn record(&mut self, record: &AuditRecord) {
+        match record.kind {
+            AuditEventKind::OrderAcknowledged => {
+                let acknowledgements = match &record.payload {
+                    serde_json::Value::Array(items) => items.iter().collect(),
+                    payload => vec![payload],
+                };
+                for acknowledgement in acknowledgements {
+                    if let Ok(ack) = RawAcknowledgement::deserialize(acknowledgement) {
+                        if ack.success {
+                            let open =
+                                matches!(ack.status.to_lowercase().as_str(), "live" | "delayed");
+                            self.order_mut(ack.order_id).open = open;
+                        }
+                    }
+                }
+            }
+            AuditEventKind::CancelAcknowledged => {
+                if let Some(canceled) = record.payload["canceled"].as_array() {
+                    for order_id in canceled.iter().filter_map(|id| id.as_str()) {
+                        self.order_mut(OrderId::new(order_id)).open = false;
+                    }
+                }
+            }
+            AuditEventKind::Fill => self.record_trade(&record.payload),
+            AuditEventKind::OrderUpdate => self.record_order_update(&record.payload),
+            _ => {}
+        }
+    }
+
+    /// Apply a user websocket event, for ledgers fed directly from the stream
+    pub fn record_user_event(&mut self, event: &UserWsEvent) {
+        if let Ok(payload) = serde_json::to_value(event) {
+            match event {
+                UserWsEvent::Trade(_) => self.record_trade(&payload),
+                UserWsEvent::Order(_) => self.record_order_update(&payload),
+            }
+        }
+    }
+
+    fn record_trade(&mut self, payload: &serde_json::Value) {
+        if let Ok(fill) = Fill::from_trade(payload) {
+            self.fills.insert(fill.id.clone(), fill);
+        }
+    }
+
+    fn record_order_update(&mut self, payload: &serde_json::Value) {
+        if let Ok(update) = RawOrderUpdate::deserialize(payload) {
+            let order = self.order_mut(update.id);
+            order.open = !update.update_type.eq_ignore_ascii_case("cancellation")
+                && update.size_matched < update.original_size;
+            order.size_matched = Some(update.size_matched);
+        }
+    }
+
+    fn order_mut(&mut self, order_id: OrderId) -> &mut LedgerOrder {
+        self.orders
+            .entry(order_id.clone())
+            .or_insert_with(|| LedgerOrder {
+                order_id,
+                open: false,
+                size_matched: None,
+            })
+    }
+
+    /// Every order the ledger has seen
+    pub fn orders(&self) -> impl Iterator<Item = &LedgerOrder> {
+        self.orders.values()
+    }
+
+    /// Every fill the ledger has seen, with its latest status
+    pub fn fills(&self) -> impl Iterator<Item = &Fill> {
+        self.fills.values()
+    }
+
+    /// Compare the ledger with the exchange's open orders and trades
+    ///
+    /// `trades` should cover the same period as the ledger: fills recorded
+    /// before the earliest trade fetched are reported as extra.
+    pub fn reconcile(&self, open_orders: &[OpenOrder], trades: &[Fill]) -> ReconciliationReport {
+        let mut report = ReconciliationReport::default();
+
+        for remote in open_orders {
+            match self.orders.get(&remote.id) {
+                Some(local) if local.open => {
+                    if let Some(size_matched) = local.size_matched {
+                        if size_matched != remote.size_matched {
+                            report.mismatched_orders.push(OrderMismatch {
+                                order_id: remote.id.clone(),
+                                local_size_matched: size_matched,
+                                remote_size_matched: remote.size_matched,
+                            });
+                        }
+                    }
+                }
+                _ => report.missing_orders.push(remote.id.clone()),
+            }
+        }
+        report.extra_orders = self
+            .orders
+            .values()
+            .filter(|local| local.open && !open_orders.iter().any(|o| o.id == local.order_id))
+            .map(|local| local.order_id.clone())
+            .collect();
+
+        for remote in trades {
+            match self.fills.get(&remote.id) {
+                Some(local) if local != remote => report.mismatched_fills.push(FillMismatch {
+                    local: local.clone(),
+                    remote: remote.clone(),
+                }),
+                Some(_) => {}
+                None => report.missing_fills.push(remote.clone()),
+            }
+        }
+        report.extra_fills = self
+            .fills
+            .values()
+            .filter(|local| !trades.iter().any(|t| t.id == local.id))
+            .cloned()
+            .collect();
+
+        report.sort();
+        report
+    }
+}
+
+/// An order open both locally and on the exchange with different fills
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderMismatch {
+    pub order_id: OrderId,
+    pub local_size_matched: Decimal,
+    pub remote_size_matched: Decimal,
+}
+
+/// A trade recorded both locally and on the exchange with different details
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillMismatch {
+    pub local: Fill,
+    pub remote: Fill,
+}
+
+/// Differences between an [`OrderLedger`] and the exchange
+///
+/// "Missing" entries exist on the exchange but not in the ledger; "extra"
+/// entries exist in the ledger but not on the exchange. Each list is sorted
+/// by ID.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReconciliationReport {
+    /// Open on the exchange, but unknown or closed in the ledger
+    pub missing_orders: Vec<OrderId>,
+    /// Open in the ledger, but not open on the exchange
+    pub extra_orders: Vec<OrderId>,
+    /// Open on both with a different matched size
+    pub mismatched_orders: Vec<OrderMismatch>,
+    /// Trades on the exchange the ledger never received
+    pub missing_fills: Vec<Fill>,
+    /// Fills in the ledger the exchange doesn't report
+    pub extra_fills: Vec<Fill>,
+    /// Trades on both with a different price, size, side or status
+    pub mismatched_fills: Vec<FillMismatch>,
+}
+
+impl ReconciliationReport {
+    /// Whether the ledger and the exchange agree
+    pub fn is_clean(&self) -> bool {
+        self.missing_orders.is_empty()
+            && self.extra_orders.is_empty()
+            && self.mismatched_orders.is_empty()
+            && self.missing_fills.is_empty()
+            && self.extra_fills.is_empty()
+            && self.mismatched_fills.is_empty()
+    }
+
+    fn sort(&mut self) {
+        self.missing_orders
+            .sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        self.extra_orders.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        self.mismatched_orders
+            .sort_by(|a, b| a.order_id.as_str().cmp(b.order_id.as_str()));
+        self.missing_fills.sort_by(|a, b| a.id.cmp(&b.id));
+        self.extra_fills.sort_by(|a, b| a.id.cmp(&b.id));
+        self.mismatched_fills
+            .sort_by(|a, b| a.remote.id.cmp(&b.remote.id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn trade(id: &str, size: &str, status: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "taker_order_id": "0x1",
+            "asset_id": "123",
+            "side": "BUY",
+            "price": "0.5",
+            "size": size,
+            "status": status,
+        })
+    }
+
+    fn open_order(id: &str, size_matched: &str) -> OpenOrder {
+        serde_json::from_value(json!({
+            "id": id,
+            "associate_trades": [],
+            "status": "LIVE",
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "original_size": "100",
+            "outcome": "Yes",
+            "maker_address": "0x0",
+            "owner": "owner",
+            "price": "0.5",
+            "side": "BUY",
+            "size_matched": size_matched,
+            "asset_id": "123",
+            "order_type": "GTC",
+            "created_at": 1700000000
+        }))
+        .unwrap()
+    }
+
+    fn record(kind: AuditEventKind, payload: serde_json::Value) -> AuditRecord {
+        AuditRecord::new(kind, None, payload)
+    }
+
+    #[test]
+    fn test_ledger_replays_audit_log() {
+        let records = [
+            record(
+                AuditEventKind::OrderAcknowledged,
+                json!([
+                    { "orderID": "0x1", "status": "live", "success": true, "errorMsg": "" },
+                    { "orderID": "0x2", "status": "live", "success": true, "errorMsg": "" },
+                    { "orderID": "0x3", "status": "matched", "success": true, "errorMsg": "" },
+                ]),
+            ),
+            record(
+                AuditEventKind::CancelAcknowledged,
+                json!({ "canceled": ["0x2"], "not_canceled": {} }),
+            ),
+            record(
+                AuditEventKind::OrderUpdate,
+                json!({
+                    "event_type": "order", "id": "0x1", "type": "UPDATE",
+                    "original_size": "100", "size_matched": "10"
+                }),
+            ),
+            record(AuditEventKind::Fill, trade("t1", "10", "MATCHED")),
+        ];
+        let ledger = OrderLedger::from_audit_records(&records);
+
+        let mut open: Vec<_> = ledger.orders().filter(|o| o.open).collect();
+        open.sort_by(|a, b| a.order_id.as_str().cmp(b.order_id.as_str()));
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].order_id, OrderId::new("0x1"));
+        assert_eq!(open[0].size_matched, Some(dec!(10)));
+        assert_eq!(ledger.fills().count(), 1);
+    }
+
+    #[test]
+    fn test_report_lists_discrepancies() {
+        let records = [
+            record(
+                AuditEventKind::OrderAcknowledged,
+                json!([
+                    { "orderID": "0x1", "status": "live", "success": true, "errorMsg": "" },
+                    { "orderID": "0x2", "status": "live", "success": true, "errorMsg": "" },
+                ]),
+            ),
+            record(
+                AuditEventKind::OrderUpdate,
+                json!({
+                    "id": "0x1", "type": "UPDATE", "original_size": "100", "size_matched": "10"
+                }),
+            ),
+            record(AuditEventKind::Fill, trade("t1", "10", "MATCHED")),
+            record(AuditEventKind::Fill, trade("t2", "5", "CONFIRMED")),
+        ];
+        let ledger = OrderLedger::from_audit_records(&records);
+
+        let open_orders = [open_order("0x1", "20"), open_order("0x9", "0")];
+        let trades: Vec<Fill> = [
+            trade("t1", "10", "CONFIRMED"),
+            trade("t3", "10", "CONFIRMED"),
+        ]
+        .iter()
+        .map(|t| Fill::from_trade(t).unwrap())
+        .collect();
+
+        let report = ledger.reconcile(&open_orders, &trades);
+        assert!(!report.is_clean());
+        assert_eq!(report.missing_orders, [OrderId::new("0x9")]);
+        assert_eq!(report.extra_orders, [OrderId::new("0x2")]);
+        assert_eq!(report.mismatched_orders[0].remote_size_matched, dec!(20));
+        assert_eq!(report.missing_fills[0].id, "t3");
+        assert_eq!(report.extra_fills[0].id, "t2");
+        assert_eq!(report.mismatched_fills[0].local.status, "MATCHED");
+
+        let clean = OrderLedger::new().reconcile(&[], &[]);
+        assert!(clean.is_clean());
+    }
+}
@@ -1,3 +1,16 @@
+use rust_decimal::Decimal;
+
+use super::encode_query_value;
+use crate::types::{ActivityType, ConditionId, Side};
+
+/// Join condition IDs into a comma-separated query value
+fn join_ids(ids: &[ConditionId]) -> String {
+    ids.iter()
+        .map(|id| encode_query_value(id.as_str()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 /// Sort direction for activity queries
 #[derive(Debug, Clone)]
 pub enum SortDirection {
@@ -28,12 +41,158 @@ impl ActivitySortBy {
     }
 }
 
+/// Sort field for position queries
+#[derive(Debug, Clone)]
+pub enum PositionSortBy {
+    Current,
+    Initial,
+    Tokens,
+    CashPnl,
+    PercentPnl,
+    Title,
+    Resolving,
+    Price,
+    AvgPrice,
+}
+
+impl PositionSortBy {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PositionSortBy::Current => "CURRENT",
+            PositionSortBy::Initial => "INITIAL",
+            PositionSortBy::Tokens => "TOKENS",
+            PositionSortBy::CashPnl => "CASHPNL",
+            PositionSortBy::PercentPnl => "PERCENTPNL",
+            PositionSortBy::Title => "TITLE",
+            PositionSortBy::Resolving => "RESOLVING",
+            PositionSortBy::Price => "PRICE",
+            PositionSortBy::AvgPrice => "AVGPRICE",
+        }
+    }
+}
+
+/// Query parameters for position endpoints with filtering, offset/limit pagination and sorting
+#[derive(Debug, Clone, Default)]
+pub struct PositionQueryParams {
+    pub markets: Vec<ConditionId>,
+    pub event_id: Option<String>,
+    pub size_threshold: Option<Decimal>,
+    pub redeemable: Option<bool>,
+    pub mergeable: Option<bool>,
+    pub title: Option<String>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub sort_by: Option<PositionSortBy>,
+    pub sort_direction: Option<SortDirection>,
+}
+
+impl PositionQueryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict results to the given market (condition ID); may be called repeatedly
+    pub fn with_market(mut self, condition_id: impl Into<ConditionId>) -> Self {
+        self.markets.push(condition_id.into());
+        self
+    }
+
+    pub fn with_event_id(mut self, event_id: impl Into<String>) -> Self {
+        self.event_id = Some(event_id.into());
+        self
+    }
+
+    /// Only return positions whose size is at least this many shares
+    pub fn with_size_threshold(mut self, size_threshold: Decimal) -> Self {
+        self.size_threshold = Some(size_threshold);
+        self
+    }
+
+    pub fn with_redeemable(mut self, redeemable: bool) -> Self {
+        self.redeemable = Some(redeemable);
+        self
+    }
+
+    pub fn with_mergeable(mut self, mergeable: bool) -> Self {
+        self.mergeable = Some(mergeable);
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn with_sort_by(mut self, sort_by: PositionSortBy) -> Self {
+        self.sort_by = Some(sort_by);
+        self
+    }
+
+    pub fn with_sort_direction(mut self, sort_direction: SortDirection) -> Self {
+        self.sort_direction = Some(sort_direction);
+        self
+    }
+
+    pub fn to_query_string(&self) -> String {
+        let mut params = Vec::new();
+
+        if !self.markets.is_empty() {
+            params.push(format!("market={}", join_ids(&self.markets)));
+        }
+        if let Some(ref event_id) = self.event_id {
+            params.push(format!("eventId={}", encode_query_value(event_id)));
+        }
+        if let Some(size_threshold) = self.size_threshold {
+            params.push(format!("sizeThreshold={}", size_threshold));
+        }
+        if let Some(redeemable) = self.redeemable {
+            params.push(format!("redeemable={}", redeemable));
+        }
+        if let Some(mergeable) = self.mergeable {
+            params.push(format!("mergeable={}", mergeable));
+        }
+        if let Some(ref title) = self.title {
+            params.push(format!("title={}", encode_query_value(title)));
+        }
+        if let Some(limit) = self.limit {
+            params.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            params.push(format!("offset={}", offset));
+        }
+        if let Some(ref sort_by) = self.sort_by {
+            params.push(format!("sortBy={}", sort_by.as_str()));
+        }
+        if let Some(ref sort_direction) = self.sort_direction {
+            params.push(format!("sortDirection={}", sort_direction.as_str()));
+        }
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("&{}", params.join("&"))
+        }
+    }
+}
+
 /// Query parameters for trade endpoints with offset/limit pagination
 #[derive(Debug, Clone, Default)]
 pub struct TradeQueryParams {
     pub limit: Option<u32>,
     pub offset: Option<u32>,
     pub taker_only: Option<bool>,
+    pub markets: Vec<ConditionId>,
+    pub side: Option<Side>,
 }
 
 impl TradeQueryParams {
@@ -56,6 +215,17 @@ impl TradeQueryParams {
         self
     }
 
+    /// Restrict results to the given market (condition ID); may be called repeatedly
+    pub fn with_market(mut self, condition_id: impl Into<ConditionId>) -> Self {
+        self.markets.push(condition_id.into());
+        self
+    }
+
+    pub fn with_side(mut self, side: Side) -> Self {
+        self.side = Some(side);
+        self
+    }
+
     pub fn to_query_string(&self) -> String {
         let mut params = Vec::new();
 
@@ -68,6 +238,12 @@ impl TradeQueryParams {
         if let Some(taker_only) = self.taker_only {
             params.push(format!("takerOnly={}", taker_only));
         }
+        if !self.markets.is_empty() {
+            params.push(format!("market={}", join_ids(&self.markets)));
+        }
+        if let Some(side) = self.side {
+            params.push(format!("side={}", side.as_str()));
+        }
 
         if params.is_empty() {
             String::new()
@@ -84,6 +260,11 @@ pub struct ActivityQueryParams {
     pub offset: Option<u32>,
     pub sort_by: Option<ActivitySortBy>,
     pub sort_direction: Option<SortDirection>,
+    pub activity_types: Vec<ActivityType>,
+    pub markets: Vec<ConditionId>,
+    pub side: Option<Side>,
+    pub start: Option<u64>,
+    pub end: Option<u64>,
 }
 
 impl ActivityQueryParams {
@@ -111,6 +292,35 @@ impl ActivityQueryParams {
         self
     }
 
+    /// Restrict results to the given activity type (trade, split, merge, redeem, ...); may be called repeatedly
+    pub fn with_activity_type(mut self, activity_type: ActivityType) -> Self {
+        self.activity_types.push(activity_type);
+        self
+    }
+
+    /// Restrict results to the given market (condition ID); may be called repeatedly
+    pub fn with_market(mut self, condition_id: impl Into<ConditionId>) -> Self {
+        self.markets.push(condition_id.into());
+        self
+    }
+
+    pub fn with_side(mut self, side: Side) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// Only return activity at or after this Unix timestamp (seconds)
+    pub fn with_start(mut self, start: u64) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Only return activity at or before this Unix timestamp (seconds)
+    pub fn with_end(mut self, end: u64) -> Self {
+        self.end = Some(end);
+        self
+    }
+
     pub fn to_query_string(&self) -> String {
         let mut params = Vec::new();
 
@@ -126,6 +336,22 @@ impl ActivityQueryParams {
         if let Some(offset) = self.offset {
             params.push(format!("offset={}", offset));
         }
+        if !self.activity_types.is_empty() {
+            let types: Vec<&str> = self.activity_types.iter().map(|t| t.as_str()).collect();
+            params.push(format!("type={}", types.join(",")));
+        }
+        if !self.markets.is_empty() {
+            params.push(format!("market={}", join_ids(&self.markets)));
+        }
+        if let Some(side) = self.side {
+            params.push(format!("side={}", side.as_str()));
+        }
+        if let Some(start) = self.start {
+            params.push(format!("start={}", start));
+        }
+        if let Some(end) = self.end {
+            params.push(format!("end={}", end));
+        }
 
         if params.is_empty() {
             String::new()
@@ -134,3 +360,189 @@ impl ActivityQueryParams {
         }
     }
 }
+
+/// Time window for leaderboard queries
+#[derive(Debug, Clone)]
+pub enum LeaderboardPeriod {
+    Day,
+    Week,
+    Month,
+    All,
+}
+
+impl LeaderboardPeriod {
+    pub fn as_str(&self) -> &str {
+        match self {
+            LeaderboardPeriod::Day => "DAY",
+            LeaderboardPeriod::Week => "WEEK",
+            LeaderboardPeriod::Month => "MONTH",
+            LeaderboardPeriod::All => "ALL",
+        }
+    }
+}
+
+/// Ranking metric for leaderboard queries
+#[derive(Debug, Clone)]
+pub enum LeaderboardOrderBy {
+    /// Rank by profit and loss
+    Pnl,
+    /// Rank by traded volume
+    Volume,
+}
+
+impl LeaderboardOrderBy {
+    pub fn as_str(&self) -> &str {
+        match self {
+            LeaderboardOrderBy::Pnl => "PNL",
+            LeaderboardOrderBy::Volume => "VOL",
+        }
+    }
+}
+
+/// Query parameters for the leaderboard endpoint
+#[derive(Debug, Clone, Default)]
+pub struct LeaderboardParams {
+    pub period: Option<LeaderboardPeriod>,
+    pub order_by: Option<LeaderboardOrderBy>,
+    pub category: Option<String>,
+    pub user: Option<String>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+impl LeaderboardParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_period(mut self, period: LeaderboardPeriod) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    pub fn with_order_by(mut self, order_by: LeaderboardOrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Restrict the ranking to a market category (e.g. "POLITICS", "SPORTS", "CRYPTO")
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    /// Only return the entry for this wallet address
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn to_query_string(&self) -> String {
+        let mut params = Vec::new();
+
+        if let Some(ref period) = self.period {
+            params.push(format!("timePeriod={}", period.as_str()));
+        }
+        if let Some(ref order_by) = self.order_by {
+            params.push(format!("orderBy={}", order_by.as_str()));
+        }
+        if let Some(ref category) = self.category {
+            params.push(format!("category={}", encode_query_value(category)));
+        }
+        if let Some(ref user) = self.user {
+            params.push(format!("user={}", encode_query_value(user)));
+        }
+        if let Some(limit) = self.limit {
+            params.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            params.push(format!("offset={}", offset));
+        }
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", params.join("&"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_empty_position_params() {
+        assert_eq!(PositionQueryParams::new().to_query_string(), "");
+    }
+
+    #[test]
+    fn test_position_params_query_string() {
+        let params = PositionQueryParams::new()
+            .with_market("0xabc")
+            .with_market("0xdef")
+            .with_size_threshold(dec!(1.5))
+            .with_redeemable(true)
+            .with_sort_by(PositionSortBy::CashPnl)
+            .with_sort_direction(SortDirection::Desc);
+
+        let query = params.to_query_string();
+        assert!(query.starts_with('&'));
+        assert!(query.contains("market=0xabc,0xdef"));
+        assert!(query.contains("sizeThreshold=1.5"));
+        assert!(query.contains("redeemable=true"));
+        assert!(query.contains("sortBy=CASHPNL"));
+        assert!(query.contains("sortDirection=DESC"));
+    }
+
+    #[test]
+    fn test_position_params_are_encoded() {
+        let query = PositionQueryParams::new()
+            .with_title("Yes & No?")
+            .with_event_id("1&limit=0")
+            .to_query_string();
+        assert_eq!(query, "&eventId=1%26limit%3D0&title=Yes%20%26%20No%3F");
+    }
+
+    #[test]
+    fn test_activity_params_query_string() {
+        let params = ActivityQueryParams::new()
+            .with_activity_type(ActivityType::Split)
+            .with_activity_type(ActivityType::Redeem)
+            .with_start(1_700_000_000)
+            .with_end(1_700_086_400);
+
+        let query = params.to_query_string();
+        assert!(query.contains("type=SPLIT,REDEEM"));
+        assert!(query.contains("start=1700000000"));
+        assert!(query.contains("end=1700086400"));
+    }
+
+    #[test]
+    fn test_leaderboard_params_query_string() {
+        assert_eq!(LeaderboardParams::new().to_query_string(), "");
+
+        let query = LeaderboardParams::new()
+            .with_period(LeaderboardPeriod::Week)
+            .with_order_by(LeaderboardOrderBy::Volume)
+            .with_limit(10)
+            .to_query_string();
+        assert_eq!(query, "?timePeriod=WEEK&orderBy=VOL&limit=10");
+
+        let query = LeaderboardParams::new()
+            .with_category("sports & esports")
+            .to_query_string();
+        assert_eq!(query, "?category=sports%20%26%20esports");
+    }
+}
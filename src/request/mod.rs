@@ -2,6 +2,47 @@ mod data_params;
 mod gamma_params;
 mod pagination;
 
-pub use data_params::{ActivityQueryParams, ActivitySortBy, SortDirection, TradeQueryParams};
+pub use data_params::{
+    ActivityQueryParams, ActivitySortBy, LeaderboardOrderBy, LeaderboardParams, LeaderboardPeriod,
+    PositionQueryParams, PositionSortBy, SortDirection, TradeQueryParams,
+};
 pub use gamma_params::GammaMarketParams;
 pub use pagination::{PaginationParams, END_CURSOR, INITIAL_CURSOR};
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+/// Percent-encode a query parameter value
+///
+/// Everything but the RFC 3986 unreserved characters is escaped, so a value
+/// can't end its parameter early or add others.
+pub(crate) fn encode_query_value(value: &str) -> Cow<'_, str> {
+    let unreserved = |byte: u8| byte.is_ascii_alphanumeric() || b"-._~".contains(&byte);
+    if value.bytes().all(unreserved) {
+        return Cow::Borrowed(value);
+    }
+    let mut encoded = String::with_capacity(value.len() * 3);
+    for byte in value.bytes() {
+        if unreserved(byte) {
+            encoded.push(byte as char);
+        } else {
+            let _ = write!(encoded, "%{:02X}", byte);
+        }
+    }
+    Cow::Owned(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_query_value() {
+        assert!(matches!(
+            encode_query_value("0xAbC-1_2.3~"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(encode_query_value("a b&c=d"), "a%20b%26c%3Dd");
+        assert_eq!(encode_query_value("€"), "%E2%82%AC");
+    }
+}
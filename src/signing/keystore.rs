@@ -0,0 +1,51 @@
+use crate::error::{Error, Result};
+use alloy_signer_local::PrivateKeySigner;
+use std::path::Path;
+
+/// Decrypt a signer from an encrypted JSON keystore (Web3 Secret Storage)
+///
+/// Keystores are produced by tools such as `cast wallet import`, geth or
+/// MetaMask exports, and keep the raw private key out of environment
+/// variables and shell history.
+///
+/// # Example
+///
+/// ```no_run
+/// # use polymarket_rs::signing::load_keystore;
+/// let password = std::env::var("KEYSTORE_PASSWORD").unwrap();
+/// let signer = load_keystore("/etc/polymarket/keystore.json", password)?;
+/// # Ok::<(), polymarket_rs::Error>(())
+/// ```
+pub fn load_keystore(
+    path: impl AsRef<Path>,
+    password: impl AsRef<[u8]>,
+) -> Result<PrivateKeySigner> {
+    let path = path.as_ref();
+    PrivateKeySigner::decrypt_keystore(path, password).map_err(|e| {
+        Error::Config(format!(
+            "Failed to decrypt keystore {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let dir = std::env::temp_dir();
+        let mut rng = rand::thread_rng();
+        let (signer, file) =
+            PrivateKeySigner::new_keystore(&dir, &mut rng, "hunter2", None).unwrap();
+        let path = dir.join(file);
+
+        let loaded = load_keystore(&path, "hunter2").unwrap();
+        assert_eq!(loaded.address(), signer.address());
+        assert!(load_keystore(&path, "wrong").is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
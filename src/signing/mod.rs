@@ -1,5 +1,10 @@
 mod eip712;
+#[cfg(feature = "keystore")]
+mod keystore;
 mod signer;
 
 pub use eip712::{sign_clob_auth_message, sign_order_message, ClobAuth, Order};
 pub use signer::EthSigner;
+
+#[cfg(feature = "keystore")]
+pub use keystore::load_keystore;
@@ -0,0 +1,104 @@
+//! Recorded responses of the major CLOB and Gamma endpoints.
+//!
+//! Every fixture refers to the same market: [`CONDITION_ID`] with the YES
+//! outcome [`TOKEN_ID`]. Each one is checked against the crate's response
+//! types, so a fixture that stops parsing flags a breaking type change.
+
+/// Condition ID of the market the fixtures describe
+pub const CONDITION_ID: &str = "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1";
+
+/// Token ID of the YES outcome of the fixture market
+pub const TOKEN_ID: &str =
+    "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+
+/// `GET /book`: [`OrderBookSummary`](crate::types::OrderBookSummary) with bids 0.48-0.50 and asks 0.52-0.54
+pub const ORDER_BOOK: &str = include_str!("fixtures/clob_order_book.json");
+
+/// `GET /midpoint`: [`MidpointResponse`](crate::types::MidpointResponse) of 0.51
+pub const MIDPOINT: &str = include_str!("fixtures/clob_midpoint.json");
+
+/// `GET /price`: [`PriceResponse`](crate::types::PriceResponse) of 0.52
+pub const PRICE: &str = include_str!("fixtures/clob_price.json");
+
+/// `GET /spread`: [`SpreadResponse`](crate::types::SpreadResponse) of 0.02
+pub const SPREAD: &str = include_str!("fixtures/clob_spread.json");
+
+/// `GET /tick-size`: [`TickSizeResponse`](crate::types::TickSizeResponse) of 0.01
+pub const TICK_SIZE: &str = include_str!("fixtures/clob_tick_size.json");
+
+/// `GET /neg-risk`: [`NegRiskResponse`](crate::types::NegRiskResponse) for a standard market
+pub const NEG_RISK: &str = include_str!("fixtures/clob_neg_risk.json");
+
+/// `POST /order`: accepted [`PostOrderResponse`](crate::types::PostOrderResponse)
+pub const POST_ORDER: &str = include_str!("fixtures/clob_post_order.json");
+
+/// `DELETE /order`: [`CancelOrdersResponse`](crate::types::CancelOrdersResponse) for the posted order
+pub const CANCEL: &str = include_str!("fixtures/clob_cancel.json");
+
+/// `GET /data/orders`: [`OpenOrdersResponse`](crate::types::OpenOrdersResponse) holding one resting bid
+pub const OPEN_ORDERS: &str = include_str!("fixtures/clob_open_orders.json");
+
+/// `GET /auth/derive-api-key` and `POST /auth/api-key`: [`ApiCreds`](crate::types::ApiCreds)
+pub const API_KEY: &str = include_str!("fixtures/clob_api_key.json");
+
+/// Gamma `GET /markets`: a single [`GammaMarket`](crate::types::GammaMarket)
+pub const GAMMA_MARKETS: &str = include_str!("fixtures/gamma_markets.json");
+
+/// Gamma `GET /events`: a single [`GammaEvent`](crate::types::GammaEvent) holding the fixture market
+pub const GAMMA_EVENTS: &str = include_str!("fixtures/gamma_events.json");
+
+/// CLOB routes served by [`MockHttpServer::with_clob_fixtures`](super::MockHttpServer::with_clob_fixtures)
+pub(super) const CLOB_ROUTES: &[(&str, &str, &str)] = &[
+    ("GET", "/book", ORDER_BOOK),
+    ("GET", "/midpoint", MIDPOINT),
+    ("GET", "/price", PRICE),
+    ("GET", "/spread", SPREAD),
+    ("GET", "/tick-size", TICK_SIZE),
+    ("GET", "/neg-risk", NEG_RISK),
+    ("POST", "/order", POST_ORDER),
+    ("DELETE", "/order", CANCEL),
+    ("GET", "/data/orders", OPEN_ORDERS),
+    ("GET", "/auth/derive-api-key", API_KEY),
+    ("POST", "/auth/api-key", API_KEY),
+];
+
+/// Gamma routes served by [`MockHttpServer::with_gamma_fixtures`](super::MockHttpServer::with_gamma_fixtures)
+pub(super) const GAMMA_ROUTES: &[(&str, &str, &str)] = &[
+    ("GET", "/markets", GAMMA_MARKETS),
+    ("GET", "/events", GAMMA_EVENTS),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_fixtures_parse() {
+        let book: OrderBookSummary = serde_json::from_str(ORDER_BOOK).unwrap();
+        assert_eq!(book.asset_id.as_str(), TOKEN_ID);
+        assert_eq!(book.bids.len(), 3);
+
+        let mid: MidpointResponse = serde_json::from_str(MIDPOINT).unwrap();
+        assert_eq!(mid.mid, dec!(0.51));
+        serde_json::from_str::<PriceResponse>(PRICE).unwrap();
+        serde_json::from_str::<SpreadResponse>(SPREAD).unwrap();
+        let tick: TickSizeResponse = serde_json::from_str(TICK_SIZE).unwrap();
+        assert_eq!(tick.minimum_tick_size, dec!(0.01));
+        serde_json::from_str::<NegRiskResponse>(NEG_RISK).unwrap();
+
+        let posted: PostOrderResponse = serde_json::from_str(POST_ORDER).unwrap();
+        let canceled: CancelOrdersResponse = serde_json::from_str(CANCEL).unwrap();
+        assert_eq!(canceled.canceled, [posted.order_id]);
+
+        let orders: OpenOrdersResponse = serde_json::from_str(OPEN_ORDERS).unwrap();
+        assert_eq!(orders.data[0].expiration, None);
+        serde_json::from_str::<ApiCreds>(API_KEY).unwrap();
+
+        let markets: Vec<GammaMarket> = serde_json::from_str(GAMMA_MARKETS).unwrap();
+        assert_eq!(markets[0].condition_id, CONDITION_ID);
+        let events: Vec<GammaEvent> = serde_json::from_str(GAMMA_EVENTS).unwrap();
+        assert_eq!(events[0].markets.len(), 1);
+    }
+}
@@ -0,0 +1,501 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use super::fixtures::{CLOB_ROUTES, GAMMA_ROUTES};
+use crate::client::TradingClient;
+use crate::error::{Error, Result};
+use crate::orders::OrderBuilder;
+use crate::types::ApiCreds;
+use crate::utils::build_hmac_signature_raw;
+use crate::PrivateKeySigner;
+
+/// A request received by [`MockHttpServer`]
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `"POST"`
+    pub method: String,
+    /// Path without the query string, e.g. `"/order"`
+    pub path: String,
+    /// Query string without the leading `?`, if any
+    pub query: Option<String>,
+    /// Headers with lowercased names
+    pub headers: HashMap<String, String>,
+    /// Raw request body; empty if none was sent
+    pub body: String,
+}
+
+impl RecordedRequest {
+    /// Get a header by case-insensitive name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Parse the body as JSON
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_str(&self.body)?)
+    }
+
+    /// Check that the L2 headers carry a valid HMAC signature for `creds`
+    ///
+    /// Recomputes the signature over the timestamp, method, path (without
+    /// query parameters, as the CLOB signs it) and body, and checks the API
+    /// key and passphrase headers.
+    pub fn verify_l2_signature(&self, creds: &ApiCreds) -> Result<()> {
+        self.verify_hmac(
+            creds,
+            "POLY_API_KEY",
+            "POLY_PASSPHRASE",
+            "POLY_SIGNATURE",
+            "POLY_TIMESTAMP",
+        )
+    }
+
+    /// Check that the relayer builder headers carry a valid HMAC signature for `creds`
+    pub fn verify_builder_signature(&self, creds: &ApiCreds) -> Result<()> {
+        self.verify_hmac(
+            creds,
+            "POLY_BUILDER_API_KEY",
+            "POLY_BUILDER_PASSPHRASE",
+            "POLY_BUILDER_SIGNATURE",
+            "POLY_BUILDER_TIMESTAMP",
+        )
+    }
+
+    fn verify_hmac(
+        &self,
+        creds: &ApiCreds,
+        key_header: &str,
+        pass_header: &str,
+        sig_header: &str,
+        ts_header: &str,
+    ) -> Result<()> {
+        let require = |name: &str| {
+            self.header(name)
+                .ok_or_else(|| Error::Signing(format!("Missing {} header", name)))
+        };
+
+        if require(key_header)? != creds.api_key {
+            return Err(Error::Signing(format!("{} does not match", key_header)));
+        }
+        if require(pass_header)? != creds.passphrase {
+            return Err(Error::Signing(format!("{} does not match", pass_header)));
+        }
+
+        let timestamp = require(ts_header)?
+            .parse()
+            .map_err(|e| Error::Signing(format!("Invalid {}: {}", ts_header, e)))?;
+        let body = (!self.body.is_empty()).then_some(self.body.as_str());
+        let expected =
+            build_hmac_signature_raw(&creds.secret, timestamp, &self.method, &self.path, body)?;
+
+        if require(sig_header)? != expected {
+            return Err(Error::Signing(format!(
+                "{} does not match {} {}",
+                sig_header, self.method, self.path
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// A canned response for one method and path
+struct Route {
+    method: String,
+    path: String,
+    /// JSON-RPC method the request body must call, if any
+    rpc_method: Option<String>,
+    /// How many more requests the route answers; `None` for any number
+    remaining: Option<usize>,
+    status: u16,
+    body: String,
+}
+
+impl Route {
+    fn matches(&self, method: &str, path: &str, target: &str, rpc_method: Option<&str>) -> bool {
+        self.method == method
+            && (self.path == path || self.path == target)
+            && self
+                .rpc_method
+                .as_deref()
+                .is_none_or(|m| Some(m) == rpc_method)
+            && self.remaining != Some(0)
+    }
+}
+
+/// Shared state between the server handle and its connection tasks
+#[derive(Default)]
+struct ServerState {
+    routes: Mutex<Vec<Route>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+    connections: AtomicUsize,
+}
+
+impl ServerState {
+    /// Find the most recently registered route for a request
+    ///
+    /// Routes registered with a query string only match that exact query;
+    /// routes without one match any query.
+    /// JSON-RPC routes only match requests calling their method; routes
+    /// registered with a use count stop matching once it is used up.
+    fn respond(&self, method: &str, path: &str, target: &str, body: &str) -> (u16, String) {
+        let rpc_method = serde_json::from_str::<serde_json::Value>(body)
+            .ok()
+            .and_then(|body| body.get("method")?.as_str().map(str::to_string));
+        self.routes
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .rev()
+            .find(|route| route.matches(method, path, target, rpc_method.as_deref()))
+            .map(|route| {
+                if let Some(remaining) = route.remaining.as_mut() {
+                    *remaining -= 1;
+                }
+                (route.status, route.body.clone())
+            })
+            .unwrap_or_else(|| {
+                let body =
+                    serde_json::json!({ "error": format!("no mock for {} {}", method, target) });
+                (404, body.to_string())
+            })
+    }
+}
+
+/// A local HTTP server that answers with canned JSON responses
+///
+/// Point any client at [`MockHttpServer::url`] to unit-test code built on it
+/// without live credentials. Every request is recorded so tests can assert
+/// on what was sent, including the signed authentication headers.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::test_util::{fixtures, MockHttpServer};
+/// use polymarket_rs::{ClobClient, TokenId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let server = MockHttpServer::with_clob_fixtures().await?;
+/// let client = ClobClient::new(server.url());
+///
+/// let book = client.get_order_book(&TokenId::new(fixtures::TOKEN_ID)).await?;
+/// assert_eq!(book.bids.len(), 3);
+/// assert_eq!(server.requests()[0].query.as_deref(), Some(&*format!("token_id={}", fixtures::TOKEN_ID)));
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockHttpServer {
+    url: String,
+    state: Arc<ServerState>,
+    task: JoinHandle<()>,
+}
+
+impl MockHttpServer {
+    /// Start a server on a random local port with no routes
+    ///
+    /// Requests without a matching route get a 404 response.
+    pub async fn start() -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| Error::Config(format!("Failed to bind mock server: {}", e)))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| Error::Config(e.to_string()))?;
+
+        let state = Arc::new(ServerState::default());
+        let task_state = Arc::clone(&state);
+        let task = tokio::spawn(async move {
+            while let Ok((tcp, _)) = listener.accept().await {
+                task_state.connections.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(serve_connection(tcp, Arc::clone(&task_state)));
+            }
+        });
+
+        Ok(Self {
+            url: format!("http://{}", addr),
+            state,
+            task,
+        })
+    }
+
+    /// Start a server serving the recorded CLOB responses in [`fixtures`](super::fixtures)
+    pub async fn with_clob_fixtures() -> Result<Self> {
+        let server = Self::start().await?;
+        for (method, path, body) in CLOB_ROUTES {
+            server.mock(method, path, 200, *body);
+        }
+        Ok(server)
+    }
+
+    /// Start a server serving the recorded Gamma responses in [`fixtures`](super::fixtures)
+    pub async fn with_gamma_fixtures() -> Result<Self> {
+        let server = Self::start().await?;
+        for (method, path, body) in GAMMA_ROUTES {
+            server.mock(method, path, 200, *body);
+        }
+        Ok(server)
+    }
+
+    /// The base URL to pass to a client constructor
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// A [`TradingClient`] pointed at this server, signing with a random
+    /// key and placeholder API credentials
+    pub fn trading_client(&self) -> TradingClient {
+        self.trading_client_with(
+            PrivateKeySigner::random(),
+            ApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into()),
+        )
+    }
+
+    /// A [`TradingClient`] pointed at this server with the given signer and
+    /// API credentials
+    pub fn trading_client_with(&self, signer: PrivateKeySigner, creds: ApiCreds) -> TradingClient {
+        TradingClient::new(
+            self.url(),
+            signer.clone(),
+            137,
+            creds,
+            OrderBuilder::new(signer, None, None),
+        )
+    }
+
+    /// Respond to `method path` with `status` and a raw JSON body
+    ///
+    /// Replaces any earlier response for the same route. Include a query
+    /// string in `path` to match only that exact query.
+    pub fn mock(&self, method: &str, path: &str, status: u16, body: impl Into<String>) {
+        self.state.routes.lock().unwrap().push(Route {
+            method: method.to_ascii_uppercase(),
+            path: path.to_string(),
+            rpc_method: None,
+            remaining: None,
+            status,
+            body: body.into(),
+        });
+    }
+
+    /// Respond to the next `method path` request only, then fall back to
+    /// earlier mocks
+    pub fn mock_once(&self, method: &str, path: &str, status: u16, body: impl Into<String>) {
+        self.state.routes.lock().unwrap().push(Route {
+            method: method.to_ascii_uppercase(),
+            path: path.to_string(),
+            rpc_method: None,
+            remaining: Some(1),
+            status,
+            body: body.into(),
+        });
+    }
+
+    /// Answer JSON-RPC calls of `method` (posted to `/`) with `result`
+    ///
+    /// For testing [`OnchainClient`](crate::onchain::OnchainClient) against
+    /// a mock node.
+    pub fn mock_rpc(&self, method: &str, result: &impl Serialize) {
+        let body = serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": result });
+        self.push_rpc(method, None, body);
+    }
+
+    /// Answer the next JSON-RPC call of `method` with `result`, then fall
+    /// back to earlier mocks
+    pub fn mock_rpc_once(&self, method: &str, result: &impl Serialize) {
+        let body = serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": result });
+        self.push_rpc(method, Some(1), body);
+    }
+
+    /// Answer JSON-RPC calls of `method` with an error
+    pub fn mock_rpc_error(&self, method: &str, code: i64, message: &str) {
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": { "code": code, "message": message },
+        });
+        self.push_rpc(method, None, body);
+    }
+
+    fn push_rpc(&self, method: &str, remaining: Option<usize>, body: serde_json::Value) {
+        self.state.routes.lock().unwrap().push(Route {
+            method: "POST".to_string(),
+            path: "/".to_string(),
+            rpc_method: Some(method.to_string()),
+            remaining,
+            status: 200,
+            body: body.to_string(),
+        });
+    }
+
+    /// Respond to `method path` with 200 and `body` serialized as JSON
+    pub fn mock_json(&self, method: &str, path: &str, body: &impl Serialize) {
+        let body = serde_json::to_string(body).expect("mock body must serialize");
+        self.mock(method, path, 200, body);
+    }
+
+    /// Requests received so far, in arrival order
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+
+    /// Number of TCP connections accepted so far
+    ///
+    /// Useful for checking that clients reuse pooled connections.
+    pub fn connection_count(&self) -> usize {
+        self.state.connections.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for MockHttpServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Serve HTTP/1.1 requests on one keep-alive connection
+async fn serve_connection(mut tcp: TcpStream, state: Arc<ServerState>) {
+    let (read, mut write) = tcp.split();
+    let mut reader = BufReader::new(read);
+
+    loop {
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line).await.unwrap_or(0) == 0 {
+            return;
+        }
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+            return;
+        };
+        let (method, target) = (method.to_string(), target.to_string());
+
+        let mut headers = HashMap::new();
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).await.unwrap_or(0) == 0 {
+                return;
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+            }
+        }
+
+        let content_length = headers
+            .get("content-length")
+            .and_then(|len| len.parse().ok())
+            .unwrap_or(0);
+        let mut body = vec![0; content_length];
+        if reader.read_exact(&mut body).await.is_err() {
+            return;
+        }
+
+        let (path, query) = match target.split_once('?') {
+            Some((path, query)) => (path.to_string(), Some(query.to_string())),
+            None => (target.clone(), None),
+        };
+        let body = String::from_utf8_lossy(&body).into_owned();
+        let (status, response) = state.respond(&method, &path, &target, &body);
+
+        state.requests.lock().unwrap().push(RecordedRequest {
+            method,
+            path,
+            query,
+            headers,
+            body,
+        });
+
+        let head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n",
+            status,
+            if status < 400 { "OK" } else { "Error" },
+            response.len()
+        );
+        if write.write_all(head.as_bytes()).await.is_err()
+            || write.write_all(response.as_bytes()).await.is_err()
+        {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fixtures;
+    use super::*;
+    use crate::client::TradingClient;
+    use crate::orders::OrderBuilder;
+    use crate::types::{CreateOrderOptions, OrderArgs, OrderId, OrderType, Side, SignatureType};
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_signed_trading_requests() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        let creds: ApiCreds = serde_json::from_str(fixtures::API_KEY).unwrap();
+        let signer = PrivateKeySigner::random();
+        let client = TradingClient::new(
+            server.url(),
+            signer.clone(),
+            137,
+            creds.clone(),
+            OrderBuilder::new(signer, Some(SignatureType::Eoa), None),
+        );
+
+        let order_id =
+            OrderId::new("0x3c1a1b2e5a0d9d7f2b8f4f1e0c6a2b9d8e7f6a5b4c3d2e1f0a9b8c7d6e5f4a3b");
+        let options = CreateOrderOptions::default()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let posted = client
+            .create_and_post_order(
+                &OrderArgs::new(fixtures::TOKEN_ID, dec!(0.5), dec!(10), Side::Buy),
+                None,
+                None,
+                options,
+                OrderType::Gtc,
+            )
+            .await
+            .unwrap();
+        assert_eq!(posted.order_id, order_id);
+        client.cancel(&order_id).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        for request in &requests {
+            request.verify_l2_signature(&creds).unwrap();
+        }
+        let body: serde_json::Value = requests[0].json().unwrap();
+        assert_eq!(body["orderType"], "GTC");
+
+        let other = ApiCreds::new(
+            creds.api_key.clone(),
+            creds.passphrase.clone(),
+            creds.passphrase,
+        );
+        assert!(requests[1].verify_l2_signature(&other).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unmatched_route_is_404() {
+        let server = MockHttpServer::start().await.unwrap();
+        server.mock("GET", "/midpoint?token_id=1", 500, "{}");
+        let client = crate::ClobClient::new(server.url());
+
+        let err = client
+            .get_midpoint(&crate::TokenId::new("2"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Api { status: 404, .. }));
+    }
+}
@@ -0,0 +1,22 @@
+//! Test support for code built on this crate.
+//!
+//! Enabled with the `test-util` feature. Provides local stand-ins for the
+//! Polymarket services so stream handling and trading logic can be tested
+//! deterministically, without network access or live credentials:
+//! - [`MockWsServer`]: A websocket server that accepts market or user
+//!   subscriptions and replays scripted frames
+//! - [`MockHttpServer`]: An HTTP server that answers with canned responses,
+//!   such as the recorded ones in [`fixtures`], and records every request
+//!   so signed headers can be checked
+//! - [`ScriptedStream`]: A programmed sequence of events, errors and delays
+//!   with the same type as a live subscription, and [`ScriptedConnector`] to
+//!   script whole reconnect sequences
+
+pub mod fixtures;
+mod http;
+mod stream;
+mod ws;
+
+pub use http::{MockHttpServer, RecordedRequest};
+pub use stream::{BoxedEventStream, ScriptedConnector, ScriptedStream};
+pub use ws::{MockWsServer, ScriptedFrame};
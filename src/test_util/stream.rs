@@ -0,0 +1,240 @@
+use futures_util::stream::{self, Stream};
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::future::{ready, Ready};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+use crate::types::WsEvent;
+
+/// The stream type returned by the websocket clients' `subscribe()`
+pub type BoxedEventStream<T = WsEvent> = Pin<Box<dyn Stream<Item = Result<T>> + Send>>;
+
+/// A single step of a [`ScriptedStream`]
+enum Step<T> {
+    Item(Result<T>),
+    Delay(Duration),
+}
+
+/// Builder for a stream that yields a programmed sequence of events, errors and delays
+///
+/// The built stream has the same type as
+/// [`MarketWsClient::subscribe`](crate::websocket::MarketWsClient::subscribe),
+/// so code consuming a subscription can be tested against edge cases such as
+/// sequence gaps, out-of-order updates and dropped connections without a
+/// server. The stream ends after the last step, like a closed connection.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::test_util::{BoxedEventStream, ScriptedStream};
+/// use polymarket_rs::{Error, StreamExt};
+/// use std::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut stream: BoxedEventStream = ScriptedStream::new()
+///     .json(serde_json::json!({
+///         "event_type": "tick_size_change",
+///         "asset_id": "123",
+///         "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+///         "old_tick_size": "0.01",
+///         "new_tick_size": "0.001",
+///         "timestamp": "1700000000000"
+///     }))
+///     .delay(Duration::from_millis(5))
+///     .error(Error::ConnectionClosed)
+///     .build();
+///
+/// assert!(stream.next().await.unwrap().is_ok());
+/// assert!(matches!(stream.next().await, Some(Err(Error::ConnectionClosed))));
+/// assert!(stream.next().await.is_none());
+/// # }
+/// ```
+pub struct ScriptedStream<T = WsEvent> {
+    steps: VecDeque<Step<T>>,
+}
+
+impl<T: Send + 'static> ScriptedStream<T> {
+    /// Create an empty script
+    pub fn new() -> Self {
+        Self {
+            steps: VecDeque::new(),
+        }
+    }
+
+    /// Yield an event
+    pub fn event(mut self, event: T) -> Self {
+        self.steps.push_back(Step::Item(Ok(event)));
+        self
+    }
+
+    /// Yield several events back to back
+    pub fn events(mut self, events: impl IntoIterator<Item = T>) -> Self {
+        self.steps
+            .extend(events.into_iter().map(|event| Step::Item(Ok(event))));
+        self
+    }
+
+    /// Yield an event given as server JSON
+    ///
+    /// Panics if the JSON does not deserialize into `T`.
+    pub fn json(self, value: serde_json::Value) -> Self
+    where
+        T: DeserializeOwned,
+    {
+        let event = serde_json::from_value(value).expect("scripted JSON must deserialize");
+        self.event(event)
+    }
+
+    /// Yield an error
+    pub fn error(mut self, error: Error) -> Self {
+        self.steps.push_back(Step::Item(Err(error)));
+        self
+    }
+
+    /// Wait before yielding the next step
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.steps.push_back(Step::Delay(delay));
+        self
+    }
+
+    /// Build the stream
+    pub fn build(self) -> BoxedEventStream<T> {
+        Box::pin(stream::unfold(self.steps, |mut steps| async move {
+            loop {
+                match steps.pop_front()? {
+                    Step::Item(item) => return Some((item, steps)),
+                    Step::Delay(delay) => tokio::time::sleep(delay).await,
+                }
+            }
+        }))
+    }
+}
+
+impl<T: Send + 'static> Default for ScriptedStream<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Scripted connections for [`ReconnectingStream`](crate::websocket::ReconnectingStream)
+///
+/// Each call to [`ScriptedConnector::connect`] hands out the next scripted
+/// connection, or fails with the scripted error, so reconnect storms and
+/// failed reconnection attempts can be replayed deterministically. Once the
+/// script is exhausted every attempt fails with [`Error::ConnectionClosed`].
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::test_util::{ScriptedConnector, ScriptedStream};
+/// use polymarket_rs::websocket::{ReconnectConfig, ReconnectingStream};
+/// use polymarket_rs::websocket::WsEvent;
+/// use polymarket_rs::Error;
+///
+/// let connector = ScriptedConnector::<WsEvent>::new()
+///     .connection(ScriptedStream::new())
+///     .failed_connection(Error::WebSocket("refused".to_string()))
+///     .connection(ScriptedStream::new());
+///
+/// let stream = ReconnectingStream::new(ReconnectConfig::default(), move || connector.connect());
+/// # let _ = stream;
+/// ```
+pub struct ScriptedConnector<T = WsEvent> {
+    connections: Arc<Mutex<VecDeque<Result<ScriptedStream<T>>>>>,
+    attempts: Arc<AtomicUsize>,
+}
+
+impl<T: Send + 'static> ScriptedConnector<T> {
+    /// Create a connector with no scripted connections
+    pub fn new() -> Self {
+        Self {
+            connections: Arc::new(Mutex::new(VecDeque::new())),
+            attempts: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Add a connection that succeeds and replays `script`
+    pub fn connection(self, script: ScriptedStream<T>) -> Self {
+        self.connections.lock().unwrap().push_back(Ok(script));
+        self
+    }
+
+    /// Add a connection attempt that fails with `error`
+    pub fn failed_connection(self, error: Error) -> Self {
+        self.connections.lock().unwrap().push_back(Err(error));
+        self
+    }
+
+    /// Connect to the next scripted connection
+    pub fn connect(&self) -> Ready<Result<BoxedEventStream<T>>> {
+        self.attempts.fetch_add(1, Ordering::SeqCst);
+        let next = self
+            .connections
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or(Err(Error::ConnectionClosed));
+        ready(next.map(ScriptedStream::build))
+    }
+
+    /// Number of connection attempts so far
+    pub fn attempts(&self) -> usize {
+        self.attempts.load(Ordering::SeqCst)
+    }
+}
+
+impl<T: Send + 'static> Default for ScriptedConnector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ScriptedConnector<T> {
+    fn clone(&self) -> Self {
+        Self {
+            connections: Arc::clone(&self.connections),
+            attempts: Arc::clone(&self.attempts),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::websocket::{ReconnectConfig, ReconnectingStream};
+    use futures_util::StreamExt;
+
+    #[tokio::test]
+    async fn test_reconnect_storm() {
+        let connector = ScriptedConnector::new()
+            .connection(ScriptedStream::new().events([1, 2]))
+            .failed_connection(Error::WebSocket("refused".to_string()))
+            .failed_connection(Error::WebSocket("refused".to_string()))
+            .connection(
+                ScriptedStream::new()
+                    .delay(Duration::from_millis(5))
+                    .event(3)
+                    .error(Error::ConnectionClosed),
+            )
+            .connection(ScriptedStream::new().event(4));
+
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            multiplier: 2.0,
+            max_attempts: Some(5),
+        };
+        let attempts = connector.clone();
+        let stream = ReconnectingStream::new(config, move || connector.connect());
+
+        let items: Vec<_> = stream.take(4).collect().await;
+        let items: Vec<u32> = items.into_iter().map(|item| item.unwrap()).collect();
+        assert_eq!(items, [1, 2, 3, 4]);
+        assert_eq!(attempts.attempts(), 5);
+    }
+}
@@ -0,0 +1,280 @@
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::{accept_async, tungstenite::Message};
+
+use crate::error::{Error, Result};
+
+/// A single step of a scripted websocket connection
+#[derive(Debug, Clone)]
+pub enum ScriptedFrame {
+    /// Send a text frame
+    Text(String),
+    /// Wait before sending the next frame
+    Delay(Duration),
+    /// Send a close frame and end the connection
+    Close,
+    /// Drop the connection without a close handshake, as a network failure would
+    Disconnect,
+}
+
+impl ScriptedFrame {
+    /// Send a value serialized as JSON, e.g. a [`WsEvent`](crate::types::WsEvent)
+    /// or a `serde_json::json!` literal
+    pub fn json(value: &impl Serialize) -> Self {
+        Self::Text(serde_json::to_string(value).expect("scripted frame must serialize"))
+    }
+}
+
+/// Shared state between the server handle and its connection tasks
+#[derive(Default)]
+struct ServerState {
+    scripts: Mutex<VecDeque<Vec<ScriptedFrame>>>,
+    received: Mutex<Vec<serde_json::Value>>,
+    connections: AtomicUsize,
+}
+
+/// A local websocket server that speaks the Polymarket subscription protocol
+///
+/// Each accepted connection waits for the client's first message (the
+/// market subscription or user authentication), records it, then replays
+/// the next script in order. Once its script is exhausted a connection stays
+/// open, answering `PING` with `PONG`, until the client disconnects.
+/// Connections beyond the last script get an empty one.
+///
+/// Scripts ending in [`ScriptedFrame::Close`] or [`ScriptedFrame::Disconnect`]
+/// followed by another script exercise reconnection deterministically.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::test_util::{MockWsServer, ScriptedFrame};
+/// use polymarket_rs::websocket::MarketWsClient;
+/// use polymarket_rs::{StreamExt, TokenId};
+/// use serde_json::json;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let server = MockWsServer::start(vec![vec![ScriptedFrame::json(&json!({
+///     "event_type": "last_trade_price",
+///     "asset_id": "123",
+///     "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+///     "price": "0.5",
+///     "side": "BUY",
+///     "size": "10",
+///     "fee_rate_bps": "0",
+///     "timestamp": "1700000000000",
+///     "transaction_hash": "0x"
+/// }))]])
+/// .await?;
+///
+/// let client = MarketWsClient::with_url(server.url());
+/// let mut stream = client.subscribe(vec![TokenId::new("123")]).await?;
+/// let event = stream.next().await.unwrap()?;
+/// # let _ = event;
+///
+/// assert_eq!(server.received()[0]["assets_ids"][0], "123");
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockWsServer {
+    url: String,
+    state: Arc<ServerState>,
+    task: JoinHandle<()>,
+}
+
+impl MockWsServer {
+    /// Start a server on a random local port with one script per connection
+    pub async fn start(scripts: Vec<Vec<ScriptedFrame>>) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| Error::WebSocket(format!("Failed to bind mock server: {}", e)))?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| Error::WebSocket(e.to_string()))?;
+
+        let state = Arc::new(ServerState {
+            scripts: Mutex::new(scripts.into()),
+            ..Default::default()
+        });
+
+        let task_state = Arc::clone(&state);
+        let task = tokio::spawn(async move {
+            while let Ok((tcp, _)) = listener.accept().await {
+                task_state.connections.fetch_add(1, Ordering::SeqCst);
+                let script = task_state
+                    .scripts
+                    .lock()
+                    .unwrap()
+                    .pop_front()
+                    .unwrap_or_default();
+                tokio::spawn(serve_connection(tcp, script, Arc::clone(&task_state)));
+            }
+        });
+
+        Ok(Self {
+            url: format!("ws://{}", addr),
+            state,
+            task,
+        })
+    }
+
+    /// The `ws://` URL to pass to [`MarketWsClient::with_url`](crate::websocket::MarketWsClient::with_url)
+    /// or [`UserWsClient::with_url`](crate::websocket::UserWsClient::with_url)
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Messages received from clients other than `PING`, in arrival order
+    ///
+    /// Messages that are not valid JSON are recorded as JSON strings.
+    pub fn received(&self) -> Vec<serde_json::Value> {
+        self.state.received.lock().unwrap().clone()
+    }
+
+    /// Number of connections accepted so far
+    pub fn connection_count(&self) -> usize {
+        self.state.connections.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for MockWsServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Handle a client message, replying to keep-alive pings
+///
+/// Returns false if the client went away.
+async fn handle_client_message<S>(write: &mut S, text: &str, state: &ServerState) -> bool
+where
+    S: SinkExt<Message> + Unpin,
+{
+    if text.trim().eq_ignore_ascii_case("ping") {
+        return write.send(Message::Text("PONG".to_string())).await.is_ok();
+    }
+
+    let value =
+        serde_json::from_str(text).unwrap_or_else(|_| serde_json::Value::String(text.to_string()));
+    state.received.lock().unwrap().push(value);
+    true
+}
+
+async fn serve_connection(tcp: TcpStream, script: Vec<ScriptedFrame>, state: Arc<ServerState>) {
+    let Ok(ws) = accept_async(tcp).await else {
+        return;
+    };
+    let (mut write, mut read) = ws.split();
+
+    // Wait for the subscription or authentication message before replaying
+    loop {
+        match read.next().await {
+            Some(Ok(Message::Text(text))) => {
+                let is_ping = text.trim().eq_ignore_ascii_case("ping");
+                if !handle_client_message(&mut write, &text, &state).await {
+                    return;
+                }
+                if !is_ping {
+                    break;
+                }
+            }
+            Some(Ok(_)) => continue,
+            _ => return,
+        }
+    }
+
+    for frame in script {
+        match frame {
+            ScriptedFrame::Text(text) => {
+                if write.send(Message::Text(text)).await.is_err() {
+                    return;
+                }
+            }
+            ScriptedFrame::Delay(delay) => tokio::time::sleep(delay).await,
+            ScriptedFrame::Close => {
+                let _ = write.send(Message::Close(None)).await;
+                return;
+            }
+            // Dropping both halves closes the TCP stream without a close frame
+            ScriptedFrame::Disconnect => return,
+        }
+    }
+
+    while let Some(Ok(msg)) = read.next().await {
+        if let Message::Text(text) = msg {
+            if !handle_client_message(&mut write, &text, &state).await {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TokenId, WsEvent};
+    use crate::websocket::{MarketWsClient, ReconnectConfig, ReconnectingStream};
+    use serde_json::json;
+
+    fn trade_frame(price: &str) -> ScriptedFrame {
+        ScriptedFrame::json(&json!({
+            "event_type": "last_trade_price",
+            "asset_id": "123",
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "price": price,
+            "side": "BUY",
+            "size": "10",
+            "fee_rate_bps": "0",
+            "timestamp": "1700000000000",
+            "transaction_hash": "0x"
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_after_close_and_disconnect() {
+        let server = MockWsServer::start(vec![
+            vec![trade_frame("0.1"), ScriptedFrame::Close],
+            vec![trade_frame("0.2"), ScriptedFrame::Disconnect],
+            vec![trade_frame("0.3")],
+        ])
+        .await
+        .unwrap();
+
+        let client = MarketWsClient::with_url(server.url());
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(50),
+            multiplier: 2.0,
+            max_attempts: Some(5),
+        };
+        let mut stream = ReconnectingStream::new(config, move || {
+            let client = client.clone();
+            async move { client.subscribe(vec![TokenId::new("123")]).await }
+        });
+
+        let mut prices = Vec::new();
+        while prices.len() < 3 {
+            let item = tokio::time::timeout(Duration::from_secs(5), stream.next())
+                .await
+                .expect("stream stalled")
+                .unwrap();
+            // An abrupt disconnect surfaces as an error before reconnecting
+            if let Ok(WsEvent::LastTradePrice(trade)) = item {
+                prices.push(trade.price.to_string());
+            }
+        }
+
+        assert_eq!(prices, ["0.1", "0.2", "0.3"]);
+        assert_eq!(server.connection_count(), 3);
+        assert!(server
+            .received()
+            .iter()
+            .all(|msg| msg["assets_ids"] == json!(["123"])));
+    }
+}
@@ -1,9 +1,11 @@
+use crate::error::{Error, Result};
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 /// API credentials for L2 authentication
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ApiCreds {
-    #[serde(rename = "apiKey")]
+    #[serde(rename = "apiKey", alias = "api_key")]
     pub api_key: String,
     pub secret: String,
     pub passphrase: String,
@@ -17,6 +19,44 @@ impl ApiCreds {
             passphrase,
         }
     }
+
+    /// Load API credentials from a JSON or TOML file
+    ///
+    /// The file holds `apiKey` (or `api_key`), `secret` and `passphrase`.
+    /// Files ending in `.toml` are parsed as TOML, which requires the `toml`
+    /// feature; anything else is parsed as JSON.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::ApiCreds;
+    /// let creds = ApiCreds::from_file("/etc/polymarket/creds.json")?;
+    /// # Ok::<(), polymarket_rs::Error>(())
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("Failed to read {}: {}", path.display(), e)))?;
+
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            Self::from_toml(&contents)
+        } else {
+            Ok(serde_json::from_str(&contents)?)
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    fn from_toml(contents: &str) -> Result<Self> {
+        toml::from_str(contents)
+            .map_err(|e| Error::Config(format!("Invalid credentials file: {}", e)))
+    }
+
+    #[cfg(not(feature = "toml"))]
+    fn from_toml(_contents: &str) -> Result<Self> {
+        Err(Error::Config(
+            "Loading TOML credentials requires the `toml` feature".to_string(),
+        ))
+    }
 }
 
 /// Response from API keys list endpoint
@@ -30,7 +70,7 @@ pub struct ApiKeysResponse {
 #[derive(Debug, Default, Clone)]
 pub struct BalanceAllowanceParams {
     pub asset_type: Option<super::AssetType>,
-    pub token_id: Option<String>,
+    pub token_id: Option<super::TokenId>,
     pub signature_type: Option<u8>,
 }
 
@@ -44,7 +84,7 @@ impl BalanceAllowanceParams {
         self
     }
 
-    pub fn token_id(mut self, token_id: impl Into<String>) -> Self {
+    pub fn token_id(mut self, token_id: impl Into<super::TokenId>) -> Self {
         self.token_id = Some(token_id.into());
         self
     }
@@ -66,7 +106,7 @@ impl BalanceAllowanceParams {
         }
 
         if let Some(ref token_id) = self.token_id {
-            params.push(("token_id", token_id.clone()));
+            params.push(("token_id", token_id.to_string()));
         }
 
         if let Some(sig_type) = self.signature_type {
@@ -76,3 +116,34 @@ impl BalanceAllowanceParams {
         params
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_creds_from_json_file() {
+        let path =
+            std::env::temp_dir().join(format!("polymarket-creds-{}.json", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"{"apiKey": "key", "secret": "secret", "passphrase": "pass"}"#,
+        )
+        .unwrap();
+        let creds = ApiCreds::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(creds.api_key, "key");
+        assert_eq!(creds.passphrase, "pass");
+        assert!(ApiCreds::from_file(&path).is_err());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_creds_from_toml() {
+        let creds =
+            ApiCreds::from_toml("api_key = \"key\"\nsecret = \"secret\"\npassphrase = \"pass\"\n")
+                .unwrap();
+        assert_eq!(creds.api_key, "key");
+    }
+}
@@ -13,9 +13,9 @@ pub enum AssetType {
 #[serde(rename_all = "UPPERCASE")]
 pub enum Side {
     #[default]
-    #[serde(rename = "BUY")]
+    #[serde(rename = "BUY", alias = "buy", alias = "Buy")]
     Buy,
-    #[serde(rename = "SELL")]
+    #[serde(rename = "SELL", alias = "sell", alias = "Sell")]
     Sell,
 }
 
@@ -46,24 +46,71 @@ impl Side {
     }
 }
 
+impl std::str::FromStr for Side {
+    type Err = crate::error::Error;
+
+    /// Parse a side in any casing ("BUY", "buy", "Buy")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("BUY") {
+            Ok(Side::Buy)
+        } else if s.eq_ignore_ascii_case("SELL") {
+            Ok(Side::Sell)
+        } else {
+            Err(crate::error::Error::InvalidParameter(format!(
+                "Invalid side: {}",
+                s
+            )))
+        }
+    }
+}
+
 /// Order type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum OrderType {
     /// Good till canceled
-    #[serde(rename = "GTC")]
+    #[serde(rename = "GTC", alias = "gtc", alias = "Gtc")]
     Gtc,
     /// Fill or kill (must be fully filled)
-    #[serde(rename = "FOK")]
+    #[serde(rename = "FOK", alias = "fok", alias = "Fok")]
     Fok,
     /// Fill and kill (can be partially filled)
-    #[serde(rename = "FAK")]
+    #[serde(rename = "FAK", alias = "fak", alias = "Fak")]
     Fak,
     /// Good till date
-    #[serde(rename = "GTD")]
+    #[serde(rename = "GTD", alias = "gtd", alias = "Gtd")]
     Gtd,
 }
 
+impl OrderType {
+    /// Convert order type to string ("GTC", "FOK", "FAK" or "GTD")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::Gtc => "GTC",
+            OrderType::Fok => "FOK",
+            OrderType::Fak => "FAK",
+            OrderType::Gtd => "GTD",
+        }
+    }
+}
+
+impl std::str::FromStr for OrderType {
+    type Err = crate::error::Error;
+
+    /// Parse an order type in any casing ("GTC", "gtc", "Gtc")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            OrderType::Gtc,
+            OrderType::Fok,
+            OrderType::Fak,
+            OrderType::Gtd,
+        ]
+        .into_iter()
+        .find(|order_type| s.eq_ignore_ascii_case(order_type.as_str()))
+        .ok_or_else(|| crate::error::Error::InvalidParameter(format!("Invalid order type: {}", s)))
+    }
+}
+
 /// Signature type for orders
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SignatureType {
@@ -136,3 +183,46 @@ pub enum ActivityType {
     Conversion,
     Redeem,
 }
+
+impl ActivityType {
+    /// Convert activity type to its API string (e.g. "TRADE", "REDEEM")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivityType::Trade => "TRADE",
+            ActivityType::Yield => "YIELD",
+            ActivityType::Reward => "REWARD",
+            ActivityType::Split => "SPLIT",
+            ActivityType::Merge => "MERGE",
+            ActivityType::Conversion => "CONVERSION",
+            ActivityType::Redeem => "REDEEM",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_side_accepts_any_casing() {
+        for raw in ["\"BUY\"", "\"buy\"", "\"Buy\""] {
+            assert_eq!(serde_json::from_str::<Side>(raw).unwrap(), Side::Buy);
+        }
+        assert_eq!("sell".parse::<Side>().unwrap(), Side::Sell);
+        assert!("hold".parse::<Side>().is_err());
+        assert_eq!(serde_json::to_string(&Side::Sell).unwrap(), "\"SELL\"");
+    }
+
+    #[test]
+    fn test_order_type_accepts_any_casing() {
+        for raw in ["\"GTC\"", "\"gtc\"", "\"Gtc\""] {
+            assert_eq!(
+                serde_json::from_str::<OrderType>(raw).unwrap(),
+                OrderType::Gtc
+            );
+        }
+        assert_eq!("fak".parse::<OrderType>().unwrap(), OrderType::Fak);
+        assert!("IOC".parse::<OrderType>().is_err());
+        assert_eq!(serde_json::to_string(&OrderType::Gtd).unwrap(), "\"GTD\"");
+    }
+}
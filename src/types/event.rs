@@ -0,0 +1,262 @@
+use super::{ConditionId, GammaEvent, GammaMarket, TokenId};
+use crate::error::{Error, Result};
+
+/// One outcome of a market and the token that pays out on it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutcomeToken {
+    /// Outcome name, e.g. "Yes"
+    pub outcome: String,
+    pub token_id: TokenId,
+}
+
+/// A market of an event with its outcome tokens
+#[derive(Debug, Clone)]
+pub struct EventMarket {
+    pub id: String,
+    pub question: String,
+    pub slug: String,
+    pub condition_id: ConditionId,
+    /// The market's label within the event, e.g. a candidate's name
+    pub group_item_title: Option<String>,
+    pub active: bool,
+    pub closed: bool,
+    /// Outcome tokens in the order the API lists them; exactly one pays out
+    pub outcomes: Vec<OutcomeToken>,
+}
+
+impl EventMarket {
+    /// Decode a Gamma market's JSON-encoded outcome and token lists
+    pub fn from_gamma(market: &GammaMarket) -> Result<Self> {
+        let names = json_list(&market.outcomes, "outcomes", &market.id)?;
+        let token_ids = json_list(&market.clob_token_ids, "clobTokenIds", &market.id)?;
+        if names.len() != token_ids.len() {
+            return Err(Error::InvalidParameter(format!(
+                "Market {} lists {} outcomes but {} tokens",
+                market.id,
+                names.len(),
+                token_ids.len()
+            )));
+        }
+        Ok(Self {
+            id: market.id.clone(),
+            question: market.question.clone(),
+            slug: market.slug.clone(),
+            condition_id: ConditionId::new(market.condition_id.clone()),
+            group_item_title: market.group_item_title.clone(),
+            active: market.active,
+            closed: market.closed,
+            outcomes: names
+                .into_iter()
+                .zip(token_ids)
+                .map(|(outcome, token_id)| OutcomeToken {
+                    outcome,
+                    token_id: TokenId::new(token_id),
+                })
+                .collect(),
+        })
+    }
+
+    /// Whether the market is open for trading
+    pub fn is_tradable(&self) -> bool {
+        self.active && !self.closed
+    }
+
+    /// Token of the outcome named `outcome`, ignoring case
+    pub fn token(&self, outcome: &str) -> Option<&TokenId> {
+        self.outcomes
+            .iter()
+            .find(|token| token.outcome.eq_ignore_ascii_case(outcome))
+            .map(|token| &token.token_id)
+    }
+
+    /// Token of the first outcome, "Yes" for binary markets
+    pub fn yes_token(&self) -> Option<&TokenId> {
+        self.outcomes.first().map(|token| &token.token_id)
+    }
+}
+
+/// Decode a Gamma field holding a JSON array of strings; absent means empty
+fn json_list(field: &Option<String>, name: &str, market_id: &str) -> Result<Vec<String>> {
+    match field.as_deref() {
+        None | Some("") => Ok(Vec::new()),
+        Some(json) => serde_json::from_str(json).map_err(|e| {
+            Error::InvalidParameter(format!("Market {} has invalid {}: {}", market_id, name, e))
+        }),
+    }
+}
+
+/// An event's markets and how their tokens relate
+///
+/// Each market's outcomes are mutually exclusive. In a neg-risk event (an
+/// election with one market per candidate, say) the markets' "Yes" outcomes
+/// are mutually exclusive too: exactly one candidate wins, and a "No" share
+/// of one market converts into "Yes" shares of all the others.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::client::GammaClient;
+/// use polymarket_rs::websocket::MarketWsClient;
+///
+/// # async fn example() -> polymarket_rs::Result<()> {
+/// let gamma = GammaClient::new("https://gamma-api.polymarket.com");
+/// let event = gamma.get_event_markets("63806").await?;
+/// for market in &event.markets {
+///     println!("{:?}: {:?}", market.group_item_title, market.yes_token());
+/// }
+///
+/// // Every order book of the event on one connection
+/// let stream = MarketWsClient::new().subscribe_event(&event).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EventMarkets {
+    pub event_id: String,
+    pub title: String,
+    pub slug: String,
+    /// Whether the markets form a neg-risk group
+    pub neg_risk: bool,
+    /// The neg-risk market ID shared by the group's markets
+    pub neg_risk_market_id: Option<String>,
+    pub markets: Vec<EventMarket>,
+}
+
+impl EventMarkets {
+    /// Decode an event and its nested markets
+    pub fn from_event(event: &GammaEvent) -> Result<Self> {
+        let markets = event
+            .markets
+            .iter()
+            .map(EventMarket::from_gamma)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            event_id: event.id.clone(),
+            title: event.title.clone(),
+            slug: event.slug.clone(),
+            neg_risk: event.neg_risk || event.markets.iter().any(|m| m.neg_risk == Some(true)),
+            neg_risk_market_id: event
+                .markets
+                .iter()
+                .find_map(|market| market.neg_risk_market_id.clone()),
+            markets,
+        })
+    }
+
+    /// Markets open for trading
+    pub fn tradable_markets(&self) -> impl Iterator<Item = &EventMarket> {
+        self.markets.iter().filter(|market| market.is_tradable())
+    }
+
+    /// Every outcome token of the tradable markets, for subscribing to their books
+    pub fn token_ids(&self) -> Vec<TokenId> {
+        self.tradable_markets()
+            .flat_map(|market| market.outcomes.iter().map(|token| token.token_id.clone()))
+            .collect()
+    }
+
+    /// The market trading `token_id`
+    pub fn market_for_token(&self, token_id: &TokenId) -> Option<&EventMarket> {
+        self.markets
+            .iter()
+            .find(|market| market.outcomes.iter().any(|t| &t.token_id == token_id))
+    }
+
+    /// Sets of tokens of which exactly one pays out
+    ///
+    /// One set per market with its outcome tokens, plus, for a neg-risk
+    /// event, the "Yes" tokens of every market. Closed markets are included
+    /// so the sets stay complete.
+    pub fn exclusive_sets(&self) -> Vec<Vec<TokenId>> {
+        let mut sets: Vec<Vec<TokenId>> = self
+            .markets
+            .iter()
+            .map(|market| market.outcomes.iter().map(|t| t.token_id.clone()).collect())
+            .collect();
+        if self.neg_risk {
+            sets.push(
+                self.markets
+                    .iter()
+                    .filter_map(|market| market.yes_token().cloned())
+                    .collect(),
+            );
+        }
+        sets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn candidate(id: &str, name: &str, closed: bool) -> serde_json::Value {
+        json!({
+            "id": id,
+            "question": format!("Will {} win?", name),
+            "description": "",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "clobTokenIds": format!("[\"{}1\", \"{}2\"]", id, id),
+            "conditionId": format!("0x{}", id),
+            "active": true,
+            "closed": closed,
+            "slug": name.to_lowercase(),
+            "groupItemTitle": name,
+            "negRisk": true,
+            "negRiskMarketID": "0xneg"
+        })
+    }
+
+    #[test]
+    fn test_neg_risk_event_relationships() {
+        let event: GammaEvent = serde_json::from_value(json!({
+            "id": "1",
+            "ticker": "election",
+            "slug": "election",
+            "title": "Election winner",
+            "negRisk": true,
+            "markets": [
+                candidate("10", "Alice", false),
+                candidate("20", "Bob", false),
+                candidate("30", "Carol", true),
+            ]
+        }))
+        .unwrap();
+        let markets = EventMarkets::from_event(&event).unwrap();
+
+        assert!(markets.neg_risk);
+        assert_eq!(markets.neg_risk_market_id.as_deref(), Some("0xneg"));
+        assert_eq!(markets.markets[1].token("no"), Some(&TokenId::new("202")));
+        assert_eq!(markets.token_ids().len(), 4);
+        assert_eq!(
+            markets
+                .market_for_token(&TokenId::new("301"))
+                .unwrap()
+                .group_item_title
+                .as_deref(),
+            Some("Carol")
+        );
+
+        let sets = markets.exclusive_sets();
+        assert_eq!(sets.len(), 4);
+        assert_eq!(
+            sets[3],
+            [
+                TokenId::new("101"),
+                TokenId::new("201"),
+                TokenId::new("301")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mismatched_token_list_is_rejected() {
+        let mut market = candidate("10", "Alice", false);
+        market["clobTokenIds"] = json!("[\"101\"]");
+        let market: GammaMarket = serde_json::from_value(market).unwrap();
+        assert!(matches!(
+            EventMarket::from_gamma(&market),
+            Err(Error::InvalidParameter(_))
+        ));
+    }
+}
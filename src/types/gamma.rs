@@ -41,6 +41,13 @@ pub struct GammaMarket {
     pub best_bid: Option<f64>,
     pub best_ask: Option<f64>,
     pub spread: Option<f64>,
+
+    // Grouping within a multi-market event
+    pub group_item_title: Option<String>,
+    pub neg_risk: Option<bool>,
+    #[serde(rename = "negRiskMarketID")]
+    pub neg_risk_market_id: Option<String>,
+
     // Nested data
     #[serde(default)]
     pub events: Vec<GammaSimplifiedEvent>,
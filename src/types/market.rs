@@ -2,6 +2,8 @@ use chrono::{DateTime, TimeDelta, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use super::ConditionId;
+
 /// Full market information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Market {
@@ -48,6 +50,40 @@ impl Market {
         }
         true
     }
+
+    /// The winning outcome, once the market has resolved
+    pub fn winner(&self) -> Option<&Token> {
+        self.tokens.iter().find(|token| token.winner)
+    }
+}
+
+/// A change in a market's trading status, reported by
+/// [`ClobClient::watch_markets`](crate::ClobClient::watch_markets)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarketLifecycleEvent {
+    /// The order book stopped accepting orders while the market is still open
+    TradingPaused { condition_id: ConditionId },
+    /// The order book accepts orders again after a pause
+    TradingResumed { condition_id: ConditionId },
+    /// The market closed; no further trading, resolution may still be pending
+    Closed { condition_id: ConditionId },
+    /// The market resolved; `winner` is `None` for split or void resolutions
+    Resolved {
+        condition_id: ConditionId,
+        winner: Option<Token>,
+    },
+}
+
+impl MarketLifecycleEvent {
+    /// The market the event is about
+    pub fn condition_id(&self) -> &ConditionId {
+        match self {
+            Self::TradingPaused { condition_id }
+            | Self::TradingResumed { condition_id }
+            | Self::Closed { condition_id }
+            | Self::Resolved { condition_id, .. } => condition_id,
+        }
+    }
 }
 
 /// Simplified market information
@@ -63,10 +99,13 @@ pub struct SimplifiedMarket {
 }
 
 /// Token within a market
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Token {
     pub token_id: String,
     pub outcome: String,
+    /// Whether this outcome won; only ever set once the market has resolved
+    #[serde(default)]
+    pub winner: bool,
 }
 
 /// Market rewards configuration
@@ -87,22 +126,10 @@ pub struct RewardsRates {
 }
 
 /// Paginated markets response
-#[derive(Debug, Serialize, Deserialize)]
-pub struct MarketsResponse {
-    pub limit: u64,
-    pub count: u64,
-    pub next_cursor: Option<String>,
-    pub data: Vec<Market>,
-}
+pub type MarketsResponse = super::Page<Market>;
 
 /// Paginated simplified markets response
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SimplifiedMarketsResponse {
-    pub limit: u64,
-    pub count: u64,
-    pub next_cursor: Option<String>,
-    pub data: Vec<SimplifiedMarket>,
-}
+pub type SimplifiedMarketsResponse = super::Page<SimplifiedMarket>;
 
 /// Midpoint price response
 #[derive(Debug, Deserialize, Serialize)]
@@ -132,8 +159,11 @@ pub struct PriceHistory {
         deserialize_with = "super::serde_helpers::deserialize_decimal"
     )]
     pub price: Decimal,
-    #[serde(rename = "t")]
-    pub timestamp: u64,
+    #[serde(
+        rename = "t",
+        deserialize_with = "super::serde_helpers::deserialize_timestamp"
+    )]
+    pub timestamp: DateTime<Utc>,
 }
 
 /// Spread response
@@ -168,10 +198,12 @@ mod tests {
                 Token {
                     token_id: "token1".to_string(),
                     outcome: "Yes".to_string(),
+                    winner: false,
                 },
                 Token {
                     token_id: "token2".to_string(),
                     outcome: "No".to_string(),
+                    winner: false,
                 },
             ],
             rewards: Rewards {
@@ -1,20 +1,30 @@
 mod auth;
 mod enums;
+mod event;
 mod gamma;
 mod market;
 mod order;
+mod page;
 mod primitives;
+#[cfg(feature = "raw")]
+mod raw;
 mod serde_helpers;
+mod timestamp;
 mod trade;
 mod websocket;
 
 // Re-export all types
 pub use auth::*;
 pub use enums::*;
+pub use event::*;
 pub use gamma::*;
 pub use market::*;
 pub use order::*;
+pub use page::*;
 pub use primitives::*;
+#[cfg(feature = "raw")]
+pub use raw::Raw;
+pub use timestamp::*;
 pub use trade::*;
 pub use websocket::*;
 
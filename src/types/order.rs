@@ -1,7 +1,8 @@
 use super::enums::{OrderType, Side};
-use crate::error::Result;
-use crate::{orders::calculate_market_price, OrderId};
+use crate::error::{Error, Result};
+use crate::{orders::calculate_market_price, ConditionId, OrderId, TokenId};
 use alloy_primitives::U256;
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -121,7 +122,7 @@ pub struct SignedOrderRequest {
     pub expiration: String,
     pub nonce: String,
     pub fee_rate_bps: String,
-    pub side: String,
+    pub side: Side,
     pub signature_type: u8,
     pub signature: String,
 }
@@ -146,21 +147,15 @@ impl PostOrder {
 }
 
 /// Response for open orders query
-#[derive(Debug, Deserialize)]
-pub struct OpenOrdersResponse {
-    pub limit: u64,
-    pub count: u64,
-    pub next_cursor: Option<String>,
-    pub data: Vec<OpenOrder>,
-}
+pub type OpenOrdersResponse = super::Page<OpenOrder>;
 
 /// Open order from the API
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct OpenOrder {
     pub id: OrderId,
     pub associate_trades: Vec<String>,
     pub status: String,
-    pub market: String,
+    pub market: ConditionId,
     #[serde(with = "rust_decimal::serde::str")]
     pub original_size: Decimal,
     pub outcome: String,
@@ -171,12 +166,15 @@ pub struct OpenOrder {
     pub side: Side,
     #[serde(with = "rust_decimal::serde::str")]
     pub size_matched: Decimal,
-    pub asset_id: String,
-    #[serde(deserialize_with = "super::serde_helpers::deserialize_number_from_string")]
-    pub expiration: u64,
+    pub asset_id: TokenId,
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_timestamp"
+    )]
+    pub expiration: Option<DateTime<Utc>>,
     pub order_type: OrderType,
-    #[serde(deserialize_with = "super::serde_helpers::deserialize_number_from_string")]
-    pub created_at: u64,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp")]
+    pub created_at: DateTime<Utc>,
 }
 
 /// Parameters for querying open orders
@@ -240,11 +238,11 @@ pub struct PriceLevel {
 /// Order book summary with bids and asks
 #[derive(Debug, Deserialize)]
 pub struct OrderBookSummary {
-    pub market: String,
-    pub asset_id: String,
+    pub market: ConditionId,
+    pub asset_id: TokenId,
     pub hash: String,
-    #[serde(deserialize_with = "super::serde_helpers::deserialize_number_from_string")]
-    pub timestamp: u64,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp")]
+    pub timestamp: DateTime<Utc>,
     pub bids: Vec<PriceLevel>,
     pub asks: Vec<PriceLevel>,
 }
@@ -295,7 +293,7 @@ impl BookParams {
 }
 
 /// Response from posting an order
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PostOrderResponse {
     pub error_msg: String,
@@ -305,6 +303,18 @@ pub struct PostOrderResponse {
     pub success: bool,
 }
 
+impl PostOrderResponse {
+    /// The response standing in for an order whose request failed
+    pub(crate) fn failed(error: &Error) -> Self {
+        Self {
+            error_msg: error.to_string(),
+            order_id: OrderId::new(""),
+            status: String::new(),
+            success: false,
+        }
+    }
+}
+
 /// Arguments for posting multiple orders
 #[derive(Debug, Clone)]
 pub struct PostOrderArgs {
@@ -325,8 +335,39 @@ impl PostOrderArgs {
 /// - `cancel_orders` - Cancel multiple orders
 /// - `cancel_all` - Cancel all orders
 /// - `cancel_market_orders` - Cancel orders by market/asset
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CancelOrdersResponse {
     pub canceled: Vec<OrderId>,
     pub not_canceled: serde_json::Value,
 }
+
+impl CancelOrdersResponse {
+    /// Combine the responses of several cancel requests into one
+    ///
+    /// `not_canceled` maps order IDs to failure reasons, so the maps are
+    /// merged; a response without the map adds no reasons.
+    pub fn merge(responses: impl IntoIterator<Item = Self>) -> Self {
+        let mut canceled = Vec::new();
+        let mut not_canceled = serde_json::Map::new();
+        for response in responses {
+            canceled.extend(response.canceled);
+            if let serde_json::Value::Object(reasons) = response.not_canceled {
+                not_canceled.extend(reasons);
+            }
+        }
+        Self {
+            canceled,
+            not_canceled: serde_json::Value::Object(not_canceled),
+        }
+    }
+
+    /// The response standing in for an order whose cancel request failed
+    pub(crate) fn failed(order_id: &OrderId, error: &Error) -> Self {
+        let mut not_canceled = serde_json::Map::new();
+        not_canceled.insert(order_id.to_string(), error.to_string().into());
+        Self {
+            canceled: Vec::new(),
+            not_canceled: serde_json::Value::Object(not_canceled),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::request::END_CURSOR;
+use serde::{Deserialize, Serialize};
+
+/// One page of a cursor-paginated CLOB endpoint
+///
+/// `next_cursor` is the cursor of the following page; the CLOB marks the
+/// last page with [`END_CURSOR`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub limit: u64,
+    pub count: u64,
+    pub next_cursor: Option<String>,
+    pub data: Vec<T>,
+}
+
+impl<T> Page<T> {
+    /// Cursor of the following page, or `None` on the last page
+    pub fn next_page_cursor(&self) -> Option<&str> {
+        self.next_cursor
+            .as_deref()
+            .filter(|cursor| !cursor.is_empty() && *cursor != END_CURSOR)
+    }
+
+    /// Whether there are pages after this one
+    pub fn has_more(&self) -> bool {
+        self.next_page_cursor().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_page_cursor() {
+        let page = |cursor: Option<&str>| Page::<u32> {
+            limit: 2,
+            count: 2,
+            next_cursor: cursor.map(str::to_string),
+            data: vec![1, 2],
+        };
+
+        assert_eq!(page(Some("MTAw")).next_page_cursor(), Some("MTAw"));
+        assert!(!page(Some(END_CURSOR)).has_more());
+        assert!(!page(Some("")).has_more());
+        assert!(!page(None).has_more());
+    }
+}
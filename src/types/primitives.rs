@@ -1,8 +1,15 @@
-use serde::{Deserialize, Serialize};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
 /// Type-safe token identifier
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// CLOB token IDs are ERC1155 position IDs written as decimal strings.
+/// [`TokenId::new`] accepts any string (e.g. IDs already returned by the API);
+/// use [`TokenId::parse`] or [`str::parse`] to validate user input.
+/// Deserializing validates, so a malformed ID in a response is an error.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 #[serde(transparent)]
 pub struct TokenId(String);
 
@@ -11,6 +18,18 @@ impl TokenId {
         Self(id.into())
     }
 
+    /// Create a TokenId, checking that it is a non-empty decimal string
+    pub fn parse(id: impl Into<String>) -> Result<Self> {
+        let id = id.into();
+        if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidParameter(format!(
+                "Invalid token_id {:?}: expected a decimal string",
+                id
+            )));
+        }
+        Ok(Self(id))
+    }
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
@@ -44,8 +63,32 @@ impl AsRef<str> for TokenId {
     }
 }
 
+impl FromStr for TokenId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Self::parse(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<TokenId> for String {
+    fn from(id: TokenId) -> Self {
+        id.0
+    }
+}
+
 /// Type-safe condition identifier
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// Condition IDs are bytes32 values written as 0x-prefixed hex strings.
+/// [`ConditionId::new`] accepts any string; use [`ConditionId::parse`] or
+/// [`str::parse`] to validate user input. Deserializing validates.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 #[serde(transparent)]
 pub struct ConditionId(String);
 
@@ -54,6 +97,21 @@ impl ConditionId {
         Self(id.into())
     }
 
+    /// Create a ConditionId, checking that it is a 0x-prefixed 32-byte hex string
+    pub fn parse(id: impl Into<String>) -> Result<Self> {
+        let id = id.into();
+        let valid = id
+            .strip_prefix("0x")
+            .is_some_and(|hex| hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()));
+        if !valid {
+            return Err(Error::InvalidParameter(format!(
+                "Invalid condition_id {:?}: expected 0x followed by 64 hex digits",
+                id
+            )));
+        }
+        Ok(Self(id))
+    }
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
@@ -87,6 +145,26 @@ impl AsRef<str> for ConditionId {
     }
 }
 
+impl FromStr for ConditionId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConditionId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Self::parse(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<ConditionId> for String {
+    fn from(id: ConditionId) -> Self {
+        id.0
+    }
+}
+
 /// Type-safe order identifier
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -130,6 +208,12 @@ impl AsRef<str> for OrderId {
     }
 }
 
+impl From<OrderId> for String {
+    fn from(id: OrderId) -> Self {
+        id.0
+    }
+}
+
 /// Type-safe market slug
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -172,3 +256,43 @@ impl AsRef<str> for MarketSlug {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_id_validation() {
+        assert!(
+            "71321045679252212594626385532706912750332728571942532289631379312455583992563"
+                .parse::<TokenId>()
+                .is_ok()
+        );
+        assert!(TokenId::parse("").is_err());
+        assert!(TokenId::parse("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_condition_id_validation() {
+        let id = "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1";
+        assert_eq!(id.parse::<ConditionId>().unwrap().as_str(), id);
+        assert!(ConditionId::parse(&id[2..]).is_err());
+        assert!(ConditionId::parse("0x1234").is_err());
+        assert!(ConditionId::parse(format!("0x{}", "zz".repeat(32))).is_err());
+    }
+
+    #[test]
+    fn test_serde_is_transparent() {
+        let id: TokenId = serde_json::from_str("\"123\"").unwrap();
+        assert_eq!(id, TokenId::new("123"));
+        assert_eq!(String::from(id), "123");
+    }
+
+    #[test]
+    fn test_deserialize_validates() {
+        assert!(serde_json::from_str::<TokenId>("\"0x12\"").is_err());
+        assert!(serde_json::from_str::<ConditionId>("\"0xabc\"").is_err());
+        let id = format!("\"0x{}\"", "ab".repeat(32));
+        assert!(serde_json::from_str::<ConditionId>(&id).is_ok());
+    }
+}
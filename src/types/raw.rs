@@ -0,0 +1,179 @@
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::value::RawValue;
+use std::ops::Deref;
+
+use crate::error::Result;
+
+/// A parsed payload together with the exact JSON the server sent
+///
+/// Dereferences to the parsed value. Serializing writes the original JSON
+/// back byte for byte, including fields this version of the crate doesn't
+/// know about, so persisted payloads can be re-parsed with
+/// [`reparse`](Self::reparse) once a newer version models them.
+///
+/// Obtain one from the `*_raw` variants of the websocket subscriptions,
+/// parsers and client getters (such as
+/// [`ClobClient::get_order_book_raw`](crate::client::ClobClient::get_order_book_raw)),
+/// or by deserializing `Raw<T>` from any JSON yourself. Requires the `raw`
+/// feature.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_rs::types::{PriceResponse, Raw};
+///
+/// let json = r#"{"price":"0.52","new_field":1}"#;
+/// let price: Raw<PriceResponse> = Raw::from_json(json)?;
+/// assert_eq!(price.price.to_string(), "0.52");
+/// assert_eq!(price.raw(), json);
+/// assert_eq!(serde_json::to_string(&price)?, json);
+/// # Ok::<(), polymarket_rs::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Raw<T> {
+    value: T,
+    raw: Box<RawValue>,
+}
+
+impl<T: DeserializeOwned> Raw<T> {
+    /// Parse `json`, keeping a copy of it
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(Self {
+            value: serde_json::from_str(json)?,
+            raw: RawValue::from_string(json.to_string())?,
+        })
+    }
+}
+
+impl<T> Raw<T> {
+    /// Pair an already parsed value with its source JSON
+    pub(crate) fn new(value: T, raw: Box<RawValue>) -> Self {
+        Self { value, raw }
+    }
+
+    /// The parsed value
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The JSON as received
+    pub fn raw(&self) -> &str {
+        self.raw.get()
+    }
+
+    /// Drop the JSON and keep the parsed value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Split into the parsed value and the JSON
+    pub fn into_parts(self) -> (T, Box<RawValue>) {
+        (self.value, self.raw)
+    }
+
+    /// Parse the original JSON again, into any type
+    pub fn reparse<U: DeserializeOwned>(&self) -> Result<U> {
+        Ok(serde_json::from_str(self.raw.get())?)
+    }
+
+    /// Apply `f` to the parsed value, keeping the JSON
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Raw<U> {
+        Raw {
+            value: f(self.value),
+            raw: self.raw,
+        }
+    }
+}
+
+impl<T> Deref for Raw<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Raw<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let raw = Box::<RawValue>::deserialize(deserializer)?;
+        let value = serde_json::from_str(raw.get()).map_err(D::Error::custom)?;
+        Ok(Self { value, raw })
+    }
+}
+
+impl<T> Serialize for Raw<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.raw.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OpenOrder, UserWsEvent, WsEvent};
+
+    const BOOK: &str = r#"{"event_type":"book","asset_id":"123","market":"0x0000000000000000000000000000000000000000000000000000000000000001","timestamp":"1700000000000","hash":"0xabc","bids":[{"price":"0.48","size":"30"}],"asks":[{"price":"0.52","size":"25"}],"future_field":{"nested":[1,2]}}"#;
+
+    #[test]
+    fn test_raw_round_trips_byte_for_byte() {
+        let events: Vec<Raw<WsEvent>> = serde_json::from_str(&format!("[{}]", BOOK)).unwrap();
+        assert!(matches!(*events[0], WsEvent::Book(_)));
+        assert_eq!(events[0].raw(), BOOK);
+        assert_eq!(
+            serde_json::to_string(&events).unwrap(),
+            format!("[{}]", BOOK)
+        );
+
+        // Fields unknown to the typed value survive for later parsing
+        let value: serde_json::Value = events[0].reparse().unwrap();
+        assert_eq!(value["future_field"]["nested"][1], 2);
+    }
+
+    const PRICE_CHANGE: &str = r#"{"event_type":"price_change","market":"0x0000000000000000000000000000000000000000000000000000000000000001","timestamp":"1700000001000","hash":"0xdef","price_changes":[{"asset_id":"123","side":"BUY","price":"0.49","size":"10"}]}"#;
+    const LAST_TRADE_PRICE: &str = r#"{"event_type":"last_trade_price","asset_id":"123","market":"0x0000000000000000000000000000000000000000000000000000000000000001","price":"0.52","size":"5","fee_rate_bps":"0","side":"SELL","timestamp":"1700000002000","transaction_hash":"0x123"}"#;
+    const TICK_SIZE_CHANGE: &str = r#"{"event_type":"tick_size_change","asset_id":"123","market":"0x0000000000000000000000000000000000000000000000000000000000000001","old_tick_size":"0.01","new_tick_size":"0.001","timestamp":"1700000003000"}"#;
+    const TRADE: &str = r#"{"event_type":"trade","id":"t1","market":"0x0000000000000000000000000000000000000000000000000000000000000001","asset_id":"123","side":"BUY","outcome":"Yes","price":"0.52","size":"5","status":"MATCHED","fee_rate_bps":"100","maker_orders":[{"maker_address":"0xabc","matched_amount":"5","price":"0.52","outcome":"Yes"}]}"#;
+    const ORDER: &str = r#"{"event_type":"order","id":"0x1","owner":"key","market":"0x0000000000000000000000000000000000000000000000000000000000000001","asset_id":"123","side":"SELL","original_size":"100","size_matched":"10","price":"0.55","associate_trades":["t1"],"outcome":"No","type":"UPDATE","created_at":"1700000000","expiration":"1800000000","order_type":"GTC","status":"LIVE","maker_address":"0xabc","timestamp":"1700000004000"}"#;
+
+    /// Serialize `event`, parse the JSON back and check nothing changed
+    fn assert_round_trips<T: Serialize + DeserializeOwned + std::fmt::Debug>(event: &T) {
+        let json = serde_json::to_string(event).unwrap();
+        let again: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(format!("{:?}", again), format!("{:?}", event), "{}", json);
+    }
+
+    #[test]
+    fn test_typed_event_round_trip() {
+        // The typed structs re-serialize to JSON that parses back the same
+        let market: Vec<WsEvent> = [BOOK, PRICE_CHANGE, LAST_TRADE_PRICE, TICK_SIZE_CHANGE]
+            .iter()
+            .map(|json| serde_json::from_str(json).unwrap())
+            .collect();
+        assert!(matches!(
+            market[..],
+            [
+                WsEvent::Book(_),
+                WsEvent::PriceChange(_),
+                WsEvent::LastTradePrice(_),
+                WsEvent::TickSizeChange(_)
+            ]
+        ));
+        market.iter().for_each(assert_round_trips);
+
+        let user: Vec<UserWsEvent> = [TRADE, ORDER]
+            .iter()
+            .map(|json| serde_json::from_str(json).unwrap())
+            .collect();
+        assert!(matches!(
+            user[..],
+            [UserWsEvent::Trade(_), UserWsEvent::Order(_)]
+        ));
+        user.iter().for_each(assert_round_trips);
+    }
+
+    #[test]
+    fn test_invalid_payload_is_rejected() {
+        assert!(Raw::<OpenOrder>::from_json(r#"{"id": 1}"#).is_err());
+    }
+}
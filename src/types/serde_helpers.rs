@@ -49,6 +49,56 @@ where
     }
 }
 
+/// Raw timestamp as sent by the API: an epoch number or a string
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TimestampRepr {
+    Int(i64),
+    Str(String),
+}
+
+impl TimestampRepr {
+    /// Convert to a datetime; `Ok(None)` for empty strings and zero epochs
+    fn into_datetime<E: serde::de::Error>(self) -> Result<Option<DateTime<Utc>>, E> {
+        let parsed = match &self {
+            TimestampRepr::Int(0) => return Ok(None),
+            TimestampRepr::Str(s) if s.trim().is_empty() || s.trim() == "0" => return Ok(None),
+            TimestampRepr::Int(i) => super::timestamp_to_datetime(*i),
+            TimestampRepr::Str(s) => super::parse_timestamp(s),
+        };
+        match (parsed, self) {
+            (Some(dt), _) => Ok(Some(dt)),
+            (None, TimestampRepr::Int(i)) => Err(E::custom(format!("invalid timestamp: {}", i))),
+            (None, TimestampRepr::Str(s)) => Err(E::custom(format!("invalid timestamp: {}", s))),
+        }
+    }
+}
+
+/// Deserialize DateTime<Utc> from an epoch in seconds or milliseconds (number or string)
+/// or an RFC3339 string
+pub fn deserialize_timestamp<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    TimestampRepr::deserialize(deserializer)?
+        .into_datetime()?
+        .ok_or_else(|| serde::de::Error::custom("missing timestamp"))
+}
+
+/// Deserialize Option<DateTime<Utc>> like [`deserialize_timestamp`]
+/// - null, empty strings and zero (e.g. "no expiration") are treated as None
+pub fn deserialize_optional_timestamp<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<TimestampRepr>::deserialize(deserializer)? {
+        Some(repr) => repr.into_datetime(),
+        None => Ok(None),
+    }
+}
+
 /// Deserialize Option<DateTime<Utc>> from an optional datetime string
 /// Supports multiple formats:
 /// - RFC3339: "2022-07-27T14:41:12.085+00:00" or "2022-07-27T14:41:12.085Z"
@@ -155,6 +205,31 @@ mod tests {
         );
     }
 
+    #[derive(Deserialize)]
+    struct TimestampStruct {
+        #[serde(deserialize_with = "deserialize_timestamp")]
+        at: DateTime<Utc>,
+        #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+        expires: Option<DateTime<Utc>>,
+    }
+
+    #[test]
+    fn test_deserialize_timestamp_mixed_encodings() {
+        let secs: TimestampStruct = serde_json::from_str(r#"{"at": 1700000000}"#).unwrap();
+        let millis: TimestampStruct =
+            serde_json::from_str(r#"{"at": "1700000000000", "expires": "0"}"#).unwrap();
+        assert_eq!(secs.at, millis.at);
+        assert!(millis.expires.is_none());
+
+        let rfc: TimestampStruct =
+            serde_json::from_str(r#"{"at": "2023-11-14T22:13:20Z", "expires": 1700003600}"#)
+                .unwrap();
+        assert_eq!(rfc.at, secs.at);
+        assert_eq!(rfc.expires.unwrap().timestamp(), 1_700_003_600);
+
+        assert!(serde_json::from_str::<TimestampStruct>(r#"{"at": "soon"}"#).is_err());
+    }
+
     #[test]
     fn test_deserialize_optional_datetime_date_only() {
         let json = r#"{"date": "2025-10-23"}"#;
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+
+/// Epoch values at or above this are milliseconds rather than seconds
+///
+/// 10^11 seconds is over 3000 years away, while 10^11 milliseconds is 1973,
+/// so the two encodings Polymarket uses never overlap in practice.
+const MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+/// Convert a Unix timestamp in either seconds or milliseconds into a `DateTime<Utc>`
+///
+/// REST endpoints mostly report seconds while websocket events report
+/// milliseconds; the unit is inferred from the magnitude.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::types::timestamp_to_datetime;
+///
+/// let secs = timestamp_to_datetime(1_700_000_000).unwrap();
+/// let millis = timestamp_to_datetime(1_700_000_000_000).unwrap();
+/// assert_eq!(secs, millis);
+/// ```
+pub fn timestamp_to_datetime(timestamp: i64) -> Option<DateTime<Utc>> {
+    if timestamp.abs() >= MILLIS_THRESHOLD {
+        DateTime::from_timestamp_millis(timestamp)
+    } else {
+        DateTime::from_timestamp(timestamp, 0)
+    }
+}
+
+/// Parse a timestamp string as sent by the API
+///
+/// Accepts integer epochs in seconds or milliseconds (e.g. `"1700000000123"`)
+/// as well as RFC3339 datetimes (e.g. `"2023-11-14T22:13:20Z"`).
+pub fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    let s = s.trim();
+    match s.parse::<i64>() {
+        Ok(timestamp) => timestamp_to_datetime(timestamp),
+        Err(_) => DateTime::parse_from_rfc3339(s)
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seconds_and_millis() {
+        let secs = timestamp_to_datetime(1_700_000_000).unwrap();
+        assert_eq!(secs.to_rfc3339(), "2023-11-14T22:13:20+00:00");
+
+        let millis = timestamp_to_datetime(1_700_000_000_123).unwrap();
+        assert_eq!(millis.timestamp_millis(), 1_700_000_000_123);
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(
+            parse_timestamp("1700000000"),
+            timestamp_to_datetime(1_700_000_000)
+        );
+        assert_eq!(
+            parse_timestamp("2023-11-14T22:13:20Z"),
+            timestamp_to_datetime(1_700_000_000)
+        );
+        assert!(parse_timestamp("yesterday").is_none());
+    }
+}
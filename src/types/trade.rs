@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 
@@ -12,9 +13,7 @@ where
     if s.is_empty() {
         Ok(None)
     } else {
-        serde_json::from_str(&format!("\"{}\"", s))
-            .map(Some)
-            .map_err(serde::de::Error::custom)
+        s.parse().map(Some).map_err(serde::de::Error::custom)
     }
 }
 
@@ -112,7 +111,8 @@ pub struct Trade {
     pub size: Decimal,
     #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub price: Decimal,
-    pub timestamp: u64,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp")]
+    pub timestamp: DateTime<Utc>,
     pub title: String,
     pub slug: String,
     pub icon: String,
@@ -137,7 +137,8 @@ pub struct Trade {
 pub struct Activity {
     #[serde(rename = "proxyWallet")]
     pub proxy_wallet: String,
-    pub timestamp: u64,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp")]
+    pub timestamp: DateTime<Utc>,
     #[serde(rename = "conditionId")]
     pub condition_id: String,
     #[serde(rename = "type")]
@@ -195,7 +196,8 @@ pub struct ClosedPosition {
         deserialize_with = "super::serde_helpers::deserialize_decimal"
     )]
     pub cur_price: Decimal,
-    pub timestamp: u64,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp")]
+    pub timestamp: DateTime<Utc>,
     pub title: String,
     pub slug: String,
     pub icon: String,
@@ -212,6 +214,65 @@ pub struct ClosedPosition {
     pub end_date: String,
 }
 
+/// Top holders of a single outcome token in a market
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MarketHolders {
+    /// Outcome token ID
+    pub token: String,
+    /// Holders of this token, largest first
+    pub holders: Vec<Holder>,
+}
+
+/// A wallet holding an outcome token
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Holder {
+    #[serde(rename = "proxyWallet")]
+    pub proxy_wallet: String,
+    pub asset: String,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub amount: Decimal,
+    #[serde(rename = "outcomeIndex")]
+    pub outcome_index: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub pseudonym: String,
+    #[serde(default)]
+    pub bio: String,
+    #[serde(rename = "profileImage", default)]
+    pub profile_image: String,
+    #[serde(rename = "profileImageOptimized", default)]
+    pub profile_image_optimized: String,
+    #[serde(rename = "displayUsernamePublic", default)]
+    pub display_username_public: bool,
+}
+
+/// Trader ranking from the leaderboard endpoint
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_number_from_string")]
+    pub rank: u32,
+    #[serde(rename = "proxyWallet")]
+    pub proxy_wallet: String,
+    #[serde(rename = "userName", default)]
+    pub user_name: Option<String>,
+    /// Traded volume over the requested window
+    #[serde(
+        rename = "vol",
+        deserialize_with = "super::serde_helpers::deserialize_decimal"
+    )]
+    pub volume: Decimal,
+    /// Profit and loss over the requested window
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub pnl: Decimal,
+    #[serde(rename = "profileImage", default)]
+    pub profile_image: Option<String>,
+    #[serde(rename = "xUsername", default)]
+    pub x_username: Option<String>,
+    #[serde(rename = "verifiedBadge", default)]
+    pub verified_badge: bool,
+}
+
 /// Parameters for querying trades
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct TradeParams {
@@ -1,8 +1,9 @@
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use super::order::PriceLevel;
-use super::Side;
+use super::{ConditionId, OrderId, OrderType, Side, TokenId};
 
 // ============================================================================
 // Market WebSocket Events
@@ -28,11 +29,12 @@ pub struct BookEvent {
     /// Event type discriminator (always "book")
     pub event_type: String,
     /// Market ID
-    pub market: String,
+    pub market: ConditionId,
     /// Token/Asset ID
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Timestamp of the event
-    pub timestamp: String,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp")]
+    pub timestamp: DateTime<Utc>,
     /// Hash of the order book
     pub hash: String,
     /// Buy side order book
@@ -50,10 +52,14 @@ pub struct PriceChangeEvent {
     /// Event type discriminator (always "price_change")
     pub event_type: String,
     /// Market ID
-    pub market: String,
+    pub market: ConditionId,
     /// Timestamp (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "super::serde_helpers::deserialize_optional_timestamp"
+    )]
+    pub timestamp: Option<DateTime<Utc>>,
     /// Hash (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hash: Option<String>,
@@ -65,7 +71,7 @@ pub struct PriceChangeEvent {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceChange {
     /// Token/Asset ID
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Side of the book (BUY or SELL)
     pub side: Side,
     /// Price level that changed
@@ -82,9 +88,9 @@ pub struct LastTradePriceEvent {
     /// Event type discriminator (always "last_trade_price")
     pub event_type: String,
     /// Market ID
-    pub market: String,
+    pub market: ConditionId,
     /// Token/Asset ID
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Trade price
     #[serde(with = "rust_decimal::serde::str")]
     pub price: Decimal,
@@ -97,7 +103,8 @@ pub struct LastTradePriceEvent {
     /// Side of the trade (BUY or SELL)
     pub side: Side,
     /// Timestamp of the trade
-    pub timestamp: String,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp")]
+    pub timestamp: DateTime<Utc>,
     /// Transaction hash on blockchain
     pub transaction_hash: String,
 }
@@ -108,9 +115,9 @@ pub struct TickSizeChangeEvent {
     /// Event type discriminator (always "tick_size_change")
     pub event_type: String,
     /// Token/Asset ID
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Market ID
-    pub market: String,
+    pub market: ConditionId,
     /// Previous tick size
     #[serde(with = "rust_decimal::serde::str")]
     pub old_tick_size: Decimal,
@@ -118,7 +125,8 @@ pub struct TickSizeChangeEvent {
     #[serde(with = "rust_decimal::serde::str")]
     pub new_tick_size: Decimal,
     /// Timestamp of the change
-    pub timestamp: String,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp")]
+    pub timestamp: DateTime<Utc>,
 }
 
 // ============================================================================
@@ -143,9 +151,9 @@ pub struct TradeEvent {
     /// Trade ID
     pub id: String,
     /// Market ID
-    pub market: String,
+    pub market: ConditionId,
     /// Token/Asset ID
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Side of the trade (BUY or SELL)
     pub side: Side,
     /// Outcome (e.g., "Yes" or "No")
@@ -158,6 +166,13 @@ pub struct TradeEvent {
     pub size: Decimal,
     /// Trade status
     pub status: TradeStatus,
+    /// Fee rate in basis points, when sent
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "rust_decimal::serde::str_option"
+    )]
+    pub fee_rate_bps: Option<Decimal>,
     /// Maker orders that were matched
     pub maker_orders: Vec<MakerOrder>,
 }
@@ -189,6 +204,9 @@ pub struct MakerOrder {
     pub price: Decimal,
     /// Outcome (e.g., "Yes" or "No")
     pub outcome: String,
+    /// Side of the maker order, when the server reports it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<Side>,
 }
 
 /// Order status update event
@@ -197,14 +215,14 @@ pub struct OrderEvent {
     /// Event type discriminator (always "order")
     pub event_type: String,
     /// Order ID
-    pub id: String,
+    pub id: OrderId,
     /// Owner ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub owner: Option<String>,
     /// Market ID
-    pub market: String,
+    pub market: ConditionId,
     /// Token/Asset ID
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Side of the order (BUY or SELL)
     pub side: Side,
     /// Order owner ID
@@ -228,20 +246,32 @@ pub struct OrderEvent {
     #[serde(rename = "type")]
     pub order_event_type: String,
     /// Created at timestamp
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "super::serde_helpers::deserialize_optional_timestamp"
+    )]
+    pub created_at: Option<DateTime<Utc>>,
     /// Expiration timestamp
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "super::serde_helpers::deserialize_optional_timestamp"
+    )]
+    pub expiration: Option<DateTime<Utc>>,
     /// Order type (GTC, FOK, etc.)
-    pub order_type: String,
+    pub order_type: OrderType,
     /// Order status (LIVE, MATCHED, CANCELLED, etc.)
     pub status: String,
     /// Maker address
     pub maker_address: String,
     /// Event timestamp
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "super::serde_helpers::deserialize_optional_timestamp"
+    )]
+    pub timestamp: Option<DateTime<Utc>>,
 }
 
 // ============================================================================
@@ -252,7 +282,129 @@ pub struct OrderEvent {
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct MarketSubscription {
     /// List of asset/token IDs to subscribe to
-    pub assets_ids: Vec<String>,
+    pub assets_ids: Vec<TokenId>,
+}
+
+/// Kind of event on the market channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarketEventType {
+    Book,
+    PriceChange,
+    LastTradePrice,
+    TickSizeChange,
+}
+
+impl MarketEventType {
+    /// Every market event type
+    pub const ALL: [MarketEventType; 4] = [
+        MarketEventType::Book,
+        MarketEventType::PriceChange,
+        MarketEventType::LastTradePrice,
+        MarketEventType::TickSizeChange,
+    ];
+
+    /// The `event_type` the server tags these events with
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarketEventType::Book => "book",
+            MarketEventType::PriceChange => "price_change",
+            MarketEventType::LastTradePrice => "last_trade_price",
+            MarketEventType::TickSizeChange => "tick_size_change",
+        }
+    }
+
+    /// The event type tagged `event_type`, if known
+    pub fn from_tag(event_type: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == event_type)
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Which market events a subscription delivers
+///
+/// Messages of other types are dropped after reading only their
+/// `event_type` tag, before the rest of the message is parsed. The default
+/// delivers everything, including event types this version doesn't know;
+/// a restricted filter drops those.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventFilter {
+    allowed: u8,
+}
+
+impl EventFilter {
+    /// Deliver every event
+    pub fn all() -> Self {
+        Self {
+            allowed: MarketEventType::ALL
+                .iter()
+                .fold(0, |bits, kind| bits | kind.bit()),
+        }
+    }
+
+    /// Deliver only events of `types`
+    pub fn only(types: &[MarketEventType]) -> Self {
+        Self {
+            allowed: types.iter().fold(0, |bits, kind| bits | kind.bit()),
+        }
+    }
+
+    /// Also deliver events of `kind`
+    pub fn with(mut self, kind: MarketEventType) -> Self {
+        self.allowed |= kind.bit();
+        self
+    }
+
+    /// Stop delivering events of `kind`
+    pub fn without(mut self, kind: MarketEventType) -> Self {
+        self.allowed &= !kind.bit();
+        self
+    }
+
+    /// Whether events of `kind` are delivered
+    pub fn allows(&self, kind: MarketEventType) -> bool {
+        self.allowed & kind.bit() != 0
+    }
+
+    /// Whether every event is delivered
+    pub fn allows_all(&self) -> bool {
+        *self == Self::all()
+    }
+
+    /// Whether a message tagged `event_type` is delivered
+    pub fn allows_tag(&self, event_type: Option<&str>) -> bool {
+        self.allows_all()
+            || event_type
+                .and_then(MarketEventType::from_tag)
+                .is_some_and(|kind| self.allows(kind))
+    }
+}
+
+impl Default for EventFilter {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// Whether a [`MarketSubscriptionUpdate`] adds or removes tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubscriptionOperation {
+    Subscribe,
+    Unsubscribe,
+}
+
+/// Message changing the tokens of an open market websocket connection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketSubscriptionUpdate {
+    /// Token IDs to add or remove
+    pub assets_ids: Vec<TokenId>,
+    /// Whether to add or remove them
+    pub operation: SubscriptionOperation,
 }
 
 /// Authentication message for user websocket
@@ -29,6 +29,19 @@ pub fn build_hmac_signature<T>(
 where
     T: ?Sized + Serialize,
 {
+    // Use compact JSON (no spaces) like standard JSON.stringify
+    let serialized = body.map(serde_json::to_string).transpose()?;
+    build_hmac_signature_raw(secret, timestamp, method, req_path, serialized.as_deref())
+}
+
+/// Build HMAC-SHA256 signature over an already-serialized request body
+pub fn build_hmac_signature_raw(
+    secret: &str,
+    timestamp: u64,
+    method: &str,
+    req_path: &str,
+    body: Option<&str>,
+) -> Result<String> {
     // Decode the base64-encoded secret
     let decoded = URL_SAFE
         .decode(secret)
@@ -37,11 +50,7 @@ where
     // Build the message to sign
     let message = match body {
         None => format!("{timestamp}{method}{req_path}"),
-        Some(s) => {
-            // Use compact JSON (no spaces) like standard JSON.stringify
-            let serialized = serde_json::to_string(&s)?;
-            format!("{timestamp}{method}{req_path}{serialized}")
-        }
+        Some(body) => format!("{timestamp}{method}{req_path}{body}"),
     };
 
     // Create HMAC
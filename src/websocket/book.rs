@@ -0,0 +1,299 @@
+use chrono::{DateTime, Utc};
+use futures_util::{Stream, StreamExt};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::{Instant, MissedTickBehavior};
+
+use crate::error::Result;
+use crate::types::{BookEvent, ConditionId, PriceChange, PriceLevel, Side, TokenId, WsEvent};
+
+/// Order book of one token, kept up to date from market websocket events
+///
+/// Seeded by a [`BookEvent`] snapshot and updated level by level from
+/// [`PriceChange`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderBook {
+    asset_id: TokenId,
+    market: ConditionId,
+    timestamp: DateTime<Utc>,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl OrderBook {
+    /// Build a book from a full snapshot
+    pub fn from_snapshot(book: &BookEvent) -> Self {
+        let levels = |levels: &[PriceLevel]| {
+            levels
+                .iter()
+                .filter(|level| !level.size.is_zero())
+                .map(|level| (level.price, level.size))
+                .collect()
+        };
+        Self {
+            asset_id: book.asset_id.clone(),
+            market: book.market.clone(),
+            timestamp: book.timestamp,
+            bids: levels(&book.bids),
+            asks: levels(&book.asks),
+        }
+    }
+
+    /// Set the size of one price level; a size of zero removes the level
+    pub fn apply(&mut self, change: &PriceChange, timestamp: Option<DateTime<Utc>>) {
+        let side = match change.side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+        if change.size.is_zero() {
+            side.remove(&change.price);
+        } else {
+            side.insert(change.price, change.size);
+        }
+        if let Some(timestamp) = timestamp {
+            self.timestamp = timestamp;
+        }
+    }
+
+    /// The token this book is for
+    pub fn asset_id(&self) -> &TokenId {
+        &self.asset_id
+    }
+
+    /// The market the token belongs to
+    pub fn market(&self) -> &ConditionId {
+        &self.market
+    }
+
+    /// Time of the last event applied to the book
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    /// Bid levels, best (highest) first
+    pub fn bids(&self) -> impl Iterator<Item = PriceLevel> + '_ {
+        self.bids
+            .iter()
+            .rev()
+            .map(|(&price, &size)| PriceLevel { price, size })
+    }
+
+    /// Ask levels, best (lowest) first
+    pub fn asks(&self) -> impl Iterator<Item = PriceLevel> + '_ {
+        self.asks
+            .iter()
+            .map(|(&price, &size)| PriceLevel { price, size })
+    }
+
+    /// Highest bid, if any
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids().next()
+    }
+
+    /// Lowest ask, if any
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks().next()
+    }
+
+    /// Midpoint of the best bid and ask, if both sides are quoted
+    pub fn mid(&self) -> Option<Decimal> {
+        Some((self.best_bid()?.price + self.best_ask()?.price) / Decimal::TWO)
+    }
+
+    /// Best ask minus best bid, if both sides are quoted
+    pub fn spread(&self) -> Option<Decimal> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+}
+
+/// Order books of every token on a market websocket stream
+#[derive(Debug, Clone, Default)]
+pub struct OrderBooks {
+    books: HashMap<TokenId, OrderBook>,
+}
+
+impl OrderBooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a market event, returning whether any book changed
+    ///
+    /// Price changes for tokens without a snapshot yet are ignored, since
+    /// the book they would apply to is unknown.
+    pub fn apply(&mut self, event: &WsEvent) -> bool {
+        match event {
+            WsEvent::Book(book) => {
+                self.books
+                    .insert(book.asset_id.clone(), OrderBook::from_snapshot(book));
+                true
+            }
+            WsEvent::PriceChange(update) => {
+                let mut changed = false;
+                for change in &update.price_changes {
+                    if let Some(book) = self.books.get_mut(&change.asset_id) {
+                        book.apply(change, update.timestamp);
+                        changed = true;
+                    }
+                }
+                changed
+            }
+            WsEvent::LastTradePrice(_) | WsEvent::TickSizeChange(_) => false,
+        }
+    }
+
+    /// The book of a token, once its snapshot has arrived
+    pub fn get(&self, asset_id: &TokenId) -> Option<&OrderBook> {
+        self.books.get(asset_id)
+    }
+
+    /// Every book, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = &OrderBook> {
+        self.books.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.books.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.books.is_empty()
+    }
+}
+
+/// Conflate a market stream into full book snapshots at a fixed cadence
+///
+/// Applies every event to an internal [`OrderBooks`] as it arrives and,
+/// once per `interval`, yields a copy of every token's book taken at the
+/// same instant. Bursts of price changes between ticks are folded into one
+/// snapshot; ticks before the first book snapshot arrives are skipped.
+///
+/// Stream errors are passed through as they occur. With a
+/// [`ReconnectingStream`](super::ReconnectingStream), the server resends
+/// full snapshots after a reconnect, which replace the stale books.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::websocket::{conflate, MarketWsClient};
+/// use polymarket_rs::{StreamExt, TokenId};
+/// use std::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let stream = MarketWsClient::new().subscribe(vec![TokenId::new("123")]).await?;
+/// let mut snapshots = conflate(stream, Duration::from_millis(250));
+/// while let Some(books) = snapshots.next().await {
+///     for book in books? {
+///         println!("{} mid {:?}", book.asset_id(), book.mid());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn conflate<S>(
+    stream: S,
+    interval: Duration,
+) -> Pin<Box<dyn Stream<Item = Result<Vec<OrderBook>>> + Send>>
+where
+    S: Stream<Item = Result<WsEvent>> + Send + 'static,
+{
+    // The first tick comes one interval in, after events already queued
+    // have been applied, rather than immediately
+    let mut ticker = tokio::time::interval_at(Instant::now() + interval, interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let state = (Box::pin(stream), OrderBooks::new(), ticker);
+
+    Box::pin(futures_util::stream::unfold(
+        state,
+        |(mut stream, mut books, mut ticker)| async move {
+            loop {
+                tokio::select! {
+                    item = stream.next() => match item {
+                        Some(Ok(event)) => {
+                            books.apply(&event);
+                        }
+                        Some(Err(e)) => return Some((Err(e), (stream, books, ticker))),
+                        None => return None,
+                    },
+                    _ = ticker.tick() => {
+                        if !books.is_empty() {
+                            let snapshot = books.iter().cloned().collect();
+                            return Some((Ok(snapshot), (stream, books, ticker)));
+                        }
+                    }
+                }
+            }
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::websocket::parse_market_message;
+    use rust_decimal_macros::dec;
+
+    const BOOK: &str = r#"{"event_type": "book", "asset_id": "123",
+        "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+        "timestamp": "1700000000000", "hash": "0x",
+        "bids": [{"price": "0.40", "size": "100"}, {"price": "0.41", "size": "50"}],
+        "asks": [{"price": "0.45", "size": "80"}]}"#;
+
+    fn price_change(price: &str, size: &str) -> String {
+        format!(
+            r#"{{"event_type": "price_change", "timestamp": "1700000001000",
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "price_changes": [{{"asset_id": "123", "side": "BUY", "price": "{}", "size": "{}"}}]}}"#,
+            price, size
+        )
+    }
+
+    fn event(json: &str) -> Result<WsEvent> {
+        parse_market_message(json).remove(0)
+    }
+
+    #[test]
+    fn test_book_applies_price_changes() {
+        let mut books = OrderBooks::new();
+        assert!(!books.apply(&event(&price_change("0.42", "10")).unwrap()));
+        assert!(books.apply(&event(BOOK).unwrap()));
+
+        let token = TokenId::new("123");
+        assert_eq!(books.get(&token).unwrap().mid(), Some(dec!(0.43)));
+
+        books.apply(&event(&price_change("0.42", "10")).unwrap());
+        books.apply(&event(&price_change("0.41", "0")).unwrap());
+        let book = books.get(&token).unwrap();
+        let bids: Vec<_> = book.bids().map(|level| level.price).collect();
+        assert_eq!(bids, [dec!(0.42), dec!(0.40)]);
+        assert_eq!(book.spread(), Some(dec!(0.03)));
+        assert_eq!(book.timestamp().timestamp_millis(), 1_700_000_001_000);
+    }
+
+    #[tokio::test]
+    async fn test_conflate_folds_bursts_into_snapshots() {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let events = futures_util::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|event| (event, rx))
+        });
+        let mut snapshots = conflate(events, Duration::from_millis(50));
+
+        tx.send(event(BOOK)).unwrap();
+        for size in ["1", "2", "3"] {
+            tx.send(event(&price_change("0.42", size))).unwrap();
+        }
+        let books = snapshots.next().await.unwrap().unwrap();
+        assert_eq!(books.len(), 1);
+        assert_eq!(books[0].best_bid().unwrap().size, dec!(3));
+
+        tx.send(Err(Error::ConnectionClosed)).unwrap();
+        assert!(snapshots.next().await.unwrap().is_err());
+
+        drop(tx);
+        assert!(snapshots.next().await.is_none());
+    }
+}
@@ -0,0 +1,182 @@
+use futures_util::{Stream, StreamExt};
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::broadcast::{self as channel, error::RecvError};
+use tokio::task::JoinHandle;
+
+use crate::error::{Error, Result};
+
+/// An item as sent through the channel; errors are shared since they can't
+/// be cloned
+type Shared<T> = std::result::Result<T, Arc<Error>>;
+
+/// One upstream stream fanned out to any number of subscribers
+///
+/// Created by [`broadcast`]. Each [`subscribe`](Self::subscribe) returns an
+/// independent stream that sees every event from the moment it subscribed,
+/// so a logger, a strategy and a UI can share one websocket connection.
+///
+/// Dropping the bus stops reading the upstream stream; subscribers then end
+/// once they have drained what was already sent.
+pub struct EventBus<T> {
+    receiver: channel::Receiver<Shared<T>>,
+    task: JoinHandle<()>,
+}
+
+/// Fan a market or user stream out to multiple consumers
+///
+/// The stream is driven by a background task, so it keeps being read (and
+/// reconnected, with [`ReconnectingStream`](super::ReconnectingStream))
+/// regardless of how many subscribers there are. Each subscriber buffers up
+/// to `capacity` events; one that falls further behind skips the oldest and
+/// receives [`Error::Lagged`] with the number it missed, without slowing the
+/// others down.
+///
+/// Upstream errors are delivered to every subscriber. Errors other than
+/// connection errors are passed on as [`Error::WebSocket`] with the
+/// original message, since errors can't be cloned.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero, or if called outside a Tokio runtime.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::websocket::{broadcast, MarketWsClient};
+/// use polymarket_rs::{StreamExt, TokenId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let stream = MarketWsClient::new().subscribe(vec![TokenId::new("123")]).await?;
+/// let bus = broadcast(stream, 1024);
+///
+/// let mut log = bus.subscribe();
+/// tokio::spawn(async move {
+///     while let Some(event) = log.next().await {
+///         println!("{:?}", event);
+///     }
+/// });
+///
+/// let mut strategy = bus.subscribe();
+/// while let Some(event) = strategy.next().await {
+///     let event = event?;
+///     // ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn broadcast<S, T>(stream: S, capacity: usize) -> EventBus<T>
+where
+    S: Stream<Item = Result<T>> + Send + 'static,
+    T: Clone + Send + 'static,
+{
+    let (sender, receiver) = channel::channel(capacity);
+    let task = tokio::spawn(async move {
+        futures_util::pin_mut!(stream);
+        while let Some(item) = stream.next().await {
+            // The bus keeps a receiver, so sending only fails once it's dropped
+            if sender.send(item.map_err(Arc::new)).is_err() {
+                break;
+            }
+        }
+    });
+    EventBus { receiver, task }
+}
+
+impl<T: Clone + Send + 'static> EventBus<T> {
+    /// Subscribe to events sent from now on
+    pub fn subscribe(&self) -> Pin<Box<dyn Stream<Item = Result<T>> + Send>> {
+        let receiver = self.receiver.resubscribe();
+        Box::pin(futures_util::stream::unfold(
+            receiver,
+            |mut receiver| async move {
+                let item = match receiver.recv().await {
+                    Ok(Ok(event)) => Ok(event),
+                    Ok(Err(e)) => Err(share_error(&e)),
+                    Err(RecvError::Lagged(skipped)) => Err(Error::Lagged(skipped)),
+                    Err(RecvError::Closed) => return None,
+                };
+                Some((item, receiver))
+            },
+        ))
+    }
+
+    /// Whether the upstream stream has ended
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl<T> Drop for EventBus<T> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Rebuild an upstream error for one subscriber
+fn share_error(error: &Error) -> Error {
+    match error {
+        Error::ConnectionClosed => Error::ConnectionClosed,
+        Error::ReconnectFailed {
+            attempts,
+            last_error,
+        } => Error::ReconnectFailed {
+            attempts: *attempts,
+            last_error: last_error.clone(),
+        },
+        Error::AccessRestricted { status, message } => Error::AccessRestricted {
+            status: *status,
+            message: message.clone(),
+        },
+        Error::WebSocket(message) => Error::WebSocket(message.clone()),
+        other => Error::WebSocket(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::stream;
+
+    #[tokio::test]
+    async fn test_every_subscriber_sees_every_event() {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<Result<u32>>();
+        let upstream = stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|item| (item, rx))
+        });
+        let bus = broadcast(upstream, 16);
+        let first = bus.subscribe();
+        let second = bus.subscribe();
+
+        tx.send(Ok(1)).unwrap();
+        tx.send(Err(Error::ConnectionClosed)).unwrap();
+        tx.send(Ok(2)).unwrap();
+        drop(tx);
+
+        for subscriber in [first, second] {
+            let items: Vec<_> = subscriber.collect().await;
+            assert_eq!(items.len(), 3);
+            assert_eq!(items[0].as_ref().unwrap(), &1);
+            assert!(matches!(items[1], Err(Error::ConnectionClosed)));
+            assert_eq!(items[2].as_ref().unwrap(), &2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_lags() {
+        let bus = broadcast(stream::iter((0..10).map(Ok::<u32, Error>)), 4);
+        let slow = bus.subscribe();
+        while !bus.is_finished() {
+            tokio::task::yield_now().await;
+        }
+
+        let items: Vec<_> = slow.collect().await;
+        assert!(matches!(items[0], Err(Error::Lagged(6))));
+        let rest: Vec<u32> = items[1..]
+            .iter()
+            .map(|item| *item.as_ref().unwrap())
+            .collect();
+        assert_eq!(rest, [6, 7, 8, 9]);
+    }
+}
@@ -0,0 +1,357 @@
+use chrono::{DateTime, TimeZone, Utc};
+use rust_decimal::Decimal;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use crate::types::{PriceHistory, TokenId, WsEvent};
+
+/// Open, high, low and close prices of one interval
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candle {
+    /// Start of the interval
+    pub start: DateTime<Utc>,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    /// Traded size; backfilled and gap-filling candles carry none
+    pub volume: Decimal,
+}
+
+impl Candle {
+    fn new(start: DateTime<Utc>, price: Decimal, volume: Decimal) -> Self {
+        Self {
+            start,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume,
+        }
+    }
+
+    fn update(&mut self, price: Decimal, volume: Decimal) {
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+        self.volume += volume;
+    }
+}
+
+/// Bounded per-token candle series built from price history and live trades
+///
+/// Seed each token with [`backfill`](Self::backfill) (or
+/// [`ClobClient::backfill_candles`](crate::client::ClobClient::backfill_candles))
+/// and keep it current with the market stream's trades. Backfilled candles
+/// and live ones merge into one series: a live candle overlapping the
+/// history keeps the history's open, and intervals without any price are
+/// filled with flat candles at the previous close so the series has no
+/// holes. The oldest candles are dropped beyond `capacity`.
+///
+/// Backfilling after the stream has started is fine too, so subscribe
+/// first and backfill once connected to miss nothing in between.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::client::ClobClient;
+/// use polymarket_rs::websocket::{CandleAggregator, MarketWsClient};
+/// use polymarket_rs::{StreamExt, TokenId};
+/// use std::time::Duration;
+///
+/// # async fn example() -> polymarket_rs::Result<()> {
+/// let token_id = TokenId::new("123");
+/// let mut stream = MarketWsClient::new().subscribe(vec![token_id.clone()]).await?;
+///
+/// let mut candles = CandleAggregator::new(Duration::from_secs(60), 1_440);
+/// let clob = ClobClient::new("https://clob.polymarket.com");
+/// clob.backfill_candles(&mut candles, &token_id, "1d").await?;
+///
+/// while let Some(event) = stream.next().await {
+///     candles.record_event(&event?);
+///     println!("{:?}", candles.latest(&token_id));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CandleAggregator {
+    interval: Duration,
+    capacity: usize,
+    tokens: HashMap<TokenId, VecDeque<Candle>>,
+}
+
+impl CandleAggregator {
+    /// Aggregate into candles of `interval`, keeping up to `capacity` per token
+    pub fn new(interval: Duration, capacity: usize) -> Self {
+        Self {
+            interval: interval.max(Duration::from_millis(1)),
+            capacity: capacity.max(1),
+            tokens: HashMap::new(),
+        }
+    }
+
+    /// Length of each candle
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Start of the interval containing `timestamp`
+    pub fn interval_start(&self, timestamp: DateTime<Utc>) -> DateTime<Utc> {
+        let millis = self.interval.as_millis() as i64;
+        let start = timestamp.timestamp_millis().div_euclid(millis) * millis;
+        Utc.timestamp_millis_opt(start)
+            .single()
+            .unwrap_or(timestamp)
+    }
+
+    /// Add a trade at `price` for `size`
+    ///
+    /// Trades older than the token's first retained candle are dropped;
+    /// other late trades widen the candle they fall in without moving its
+    /// close.
+    pub fn record_trade(
+        &mut self,
+        asset_id: &TokenId,
+        timestamp: DateTime<Utc>,
+        price: Decimal,
+        size: Decimal,
+    ) {
+        let start = self.interval_start(timestamp);
+        let candles = self.tokens.entry(asset_id.clone()).or_default();
+        apply(candles, start, price, size, self.interval, self.capacity);
+    }
+
+    /// Add the trade of a `last_trade_price` event, returning whether the
+    /// event was one
+    pub fn record_event(&mut self, event: &WsEvent) -> bool {
+        match event {
+            WsEvent::LastTradePrice(trade) => {
+                self.record_trade(&trade.asset_id, trade.timestamp, trade.price, trade.size);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Merge historical prices into a token's series
+    ///
+    /// History newer than the token's first live candle is ignored, since
+    /// the live trades already cover it.
+    pub fn backfill(&mut self, asset_id: &TokenId, history: &[PriceHistory]) {
+        let mut points: Vec<_> = history.iter().map(|p| (p.timestamp, p.price)).collect();
+        points.sort_by_key(|(timestamp, _)| *timestamp);
+
+        let mut merged = VecDeque::new();
+        for (timestamp, price) in points {
+            let start = self.interval_start(timestamp);
+            apply(
+                &mut merged,
+                start,
+                price,
+                Decimal::ZERO,
+                self.interval,
+                self.capacity,
+            );
+        }
+
+        let live = self.tokens.remove(asset_id).unwrap_or_default();
+        if let Some(first) = live.front() {
+            while merged
+                .back()
+                .is_some_and(|candle| candle.start > first.start)
+            {
+                merged.pop_back();
+            }
+            let overlap = match merged.back() {
+                Some(candle) if candle.start == first.start => merged.pop_back(),
+                _ => None,
+            };
+            let mut live = live.into_iter();
+            if let Some(mut first) = live.next() {
+                if let Some(history) = overlap {
+                    first.open = history.open;
+                    first.high = first.high.max(history.high);
+                    first.low = first.low.min(history.low);
+                }
+                fill_gap(&mut merged, first.start, self.interval, self.capacity);
+                push(&mut merged, first, self.capacity);
+            }
+            for candle in live {
+                push(&mut merged, candle, self.capacity);
+            }
+        }
+        self.tokens.insert(asset_id.clone(), merged);
+    }
+
+    /// A token's candles, oldest first
+    pub fn candles(&self, asset_id: &TokenId) -> impl Iterator<Item = &Candle> {
+        self.tokens.get(asset_id).into_iter().flatten()
+    }
+
+    /// A token's most recent candle, which may still be forming
+    pub fn latest(&self, asset_id: &TokenId) -> Option<&Candle> {
+        self.tokens.get(asset_id)?.back()
+    }
+
+    /// Tokens with at least one candle
+    pub fn tokens(&self) -> impl Iterator<Item = &TokenId> {
+        self.tokens.keys()
+    }
+}
+
+fn apply(
+    candles: &mut VecDeque<Candle>,
+    start: DateTime<Utc>,
+    price: Decimal,
+    volume: Decimal,
+    interval: Duration,
+    capacity: usize,
+) {
+    match candles.back_mut() {
+        Some(last) if last.start == start => last.update(price, volume),
+        Some(last) if last.start > start => {
+            if let Ok(index) = candles.binary_search_by_key(&start, |candle| candle.start) {
+                let candle = &mut candles[index];
+                candle.high = candle.high.max(price);
+                candle.low = candle.low.min(price);
+                candle.volume += volume;
+            }
+        }
+        _ => {
+            fill_gap(candles, start, interval, capacity);
+            push(candles, Candle::new(start, price, volume), capacity);
+        }
+    }
+}
+
+/// Add flat candles at the last close up to the interval before `start`
+fn fill_gap(
+    candles: &mut VecDeque<Candle>,
+    start: DateTime<Utc>,
+    interval: Duration,
+    capacity: usize,
+) {
+    let Some(last) = candles.back().copied() else {
+        return;
+    };
+    let Ok(step) = chrono::Duration::from_std(interval) else {
+        return;
+    };
+    // Only the last `capacity` fillers could be kept anyway
+    let missing = ((start - last.start).num_milliseconds() / step.num_milliseconds() - 1)
+        .clamp(0, capacity as i64);
+    let first = start - step * missing as i32;
+    for i in 0..missing as i32 {
+        push(
+            candles,
+            Candle::new(first + step * i, last.close, Decimal::ZERO),
+            capacity,
+        );
+    }
+}
+
+fn push(candles: &mut VecDeque<Candle>, candle: Candle, capacity: usize) {
+    if candles.len() == capacity {
+        candles.pop_front();
+    }
+    candles.push_back(candle);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClobClient;
+    use crate::test_util::MockHttpServer;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(seconds, 0).unwrap()
+    }
+
+    fn point(seconds: i64, price: Decimal) -> PriceHistory {
+        PriceHistory {
+            price,
+            timestamp: at(seconds),
+        }
+    }
+
+    #[test]
+    fn test_backfill_merges_with_live_trades() {
+        let token = TokenId::new("123");
+        let mut candles = CandleAggregator::new(Duration::from_secs(60), 100);
+
+        // The stream delivered a trade before the backfill completed
+        candles.record_trade(&token, at(250), dec!(0.55), dec!(10));
+        candles.backfill(
+            &token,
+            &[
+                point(0, dec!(0.40)),
+                point(30, dec!(0.45)),
+                point(240, dec!(0.50)),
+                point(300, dec!(0.99)),
+            ],
+        );
+        candles.record_trade(&token, at(270), dec!(0.52), dec!(5));
+        candles.record_trade(&token, at(310), dec!(0.60), dec!(1));
+
+        let series: Vec<_> = candles.candles(&token).copied().collect();
+        let starts: Vec<_> = series.iter().map(|c| c.start.timestamp()).collect();
+        assert_eq!(starts, [0, 60, 120, 180, 240, 300]);
+
+        assert_eq!((series[0].open, series[0].close), (dec!(0.40), dec!(0.45)));
+        // Gaps are flat at the previous close
+        assert_eq!(series[1].open, dec!(0.45));
+        assert_eq!(series[3].close, dec!(0.45));
+        // The overlapping candle opens at the history, closes at the live price
+        assert_eq!(series[4].open, dec!(0.50));
+        assert_eq!(series[4].high, dec!(0.55));
+        assert_eq!(series[4].close, dec!(0.52));
+        assert_eq!(series[4].volume, dec!(15));
+        // History after the live trades started is ignored
+        assert_eq!(series[5].high, dec!(0.60));
+    }
+
+    #[test]
+    fn test_capacity_and_late_trades() {
+        let token = TokenId::new("123");
+        let mut candles = CandleAggregator::new(Duration::from_secs(60), 3);
+        for minute in 0..5 {
+            candles.record_trade(&token, at(minute * 60), dec!(0.5), dec!(1));
+        }
+        assert_eq!(candles.candles(&token).count(), 3);
+
+        candles.record_trade(&token, at(190), dec!(0.7), dec!(2));
+        let late = candles.candles(&token).nth(1).unwrap();
+        assert_eq!(
+            (late.high, late.close, late.volume),
+            (dec!(0.7), dec!(0.5), dec!(3))
+        );
+        assert_eq!(candles.latest(&token).unwrap().close, dec!(0.5));
+    }
+
+    #[tokio::test]
+    async fn test_backfill_from_prices_history() {
+        let server = MockHttpServer::with_clob_fixtures().await.unwrap();
+        server.mock_json(
+            "GET",
+            "/prices-history",
+            &json!({ "history": [{ "t": 60, "p": 0.4 }, { "t": 200, "p": 0.6 }] }),
+        );
+        let token = TokenId::new("123");
+        let mut candles = CandleAggregator::new(Duration::from_secs(300), 10);
+
+        let clob = ClobClient::new(server.url());
+        let points = clob
+            .backfill_candles(&mut candles, &token, "1d")
+            .await
+            .unwrap();
+
+        assert_eq!(points, 2);
+        let query = server.requests()[0].query.clone().unwrap();
+        assert!(query.contains("fidelity=5"));
+        let candle = candles.latest(&token).unwrap();
+        assert_eq!((candle.open, candle.close), (dec!(0.4), dec!(0.6)));
+    }
+}
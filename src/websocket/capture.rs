@@ -0,0 +1,298 @@
+use futures_util::Stream;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+use super::parse::{parse_market_message, parse_market_message_filtered, parse_user_message};
+use crate::error::Result;
+use crate::types::{EventFilter, UserWsEvent, WsEvent};
+
+/// Recorded websocket text frames, replayable through the parsers offline
+///
+/// Captures are stored one frame per line, the format
+/// [`write`](Self::write) produces and [`read`](Self::read) expects. Record
+/// one by pushing every text frame of a market channel connection whole, as
+/// received: the server batches several events into one array frame, so
+/// recording events one by one would replay differently.
+///
+/// Replaying runs every frame through the same parsing step the clients
+/// apply, so a capture doubles as a fixture for testing event handling and
+/// as a benchmark input: `cargo bench` replays the repository's synthetic
+/// capture, `benches/data/synthetic_market_frames.jsonl`.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::websocket::{FrameCapture, OrderBooks};
+///
+/// # fn example() -> polymarket_rs::Result<()> {
+/// let capture = FrameCapture::load("market_frames.jsonl")?;
+/// let mut books = OrderBooks::new();
+/// for event in capture.market_events() {
+///     books.apply(&event?);
+/// }
+/// println!("{} frames, {} books", capture.len(), books.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FrameCapture {
+    frames: Vec<String>,
+}
+
+impl FrameCapture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A capture of the given frames, in order
+    pub fn from_frames<I, S>(frames: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut capture = Self::new();
+        for frame in frames {
+            capture.push(frame);
+        }
+        capture
+    }
+
+    /// Read a capture of one frame per line, skipping blank lines
+    pub fn read(reader: impl BufRead) -> Result<Self> {
+        let mut capture = Self::new();
+        for line in reader.lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                capture.frames.push(line);
+            }
+        }
+        Ok(capture)
+    }
+
+    /// Read a capture from a file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+
+    /// Write the capture, one frame per line
+    pub fn write(&self, mut writer: impl Write) -> Result<()> {
+        for frame in &self.frames {
+            writeln!(writer, "{}", frame)?;
+        }
+        Ok(())
+    }
+
+    /// Write the capture to a file, replacing it
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut file = File::create(path)?;
+        self.write(&mut file)?;
+        Ok(file.flush()?)
+    }
+
+    /// Append a frame
+    ///
+    /// Line breaks are replaced with spaces so the frame fits on one line;
+    /// JSON can only contain them as whitespace, so this doesn't change
+    /// what it parses to.
+    pub fn push(&mut self, frame: impl Into<String>) {
+        let mut frame = frame.into();
+        if frame.contains(['\n', '\r']) {
+            frame = frame.replace(['\n', '\r'], " ");
+        }
+        self.frames.push(frame);
+    }
+
+    /// The frames, in order
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Combined size of the frames in bytes
+    pub fn total_bytes(&self) -> usize {
+        self.frames.iter().map(String::len).sum()
+    }
+
+    /// Parse the frames as market channel messages
+    ///
+    /// Frames carrying no event, such as heartbeat replies, are skipped.
+    pub fn market_events(&self) -> impl Iterator<Item = Result<WsEvent>> + '_ {
+        self.frames
+            .iter()
+            .flat_map(|frame| parse_market_message(frame))
+    }
+
+    /// Parse the frames as market channel messages, keeping only the event
+    /// types `filter` allows
+    pub fn market_events_filtered(
+        &self,
+        filter: EventFilter,
+    ) -> impl Iterator<Item = Result<WsEvent>> + '_ {
+        self.frames
+            .iter()
+            .flat_map(move |frame| parse_market_message_filtered(frame, filter))
+    }
+
+    /// Parse the frames as user channel messages
+    pub fn user_events(&self) -> impl Iterator<Item = Result<UserWsEvent>> + '_ {
+        self.frames
+            .iter()
+            .flat_map(|frame| parse_user_message(frame))
+    }
+
+    /// Replay the frames as a market event stream
+    ///
+    /// The stream has the type of a live subscription's items, so it can
+    /// drive [`conflate`](super::conflate), [`run_handler`](super::run_handler)
+    /// or any other stream consumer offline.
+    pub fn into_market_stream(self) -> impl Stream<Item = Result<WsEvent>> + Send + Unpin {
+        futures_util::stream::iter(
+            self.frames
+                .into_iter()
+                .flat_map(|frame| parse_market_message(&frame)),
+        )
+    }
+
+    /// Replay the frames as a user event stream
+    pub fn into_user_stream(self) -> impl Stream<Item = Result<UserWsEvent>> + Send + Unpin {
+        futures_util::stream::iter(
+            self.frames
+                .into_iter()
+                .flat_map(|frame| parse_user_message(&frame)),
+        )
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for FrameCapture {
+    fn from_iter<I: IntoIterator<Item = S>>(frames: I) -> Self {
+        Self::from_frames(frames)
+    }
+}
+
+impl<S: Into<String>> Extend<S> for FrameCapture {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, frames: I) {
+        for frame in frames {
+            self.push(frame);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MarketEventType;
+    use crate::websocket::OrderBooks;
+    use crate::{StreamExt, TokenId};
+    use rust_decimal_macros::dec;
+
+    const MARKET: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+    fn capture() -> FrameCapture {
+        FrameCapture::from_frames([
+            format!(
+                r#"[{{"event_type": "book", "asset_id": "123", "market": "{MARKET}",
+                "timestamp": "1700000000000", "hash": "0xabc",
+                "bids": [{{"price": "0.48", "size": "30"}}],
+                "asks": [{{"price": "0.52", "size": "25"}}]}}]"#
+            ),
+            "PONG".to_string(),
+            format!(
+                r#"{{"event_type": "price_change", "market": "{MARKET}",
+                "timestamp": "1700000001000", "price_changes": [
+                {{"asset_id": "123", "side": "BUY", "price": "0.49", "size": "10"}}]}}"#
+            ),
+            format!(
+                r#"{{"event_type": "last_trade_price", "asset_id": "123", "market": "{MARKET}",
+                "price": "0.52", "size": "5", "fee_rate_bps": "0", "side": "BUY",
+                "timestamp": "1700000002000", "transaction_hash": "0xdef"}}"#
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_capture_round_trips_through_lines() {
+        let capture = capture();
+        assert!(capture.frames().iter().all(|frame| !frame.contains('\n')));
+
+        let mut buffer = Vec::new();
+        capture.write(&mut buffer).unwrap();
+        buffer.extend_from_slice(b"\n\n");
+        let read = FrameCapture::read(buffer.as_slice()).unwrap();
+        assert_eq!(read.frames(), capture.frames());
+        assert_eq!(read.total_bytes(), capture.total_bytes());
+    }
+
+    #[test]
+    fn test_replay_through_parser_and_books() {
+        let capture = capture();
+        let events: Vec<_> = capture.market_events().collect::<Result<_>>().unwrap();
+        // The heartbeat reply carries no event
+        assert_eq!(events.len(), 3);
+
+        let mut books = OrderBooks::new();
+        for event in &events {
+            books.apply(event);
+        }
+        let book = books.get(&TokenId::new("123")).unwrap();
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.49));
+
+        let trades = capture
+            .market_events_filtered(EventFilter::only(&[MarketEventType::LastTradePrice]))
+            .count();
+        assert_eq!(trades, 1);
+    }
+
+    #[test]
+    fn test_array_frame_updates_every_book() {
+        let book = |asset_id: &str, bid: &str| {
+            format!(
+                r#"{{"event_type": "book", "asset_id": "{asset_id}", "market": "{MARKET}",
+                "timestamp": "1700000000000", "hash": "0x",
+                "bids": [{{"price": "{bid}", "size": "30"}}], "asks": []}}"#
+            )
+        };
+        let capture = FrameCapture::from_frames([format!(
+            "[{}, {}]",
+            book("123", "0.48"),
+            book("456", "0.51")
+        )]);
+
+        let mut books = OrderBooks::new();
+        for event in capture.market_events() {
+            books.apply(&event.unwrap());
+        }
+        assert_eq!(books.len(), 2);
+        let book = books.get(&TokenId::new("456")).unwrap();
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.51));
+    }
+
+    #[test]
+    fn test_bench_capture_replays() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/benches/data/synthetic_market_frames.jsonl"
+        );
+        let capture = FrameCapture::load(path).unwrap();
+        let events: Vec<_> = capture.market_events().collect::<Result<_>>().unwrap();
+        assert!(capture
+            .frames()
+            .iter()
+            .any(|frame| parse_market_message(frame).len() > 1));
+        assert!(events.len() > capture.len());
+    }
+
+    #[tokio::test]
+    async fn test_replay_as_stream() {
+        let events: Vec<_> = capture().into_market_stream().collect().await;
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], Ok(WsEvent::Book(_))));
+    }
+}
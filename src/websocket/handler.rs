@@ -0,0 +1,189 @@
+use futures_util::{pin_mut, Stream, StreamExt};
+use std::ops::ControlFlow;
+
+use crate::error::{Error, Result};
+use crate::types::{
+    BookEvent, LastTradePriceEvent, OrderEvent, PriceChangeEvent, TickSizeChangeEvent, TradeEvent,
+    UserWsEvent, WsEvent,
+};
+
+/// Callbacks for websocket events, one method per event type
+///
+/// An alternative to matching on [`WsEvent`] and [`UserWsEvent`] by hand:
+/// implement the methods for the events you care about (the rest default to
+/// doing nothing) and let [`run_handler`] feed a stream into them.
+///
+/// Callbacks are synchronous so the driving future stays `Send`; hand work
+/// that needs to await (placing orders, writing to a database) to a channel
+/// or spawned task.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::websocket::{run_handler, EventHandler, MarketWsClient};
+/// use polymarket_rs::types::{BookEvent, LastTradePriceEvent};
+/// use polymarket_rs::TokenId;
+///
+/// struct Printer;
+///
+/// impl EventHandler for Printer {
+///     fn on_book(&mut self, book: BookEvent) {
+///         println!("{} bids, {} asks", book.bids.len(), book.asks.len());
+///     }
+///
+///     fn on_trade(&mut self, trade: LastTradePriceEvent) {
+///         println!("trade at {}", trade.price);
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> polymarket_rs::Result<()> {
+/// let stream = MarketWsClient::new().subscribe(vec![TokenId::new("123")]).await?;
+/// run_handler(stream, &mut Printer).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[allow(unused_variables)]
+pub trait EventHandler {
+    /// Full order book snapshot
+    fn on_book(&mut self, book: BookEvent) {}
+
+    /// Price levels added, changed or removed
+    fn on_price_change(&mut self, change: PriceChangeEvent) {}
+
+    /// A market trade, i.e. a maker and taker order matched
+    fn on_trade(&mut self, trade: LastTradePriceEvent) {}
+
+    /// The market's minimum tick size changed
+    fn on_tick_size_change(&mut self, change: TickSizeChangeEvent) {}
+
+    /// A trade involving one of the user's orders
+    fn on_user_trade(&mut self, trade: TradeEvent) {}
+
+    /// One of the user's orders was placed, updated or canceled
+    fn on_order(&mut self, order: OrderEvent) {}
+
+    /// The stream yielded an error
+    ///
+    /// Return [`ControlFlow::Continue`] to keep going, e.g. when the stream
+    /// reconnects by itself. The default stops [`run_handler`], which then
+    /// returns the error.
+    fn on_error(&mut self, error: &Error) -> ControlFlow<()> {
+        ControlFlow::Break(())
+    }
+}
+
+/// An event that can be routed to the matching [`EventHandler`] method
+pub trait Dispatch {
+    /// Call the handler method for this event
+    fn dispatch<H: EventHandler + ?Sized>(self, handler: &mut H);
+}
+
+impl Dispatch for WsEvent {
+    fn dispatch<H: EventHandler + ?Sized>(self, handler: &mut H) {
+        match self {
+            WsEvent::Book(book) => handler.on_book(book),
+            WsEvent::PriceChange(change) => handler.on_price_change(change),
+            WsEvent::LastTradePrice(trade) => handler.on_trade(trade),
+            WsEvent::TickSizeChange(change) => handler.on_tick_size_change(change),
+        }
+    }
+}
+
+impl Dispatch for UserWsEvent {
+    fn dispatch<H: EventHandler + ?Sized>(self, handler: &mut H) {
+        match self {
+            UserWsEvent::Trade(trade) => handler.on_user_trade(trade),
+            UserWsEvent::Order(order) => handler.on_order(order),
+        }
+    }
+}
+
+/// Feed every event of `stream` to `handler` until the stream ends
+///
+/// Works with market and user streams alike, with or without
+/// [`ReconnectingStream`](super::ReconnectingStream). Returns the error that
+/// made [`EventHandler::on_error`] break, or `Ok` once the stream ends.
+pub async fn run_handler<S, E, H>(stream: S, handler: &mut H) -> Result<()>
+where
+    S: Stream<Item = Result<E>>,
+    E: Dispatch,
+    H: EventHandler + ?Sized,
+{
+    pin_mut!(stream);
+    while let Some(item) = stream.next().await {
+        match item {
+            Ok(event) => event.dispatch(handler),
+            Err(e) => {
+                if handler.on_error(&e).is_break() {
+                    return Err(e);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::websocket::parse_market_message;
+    use futures_util::stream;
+    use rust_decimal::Decimal;
+
+    #[derive(Default)]
+    struct Recorder {
+        trades: Vec<Decimal>,
+        tick_sizes: usize,
+        errors: usize,
+    }
+
+    impl EventHandler for Recorder {
+        fn on_trade(&mut self, trade: LastTradePriceEvent) {
+            self.trades.push(trade.price);
+        }
+
+        fn on_tick_size_change(&mut self, _change: TickSizeChangeEvent) {
+            self.tick_sizes += 1;
+        }
+
+        fn on_error(&mut self, _error: &Error) -> ControlFlow<()> {
+            self.errors += 1;
+            if self.errors > 1 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    fn event(json: &str) -> Result<WsEvent> {
+        parse_market_message(json).remove(0)
+    }
+
+    #[tokio::test]
+    async fn test_dispatches_until_handler_breaks() {
+        let trade = r#"{"event_type": "last_trade_price", "asset_id": "123",
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "price": "0.42", "side": "BUY", "size": "10", "fee_rate_bps": "0",
+            "timestamp": "1700000000000", "transaction_hash": "0x"}"#;
+        let tick = r#"{"event_type": "tick_size_change", "asset_id": "123",
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "old_tick_size": "0.01", "new_tick_size": "0.001", "timestamp": "1700000000000"}"#;
+
+        let events = stream::iter(vec![
+            event(trade),
+            Err(Error::ConnectionClosed),
+            event(tick),
+            Err(Error::ConnectionClosed),
+            event(trade),
+        ]);
+        let mut recorder = Recorder::default();
+        let result = run_handler(events, &mut recorder).await;
+
+        assert!(matches!(result, Err(Error::ConnectionClosed)));
+        assert_eq!(recorder.trades, [Decimal::new(42, 2)]);
+        assert_eq!(recorder.tick_sizes, 1);
+        assert_eq!(recorder.errors, 2);
+    }
+}
@@ -0,0 +1,343 @@
+use futures_util::stream::{self, SplitSink, SplitStream};
+use futures_util::{SinkExt, Stream, StreamExt};
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::error::Result;
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Write half of a websocket connection
+pub(crate) type WsWriter = SplitSink<WsStream, Message>;
+
+/// Read half of a websocket connection
+pub(crate) type WsReader = SplitStream<WsStream>;
+
+/// Write half shared between a stream's heartbeat and other senders
+pub(crate) type SharedWriter = Arc<tokio::sync::Mutex<Option<WsWriter>>>;
+
+/// Interval of the `PING` heartbeat the server expects from clients
+pub(crate) const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Lifecycle of a monitored websocket connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Connecting or authenticating
+    Connecting,
+    /// Open and delivering messages
+    Connected,
+    /// Never connected, or the last connection ended
+    Disconnected,
+}
+
+#[derive(Debug)]
+struct HealthState {
+    state: ConnectionState,
+    /// Incremented per connection so a stale stream can't mark a newer one down
+    connection: u64,
+    last_message: Option<Instant>,
+    ping_sent: Option<Instant>,
+    latency: Option<Duration>,
+}
+
+/// Live health of a websocket connection, shared with other tasks
+///
+/// Attach one to a client with `with_health` and every stream the client
+/// opens reports to it: its state, when it last received anything, and the
+/// round-trip time of its `PING` heartbeats. Queries are synchronous and
+/// cheap, so a supervisor can poll a handle without touching the stream,
+/// and a handle survives reconnects of a
+/// [`ReconnectingStream`](super::ReconnectingStream).
+///
+/// Give each monitored stream its own handle; streams sharing one overwrite
+/// each other's readings.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::websocket::{ConnectionHealth, ConnectionState, UserWsClient};
+/// use std::time::Duration;
+///
+/// # async fn example(creds: polymarket_rs::types::ApiCreds) -> polymarket_rs::Result<()> {
+/// let health = ConnectionHealth::new();
+/// let client = UserWsClient::new().with_health(health.clone());
+/// let stream = client.subscribe_with_creds(&creds).await?;
+///
+/// // In a supervisor task
+/// if health.state() != ConnectionState::Connected || health.is_stale(Duration::from_secs(30)) {
+///     println!("restart; last ping took {:?}", health.latency());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConnectionHealth {
+    inner: Arc<Mutex<HealthState>>,
+}
+
+impl ConnectionHealth {
+    /// Create a handle for a stream that hasn't connected yet
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(HealthState {
+                state: ConnectionState::Disconnected,
+                connection: 0,
+                last_message: None,
+                ping_sent: None,
+                latency: None,
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HealthState> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Current state of the connection
+    pub fn state(&self) -> ConnectionState {
+        self.lock().state
+    }
+
+    /// When the last frame of any kind arrived, including heartbeat replies
+    pub fn last_message(&self) -> Option<Instant> {
+        self.lock().last_message
+    }
+
+    /// Time since the last frame arrived
+    pub fn since_last_message(&self) -> Option<Duration> {
+        self.last_message().map(|at| at.elapsed())
+    }
+
+    /// Round-trip time of the most recently answered `PING`
+    pub fn latency(&self) -> Option<Duration> {
+        self.lock().latency
+    }
+
+    /// How long the outstanding `PING` has gone unanswered, if one has
+    pub fn awaiting_pong(&self) -> Option<Duration> {
+        self.lock().ping_sent.map(|at| at.elapsed())
+    }
+
+    /// Whether the connection has been silent for longer than `max_silence`
+    ///
+    /// A connection that never received anything counts as stale.
+    pub fn is_stale(&self, max_silence: Duration) -> bool {
+        self.since_last_message()
+            .is_none_or(|silence| silence > max_silence)
+    }
+
+    /// Mark a new connection attempt
+    pub(crate) fn connecting(&self) {
+        self.lock().state = ConnectionState::Connecting;
+    }
+
+    /// Mark the connection open, returning its generation
+    fn connected(&self) -> u64 {
+        let mut health = self.lock();
+        health.connection += 1;
+        health.state = ConnectionState::Connected;
+        health.last_message = Some(Instant::now());
+        health.ping_sent = None;
+        health.connection
+    }
+
+    /// Mark a connection ended, unless a newer one has replaced it
+    fn disconnected(&self, connection: u64) {
+        let mut health = self.lock();
+        if health.connection == connection && health.state == ConnectionState::Connected {
+            health.state = ConnectionState::Disconnected;
+            health.ping_sent = None;
+        }
+    }
+
+    /// Mark a connection attempt failed
+    pub(crate) fn failed(&self) {
+        let mut health = self.lock();
+        if health.state == ConnectionState::Connecting {
+            health.state = ConnectionState::Disconnected;
+        }
+    }
+
+    fn ping_sent(&self) {
+        let mut health = self.lock();
+        // Keep timing the oldest unanswered ping
+        health.ping_sent.get_or_insert_with(Instant::now);
+    }
+
+    fn received(&self, message: &Message) {
+        let now = Instant::now();
+        let mut health = self.lock();
+        health.last_message = Some(now);
+        let is_pong = match message {
+            Message::Text(text) => text.trim().eq_ignore_ascii_case("pong"),
+            Message::Pong(_) => true,
+            _ => false,
+        };
+        if is_pong {
+            if let Some(sent) = health.ping_sent.take() {
+                health.latency = Some(now - sent);
+            }
+        }
+    }
+}
+
+impl Default for ConnectionHealth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Marks its connection down when the stream ends or is dropped
+struct Connection {
+    health: ConnectionHealth,
+    id: u64,
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.health.disconnected(self.id);
+    }
+}
+
+struct Monitored<T, P> {
+    read: WsReader,
+    writer: SharedWriter,
+    connection: Connection,
+    heartbeat: Option<Interval>,
+    parse: P,
+    /// Events of the last message not yet yielded
+    pending: VecDeque<Result<T>>,
+}
+
+impl<T, P> Monitored<T, P> {
+    /// Send a `PING`; a failed send surfaces as a read error shortly after
+    async fn ping(&mut self) {
+        if let Some(write) = self.writer.lock().await.as_mut() {
+            match write.send(Message::Text("PING".to_string())).await {
+                Ok(()) => self.connection.health.ping_sent(),
+                Err(e) => log::debug!("Failed to send websocket heartbeat: {}", e),
+            }
+        }
+    }
+}
+
+async fn tick(heartbeat: &mut Option<Interval>) {
+    match heartbeat {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Turn an open connection into an event stream that sends `PING` every
+/// `ping_interval` and reports to `health`
+pub(crate) fn monitor<T, P>(
+    read: WsReader,
+    writer: SharedWriter,
+    health: ConnectionHealth,
+    ping_interval: Option<Duration>,
+    parse: P,
+) -> Pin<Box<dyn Stream<Item = Result<T>> + Send>>
+where
+    T: Send + 'static,
+    P: Fn(std::result::Result<Message, tungstenite::Error>) -> Vec<Result<T>> + Send + 'static,
+{
+    let heartbeat = ping_interval.map(|period| {
+        let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        interval
+    });
+    let id = health.connected();
+    let state = Monitored {
+        read,
+        writer,
+        connection: Connection { health, id },
+        heartbeat,
+        parse,
+        pending: VecDeque::new(),
+    };
+
+    Box::pin(stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.pending.pop_front() {
+                return Some((item, state));
+            }
+            let message = tokio::select! {
+                message = state.read.next() => message,
+                _ = tick(&mut state.heartbeat) => {
+                    state.ping().await;
+                    continue;
+                }
+            };
+            let Some(message) = message else {
+                state.connection.health.disconnected(state.connection.id);
+                return None;
+            };
+            match &message {
+                Ok(Message::Close(_)) | Err(_) => {
+                    state.connection.health.disconnected(state.connection.id)
+                }
+                Ok(message) => state.connection.health.received(message),
+            }
+            state.pending.extend((state.parse)(message));
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{MockWsServer, ScriptedFrame};
+    use crate::types::ApiCreds;
+    use crate::websocket::UserWsClient;
+
+    #[tokio::test]
+    async fn test_health_tracks_heartbeat_and_disconnect() {
+        let server = MockWsServer::start(vec![vec![]]).await.unwrap();
+        let health = ConnectionHealth::new();
+        assert_eq!(health.state(), ConnectionState::Disconnected);
+        assert!(health.is_stale(Duration::from_secs(60)));
+
+        let client = UserWsClient::with_url(server.url())
+            .with_health(health.clone())
+            .with_ping_interval(Duration::from_millis(20));
+        let creds = ApiCreds::new("key".into(), "secret".into(), "pass".into());
+        let mut stream = client.subscribe_with_creds(&creds).await.unwrap();
+        assert_eq!(health.state(), ConnectionState::Connected);
+
+        // PONG replies are consumed by the stream without yielding events
+        let next = tokio::time::timeout(Duration::from_millis(200), stream.next()).await;
+        assert!(next.is_err());
+        assert!(health.latency().is_some());
+        assert!(!health.is_stale(Duration::from_secs(60)));
+
+        drop(stream);
+        assert_eq!(health.state(), ConnectionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn test_server_close_marks_disconnected() {
+        let server = MockWsServer::start(vec![vec![ScriptedFrame::Close]])
+            .await
+            .unwrap();
+        let health = ConnectionHealth::new();
+        let client =
+            crate::websocket::MarketWsClient::with_url(server.url()).with_health(health.clone());
+        let mut stream = client
+            .subscribe(vec![crate::types::TokenId::new("123")])
+            .await
+            .unwrap();
+
+        assert!(stream.next().await.unwrap().is_err());
+        assert_eq!(health.state(), ConnectionState::Disconnected);
+    }
+}
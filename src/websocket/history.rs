@@ -0,0 +1,286 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use super::OrderBook;
+use crate::types::TokenId;
+
+/// Top of book at one point in time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BookSample {
+    pub timestamp: DateTime<Utc>,
+    pub mid: Decimal,
+    pub spread: Decimal,
+    /// Size at the best bid
+    pub bid_size: Decimal,
+    /// Size at the best ask
+    pub ask_size: Decimal,
+}
+
+impl BookSample {
+    /// Sample a book's top of book; `None` unless both sides are quoted
+    pub fn from_book(book: &OrderBook) -> Option<Self> {
+        let bid = book.best_bid()?;
+        let ask = book.best_ask()?;
+        Some(Self {
+            timestamp: book.timestamp(),
+            mid: (bid.price + ask.price) / Decimal::TWO,
+            spread: ask.price - bid.price,
+            bid_size: bid.size,
+            ask_size: ask.size,
+        })
+    }
+
+    /// The value of one metric
+    pub fn get(&self, metric: BookMetric) -> Decimal {
+        match metric {
+            BookMetric::Mid => self.mid,
+            BookMetric::Spread => self.spread,
+            BookMetric::BidSize => self.bid_size,
+            BookMetric::AskSize => self.ask_size,
+        }
+    }
+}
+
+/// A field of [`BookSample`] to compute statistics over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookMetric {
+    Mid,
+    Spread,
+    BidSize,
+    AskSize,
+}
+
+/// Summary statistics over a window of samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RollingStats {
+    /// Number of samples in the window
+    pub count: usize,
+    pub mean: Decimal,
+    /// Population standard deviation
+    pub std_dev: Decimal,
+    pub min: Decimal,
+    pub max: Decimal,
+}
+
+impl RollingStats {
+    fn from_values(values: impl Iterator<Item = Decimal>) -> Option<Self> {
+        let values: Vec<Decimal> = values.collect();
+        let count = values.len();
+        let first = *values.first()?;
+        let n = Decimal::from(count);
+
+        let mean = values.iter().sum::<Decimal>() / n;
+        let variance = values
+            .iter()
+            .map(|value| (value - mean) * (value - mean))
+            .sum::<Decimal>()
+            / n;
+        let std_dev = variance
+            .to_f64()
+            .and_then(|variance| Decimal::from_f64(variance.sqrt()))
+            .unwrap_or_default();
+
+        let (min, max) = values.iter().fold((first, first), |(min, max), &value| {
+            (min.min(value), max.max(value))
+        });
+        Some(Self {
+            count,
+            mean,
+            std_dev,
+            min,
+            max,
+        })
+    }
+}
+
+/// Bounded per-token history of top-of-book samples
+///
+/// Keeps the last `capacity` samples of each token, dropping the oldest,
+/// and answers rolling mean, standard deviation, min and max queries over
+/// them, enough for short-window signals without a time-series store.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_rs::websocket::{BookHistory, BookMetric, OrderBooks};
+/// use std::time::Duration;
+///
+/// # fn example(books: &OrderBooks) {
+/// let mut history = BookHistory::new(1_000);
+/// for book in books.iter() {
+///     history.record_book(book);
+/// }
+///
+/// # let token_id = polymarket_rs::TokenId::new("123");
+/// if let Some(stats) = history.stats(&token_id, BookMetric::Mid, Duration::from_secs(60)) {
+///     println!("1m mid {} ± {}", stats.mean, stats.std_dev);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BookHistory {
+    capacity: usize,
+    tokens: HashMap<TokenId, VecDeque<BookSample>>,
+}
+
+impl BookHistory {
+    /// Create a history keeping up to `capacity` samples per token
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            tokens: HashMap::new(),
+        }
+    }
+
+    /// Append a sample, evicting the token's oldest once full
+    pub fn record(&mut self, asset_id: &TokenId, sample: BookSample) {
+        let samples = self
+            .tokens
+            .entry(asset_id.clone())
+            .or_insert_with(|| VecDeque::with_capacity(self.capacity));
+        if samples.len() == self.capacity {
+            samples.pop_front();
+        }
+        samples.push_back(sample);
+    }
+
+    /// Sample and record a book, returning whether it had both sides quoted
+    pub fn record_book(&mut self, book: &OrderBook) -> bool {
+        match BookSample::from_book(book) {
+            Some(sample) => {
+                self.record(book.asset_id(), sample);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// A token's samples, oldest first
+    pub fn samples(&self, asset_id: &TokenId) -> impl Iterator<Item = &BookSample> {
+        self.tokens.get(asset_id).into_iter().flatten()
+    }
+
+    /// A token's most recent sample
+    pub fn latest(&self, asset_id: &TokenId) -> Option<&BookSample> {
+        self.tokens.get(asset_id)?.back()
+    }
+
+    /// Statistics over the samples within `window` of the latest one
+    pub fn stats(
+        &self,
+        asset_id: &TokenId,
+        metric: BookMetric,
+        window: Duration,
+    ) -> Option<RollingStats> {
+        let samples = self.tokens.get(asset_id)?;
+        let latest = samples.back()?.timestamp;
+        // A window reaching past the earliest representable time covers every sample
+        let since = chrono::Duration::from_std(window)
+            .ok()
+            .and_then(|window| latest.checked_sub_signed(window));
+        RollingStats::from_values(
+            samples
+                .iter()
+                .rev()
+                .take_while(|sample| since.is_none_or(|since| sample.timestamp >= since))
+                .map(|sample| sample.get(metric)),
+        )
+    }
+
+    /// Statistics over a token's last `count` samples
+    pub fn stats_last(
+        &self,
+        asset_id: &TokenId,
+        metric: BookMetric,
+        count: usize,
+    ) -> Option<RollingStats> {
+        let samples = self.tokens.get(asset_id)?;
+        RollingStats::from_values(
+            samples
+                .iter()
+                .rev()
+                .take(count)
+                .map(|sample| sample.get(metric)),
+        )
+    }
+
+    /// Drop a token's history
+    pub fn remove(&mut self, asset_id: &TokenId) {
+        self.tokens.remove(asset_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn sample(secs: i64, mid: Decimal) -> BookSample {
+        BookSample {
+            timestamp: Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap(),
+            mid,
+            spread: dec!(0.02),
+            bid_size: dec!(100),
+            ask_size: dec!(50),
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer_evicts_oldest() {
+        let token = TokenId::new("123");
+        let mut history = BookHistory::new(3);
+        for (secs, mid) in [
+            (0, dec!(0.1)),
+            (1, dec!(0.2)),
+            (2, dec!(0.3)),
+            (3, dec!(0.4)),
+        ] {
+            history.record(&token, sample(secs, mid));
+        }
+
+        let mids: Vec<_> = history.samples(&token).map(|s| s.mid).collect();
+        assert_eq!(mids, [dec!(0.2), dec!(0.3), dec!(0.4)]);
+        assert_eq!(history.latest(&token).unwrap().mid, dec!(0.4));
+    }
+
+    #[test]
+    fn test_rolling_stats() {
+        let token = TokenId::new("123");
+        let mut history = BookHistory::new(10);
+        for (secs, mid) in [
+            (0, dec!(0.9)),
+            (10, dec!(0.4)),
+            (11, dec!(0.6)),
+            (12, dec!(0.5)),
+        ] {
+            history.record(&token, sample(secs, mid));
+        }
+
+        let stats = history
+            .stats(&token, BookMetric::Mid, Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.mean, dec!(0.5));
+        assert_eq!((stats.min, stats.max), (dec!(0.4), dec!(0.6)));
+        assert_eq!(stats.std_dev.round_dp(4), dec!(0.0816));
+
+        let stats = history
+            .stats(&token, BookMetric::Mid, Duration::MAX)
+            .unwrap();
+        assert_eq!(stats.count, 4);
+
+        let stats = history.stats_last(&token, BookMetric::Spread, 2).unwrap();
+        assert_eq!((stats.count, stats.std_dev), (2, Decimal::ZERO));
+        assert!(history
+            .stats(
+                &TokenId::new("456"),
+                BookMetric::Mid,
+                Duration::from_secs(5)
+            )
+            .is_none());
+    }
+}
@@ -1,31 +1,107 @@
 use futures_util::{SinkExt, Stream, StreamExt};
 use std::pin::Pin;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
+use super::health::{
+    monitor, ConnectionHealth, SharedWriter, WsReader, WsWriter, DEFAULT_PING_INTERVAL,
+};
+use super::parse::parse_market_message_filtered;
+#[cfg(feature = "raw")]
+use super::parse::parse_text_raw;
+use super::stream::ReconnectingStream;
+use crate::config::RetryPolicy;
 use crate::error::{Error, Result};
-use crate::types::{MarketSubscription, WsEvent};
-
-/// Handle for querying WebSocket subscription state
-///
-/// This handle provides read-only access to the current token IDs
-/// being subscribed to.
+#[cfg(feature = "raw")]
+use crate::types::Raw;
+use crate::types::{
+    EventFilter, EventMarkets, MarketSubscription, MarketSubscriptionUpdate, SubscriptionOperation,
+    TokenId, WsEvent,
+};
+
+/// Handle for querying and changing the tokens of a market subscription
 ///
-/// **Note**: Polymarket does not support updating subscriptions on an
-/// existing WebSocket connection. To change subscriptions, you must
-/// close the connection and create a new one with the updated token list.
+/// Tokens added or removed through the handle are sent to the open
+/// connection and recorded in the handle's token set. Streams created with
+/// [`MarketWsClient::subscribe_reconnecting`] resubscribe to that set on
+/// every reconnect, so the subscription after a drop is exactly the one in
+/// effect before it.
 #[derive(Clone)]
 pub struct SubscriptionHandle {
     /// Shared state containing current token IDs
-    current_tokens: Arc<RwLock<Vec<String>>>,
+    current_tokens: Arc<RwLock<Vec<TokenId>>>,
+    /// Write half of the live connection, replaced on reconnect
+    writer: SharedWriter,
 }
 
 impl SubscriptionHandle {
+    fn new(token_ids: Vec<TokenId>) -> Self {
+        Self {
+            current_tokens: Arc::new(RwLock::new(token_ids)),
+            writer: Arc::new(Mutex::new(None)),
+        }
+    }
+
     /// Get the current token IDs being subscribed to
-    pub async fn current_tokens(&self) -> Vec<String> {
+    pub async fn current_tokens(&self) -> Vec<TokenId> {
         self.current_tokens.read().await.clone()
     }
+
+    /// Add tokens to the subscription
+    ///
+    /// Tokens already subscribed are skipped. The tokens are recorded even
+    /// if sending fails, so a reconnecting stream picks them up on its next
+    /// connection.
+    pub async fn subscribe(&self, token_ids: Vec<TokenId>) -> Result<()> {
+        let mut writer = self.writer.lock().await;
+        let added: Vec<TokenId> = {
+            let mut current = self.current_tokens.write().await;
+            let added: Vec<TokenId> = token_ids
+                .into_iter()
+                .filter(|token_id| !current.contains(token_id))
+                .collect();
+            current.extend(added.iter().cloned());
+            added
+        };
+        send_update(&mut writer, added, SubscriptionOperation::Subscribe).await
+    }
+
+    /// Remove tokens from the subscription
+    ///
+    /// Like [`subscribe`](Self::subscribe), the change is recorded even if
+    /// sending fails.
+    pub async fn unsubscribe(&self, token_ids: Vec<TokenId>) -> Result<()> {
+        let mut writer = self.writer.lock().await;
+        self.current_tokens
+            .write()
+            .await
+            .retain(|token_id| !token_ids.contains(token_id));
+        send_update(&mut writer, token_ids, SubscriptionOperation::Unsubscribe).await
+    }
+}
+
+/// Send a subscription change over the live connection, if there is one
+async fn send_update(
+    writer: &mut Option<WsWriter>,
+    assets_ids: Vec<TokenId>,
+    operation: SubscriptionOperation,
+) -> Result<()> {
+    let Some(write) = writer.as_mut() else {
+        return Ok(());
+    };
+    if assets_ids.is_empty() {
+        return Ok(());
+    }
+    let update = MarketSubscriptionUpdate {
+        assets_ids,
+        operation,
+    };
+    write
+        .send(Message::Text(serde_json::to_string(&update)?))
+        .await
+        .map_err(|e| Error::WebSocket(e.to_string()))
 }
 
 /// WebSocket client for streaming market data (order book updates)
@@ -38,112 +114,129 @@ impl SubscriptionHandle {
 /// The Polymarket WebSocket server will disconnect idle connections after 1-2 minutes.
 /// The Python client uses `ping_interval=5` to send keep-alive pings every 5 seconds.
 ///
-/// For Rust, the recommended approach is to use [`ReconnectingStream`](crate::websocket::ReconnectingStream)
-/// which automatically handles connection resets and reconnects with exponential backoff.
-/// This is more robust than manual ping/pong management.
+/// Streams send a `PING` heartbeat every 10 seconds by default (see
+/// [`with_ping_interval`](Self::with_ping_interval)), and report their state and
+/// heartbeat latency to a [`ConnectionHealth`] attached with
+/// [`with_health`](Self::with_health). To survive connection resets, use
+/// [`ReconnectingStream`](crate::websocket::ReconnectingStream), which reconnects
+/// with exponential backoff.
 #[derive(Debug, Clone)]
 pub struct MarketWsClient {
     ws_url: String,
+    ping_interval: Option<Duration>,
+    health: Option<ConnectionHealth>,
+    filter: EventFilter,
 }
 
-/// Parse a WebSocket message into a WsEvent
+/// Parse a WebSocket message into its WsEvents
 ///
 /// This is a helper function that handles the parsing logic shared by both
 /// subscribe() and subscribe_with_handle() methods.
 fn parse_ws_message(
     msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
-) -> Option<Result<WsEvent>> {
+    filter: EventFilter,
+) -> Vec<Result<WsEvent>> {
     match msg {
-        Ok(Message::Text(text)) => {
-            // Skip empty or whitespace-only messages
-            let trimmed = text.trim();
-            if trimmed.is_empty() {
-                return None;
-            }
-
-            // Skip PING/PONG messages sent as text (some servers do this)
-            if trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong") {
-                return None;
-            }
-
-            // The server can send either a single object or an array
-            // Try to parse as array first
-            if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                // Got an array, take the first event
-                if let Some(first) = events.first() {
-                    match serde_json::from_value::<WsEvent>(first.clone()) {
-                        Ok(event) => return Some(Ok(event)),
-                        Err(e) => return Some(Err(Error::Json(e))),
-                    }
-                } else {
-                    // Empty array, ignore
-                    return None;
-                }
-            }
-
-            // Try parsing as single object
-            match serde_json::from_str::<WsEvent>(&text) {
-                Ok(event) => Some(Ok(event)),
-                Err(e) => {
-                    // Log unexpected message format for debugging
-                    log::warn!(
-                        "Unexpected WebSocket message (first 200 chars): {}",
-                        &text.chars().take(200).collect::<String>()
-                    );
-                    Some(Err(Error::Json(e)))
-                }
-            }
-        }
+        Ok(Message::Text(text)) => parse_market_message_filtered(&text, filter),
         Ok(Message::Close(_)) => {
             // Connection closed gracefully
-            Some(Err(Error::ConnectionClosed))
+            vec![Err(Error::ConnectionClosed)]
         }
         Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
             // Ignore ping/pong frames (handled automatically)
-            None
+            Vec::new()
         }
         Ok(Message::Binary(_)) => {
             // Unexpected binary message
-            Some(Err(Error::WebSocket(
+            vec![Err(Error::WebSocket(
                 "Unexpected binary message".to_string(),
-            )))
+            ))]
         }
         Ok(Message::Frame(_)) => {
             // Raw frame (shouldn't happen)
-            None
+            Vec::new()
         }
         Err(e) => {
             // WebSocket error
-            Some(Err(Error::WebSocket(e.to_string())))
+            vec![Err(Error::WebSocket(e.to_string()))]
         }
     }
 }
 
+/// Parse a WebSocket message into its WsEvents along with their JSON
+#[cfg(feature = "raw")]
+fn parse_raw_ws_message(
+    msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
+    filter: EventFilter,
+) -> Vec<Result<Raw<WsEvent>>> {
+    match msg {
+        Ok(Message::Text(text)) => parse_text_raw(&text, filter),
+        // Other frames never carry an event
+        other => parse_ws_message(other, filter)
+            .into_iter()
+            .filter_map(|result| result.err())
+            .map(Err)
+            .collect(),
+    }
+}
+
 impl MarketWsClient {
     /// Default WebSocket URL for market data
     const DEFAULT_WS_URL: &'static str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
 
     /// Create a new market WebSocket client with the default endpoint
     pub fn new() -> Self {
-        Self {
-            ws_url: Self::DEFAULT_WS_URL.to_string(),
-        }
+        Self::with_url(Self::DEFAULT_WS_URL)
     }
 
     /// Create a new market WebSocket client with a custom endpoint
     pub fn with_url(ws_url: impl Into<String>) -> Self {
         Self {
             ws_url: ws_url.into(),
+            ping_interval: Some(DEFAULT_PING_INTERVAL),
+            health: None,
+            filter: EventFilter::all(),
         }
     }
 
-    /// Subscribe to market updates with a handle to query subscription state
+    /// Interval of the `PING` heartbeat; `Duration::ZERO` disables it
+    pub fn with_ping_interval(mut self, interval: Duration) -> Self {
+        self.ping_interval = Some(interval).filter(|interval| !interval.is_zero());
+        self
+    }
+
+    /// Report the state of every stream this client opens to `health`
+    pub fn with_health(mut self, health: ConnectionHealth) -> Self {
+        self.health = Some(health);
+        self
+    }
+
+    /// Deliver only the event types `filter` allows
+    ///
+    /// Applies to every stream the client opens. Other messages are dropped
+    /// before being parsed, which saves most of the decoding work when, say,
+    /// only trades are wanted from busy books.
+    ///
+    /// ```
+    /// use polymarket_rs::websocket::{EventFilter, MarketEventType, MarketWsClient};
+    ///
+    /// let client = MarketWsClient::new()
+    ///     .with_event_filter(EventFilter::only(&[MarketEventType::LastTradePrice]));
+    /// ```
+    pub fn with_event_filter(mut self, filter: EventFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Subscribe to market updates with a handle to manage the subscription
     ///
     /// Returns a stream of [`WsEvent`] items and a [`SubscriptionHandle`] that can be used
-    /// to query which token IDs are currently subscribed.
+    /// to query which token IDs are currently subscribed, and to add or remove tokens
+    /// without reconnecting.
     ///
-    /// **Note**: Polymarket does not support updating subscriptions on an existing connection.
-    /// To change subscriptions, you must close the connection and create a new one.
+    /// The stream does not reconnect; use
+    /// [`subscribe_reconnecting`](Self::subscribe_reconnecting) to keep runtime changes
+    /// across reconnects.
     ///
     /// # Arguments
     ///
@@ -153,7 +246,7 @@ impl MarketWsClient {
     ///
     /// A tuple containing:
     /// - Stream of [`WsEvent`] items
-    /// - [`SubscriptionHandle`] for querying current subscriptions
+    /// - [`SubscriptionHandle`] for managing subscriptions
     ///
     /// # Events
     ///
@@ -169,43 +262,137 @@ impl MarketWsClient {
     /// - The subscription message cannot be sent
     pub async fn subscribe_with_handle(
         &self,
-        token_ids: Vec<String>,
+        token_ids: Vec<TokenId>,
     ) -> Result<(
         Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>,
         SubscriptionHandle,
     )> {
-        // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (write, read) = ws_stream.split();
-        let mut write = write;
-
-        // Create subscription message
-        let subscription = MarketSubscription {
-            assets_ids: token_ids.clone(),
-        };
+        let handle = SubscriptionHandle::new(token_ids);
+        let stream = self.connect_with_handle(&handle).await?;
+        Ok((stream, handle))
+    }
 
-        let subscription_msg = serde_json::to_string(&subscription)?;
+    /// Subscribe with automatic reconnection that preserves runtime changes
+    ///
+    /// Like [`subscribe_with_handle`](Self::subscribe_with_handle), wrapped in a
+    /// [`ReconnectingStream`]. Each reconnect subscribes to the handle's token set
+    /// as it stands at that moment rather than the initial list, so tokens added
+    /// or removed at runtime survive disconnects. Changes made while reconnecting
+    /// wait for the new connection.
+    ///
+    /// `config` is a [`ReconnectConfig`](super::ReconnectConfig) or a [`RetryPolicy`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_rs::websocket::{MarketWsClient, ReconnectConfig};
+    /// use polymarket_rs::{StreamExt, TokenId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = MarketWsClient::new();
+    /// let (mut stream, handle) =
+    ///     client.subscribe_reconnecting(vec![TokenId::new("123")], ReconnectConfig::default());
+    ///
+    /// // Later, from anywhere holding a clone of the handle
+    /// handle.subscribe(vec![TokenId::new("456")]).await?;
+    ///
+    /// while let Some(event) = stream.next().await {
+    ///     println!("{:?}", event?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscribe_reconnecting(
+        &self,
+        token_ids: Vec<TokenId>,
+        config: impl Into<RetryPolicy>,
+    ) -> (
+        Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>,
+        SubscriptionHandle,
+    ) {
+        let handle = SubscriptionHandle::new(token_ids);
+        let client = self.clone();
+        let connect_handle = handle.clone();
+        let stream = ReconnectingStream::new(config, move || {
+            let client = client.clone();
+            let handle = connect_handle.clone();
+            async move { client.connect_with_handle(&handle).await }
+        });
+        (Box::pin(stream), handle)
+    }
 
-        // Send initial subscription message
-        write
-            .send(Message::Text(subscription_msg))
-            .await
-            .map_err(|e| Error::WebSocket(e.to_string()))?;
+    /// Connect, subscribe to the handle's tokens and hand it the write half
+    async fn connect_with_handle(
+        &self,
+        handle: &SubscriptionHandle,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
+        // Hold the writer so no change slips in between reading the tokens
+        // and installing the new connection
+        let mut writer = handle.writer.lock().await;
+        *writer = None;
 
-        // Drop the write half since we don't need to send any more messages
-        drop(write);
+        // Subscribe to the tokens in effect now, including runtime changes
+        let (write, read) = self.connect(handle.current_tokens().await).await?;
+        *writer = Some(write);
+        drop(writer);
 
-        // Create shared state for current tokens
-        let current_tokens = Arc::new(RwLock::new(token_ids));
+        Ok(self.event_stream(read, handle.writer.clone()))
+    }
 
-        // Create subscription handle
-        let handle = SubscriptionHandle { current_tokens };
+    /// Connect and send the subscription message
+    async fn connect(&self, token_ids: Vec<TokenId>) -> Result<(WsWriter, WsReader)> {
+        let health = self.health.clone().unwrap_or_default();
+        health.connecting();
+        let result = async {
+            // Connect to the WebSocket endpoint
+            let (ws_stream, _) = connect_async(&self.ws_url).await?;
+            let (mut write, read) = ws_stream.split();
+
+            let subscription = MarketSubscription {
+                assets_ids: token_ids,
+            };
+            let subscription_msg = serde_json::to_string(&subscription)?;
+            write
+                .send(Message::Text(subscription_msg))
+                .await
+                .map_err(|e| Error::WebSocket(e.to_string()))?;
+            Ok((write, read))
+        }
+        .await;
+        if result.is_err() {
+            health.failed();
+        }
+        result
+    }
 
-        // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+    /// Parse a connection's messages into events, with heartbeat and health
+    fn event_stream(
+        &self,
+        read: WsReader,
+        writer: SharedWriter,
+    ) -> Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>> {
+        let filter = self.filter;
+        monitor(
+            read,
+            writer,
+            self.health.clone().unwrap_or_default(),
+            self.ping_interval,
+            move |message| parse_ws_message(message, filter),
+        )
+    }
 
-        Ok((Box::pin(stream), handle))
+    /// Subscribe to the order books of every tradable market of an event
+    ///
+    /// Subscribes to all outcome tokens from [`EventMarkets::token_ids`] on one
+    /// connection; map events back to their market with
+    /// [`EventMarkets::market_for_token`]. For a long-running subscription, pass
+    /// the same token IDs to [`subscribe_reconnecting`](Self::subscribe_reconnecting).
+    pub async fn subscribe_event(
+        &self,
+        event: &EventMarkets,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
+        self.subscribe(event.token_ids()).await
     }
 
     /// Subscribe to market updates for the specified token IDs
@@ -235,34 +422,32 @@ impl MarketWsClient {
     /// - The subscription message cannot be sent
     pub async fn subscribe(
         &self,
-        token_ids: Vec<String>,
+        token_ids: Vec<TokenId>,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
-        // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (write, read) = ws_stream.split();
-        let mut write = write;
-
-        // Create subscription message
-        let subscription = MarketSubscription {
-            assets_ids: token_ids,
-        };
-
-        let subscription_msg = serde_json::to_string(&subscription)?;
-
-        // Send subscription message
-        write
-            .send(Message::Text(subscription_msg))
-            .await
-            .map_err(|e| Error::WebSocket(e.to_string()))?;
+        let (write, read) = self.connect(token_ids).await?;
 
-        // Drop the write half since we don't need to send any more messages
-        drop(write);
-
-        // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+        // The write half stays open for the heartbeat
+        Ok(self.event_stream(read, Arc::new(Mutex::new(Some(write)))))
+    }
 
-        Ok(Box::pin(stream))
+    /// Subscribe like [`subscribe`](Self::subscribe), keeping each event's JSON
+    ///
+    /// Persist [`Raw::raw`] to keep exactly what the server sent, including
+    /// fields this version doesn't parse. Requires the `raw` feature.
+    #[cfg(feature = "raw")]
+    pub async fn subscribe_raw(
+        &self,
+        token_ids: Vec<TokenId>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Raw<WsEvent>>> + Send>>> {
+        let (write, read) = self.connect(token_ids).await?;
+        let filter = self.filter;
+        Ok(monitor(
+            read,
+            Arc::new(Mutex::new(Some(write))),
+            self.health.clone().unwrap_or_default(),
+            self.ping_interval,
+            move |message| parse_raw_ws_message(message, filter),
+        ))
     }
 }
 
@@ -275,6 +460,10 @@ impl Default for MarketWsClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{MockWsServer, ScriptedFrame};
+    use crate::types::MarketEventType;
+    use crate::websocket::ReconnectConfig;
+    use std::time::Duration;
 
     #[test]
     fn test_client_creation() {
@@ -288,4 +477,82 @@ mod tests {
         let client = MarketWsClient::with_url(custom_url);
         assert_eq!(client.ws_url, custom_url);
     }
+
+    #[tokio::test]
+    async fn test_reconnect_restores_runtime_subscriptions() {
+        let trade = ScriptedFrame::json(&serde_json::json!({
+            "event_type": "last_trade_price",
+            "asset_id": "123",
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "price": "0.5",
+            "side": "BUY",
+            "size": "10",
+            "fee_rate_bps": "0",
+            "timestamp": "1700000000000",
+            "transaction_hash": "0x"
+        }));
+        let server = MockWsServer::start(vec![
+            vec![
+                trade.clone(),
+                ScriptedFrame::Delay(Duration::from_millis(100)),
+                ScriptedFrame::Disconnect,
+            ],
+            vec![trade],
+        ])
+        .await
+        .unwrap();
+
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let client = MarketWsClient::with_url(server.url());
+        let (mut stream, handle) =
+            client.subscribe_reconnecting(vec![TokenId::new("123"), TokenId::new("456")], config);
+
+        stream.next().await.unwrap().unwrap();
+        handle.subscribe(vec![TokenId::new("789")]).await.unwrap();
+        handle.unsubscribe(vec![TokenId::new("456")]).await.unwrap();
+        // The dropped connection surfaces as an error before the reconnect
+        while stream.next().await.unwrap().is_err() {}
+
+        let received = server.received();
+        assert_eq!(server.connection_count(), 2);
+        assert_eq!(
+            received.last().unwrap(),
+            &serde_json::json!({ "assets_ids": ["123", "789"] })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_event_filter_drops_before_parsing() {
+        let server = MockWsServer::start(vec![vec![
+            // Malformed, so it would surface as an error if it were parsed
+            ScriptedFrame::Text(r#"{"event_type": "book", "bids": "?"}"#.to_string()),
+            ScriptedFrame::json(&serde_json::json!([{
+                "event_type": "last_trade_price",
+                "asset_id": "123",
+                "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "price": "0.5",
+                "side": "BUY",
+                "size": "10",
+                "fee_rate_bps": "0",
+                "timestamp": "1700000000000",
+                "transaction_hash": "0x"
+            }])),
+        ]])
+        .await
+        .unwrap();
+
+        let client = MarketWsClient::with_url(server.url()).with_event_filter(
+            EventFilter::all()
+                .without(MarketEventType::Book)
+                .without(MarketEventType::PriceChange),
+        );
+        let mut stream = client.subscribe(vec![TokenId::new("123")]).await.unwrap();
+        assert!(matches!(
+            stream.next().await.unwrap(),
+            Ok(WsEvent::LastTradePrice(_))
+        ));
+    }
 }
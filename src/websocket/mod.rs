@@ -4,22 +4,51 @@
 //! - [`MarketWsClient`]: Streams real-time order book updates for markets
 //! - [`UserWsClient`]: Streams authenticated user events (trades and order updates)
 //!
+//! Events can be consumed by matching on [`WsEvent`] / [`UserWsEvent`], or by
+//! implementing [`EventHandler`] and driving it with [`run_handler`].
+//! [`conflate`] turns a market stream into periodic full book snapshots, and
+//! [`broadcast`] shares one stream between several consumers.
+//! [`CandleAggregator`] builds per-token candles from price history and
+//! live trades. [`FrameCapture`] replays recorded frames through the
+//! parsers offline.
+//!
 //! # Connection Management
 //!
 //! The Polymarket WebSocket server may disconnect idle connections after 1-2 minutes.
 //! For production use, it's recommended to use [`ReconnectingStream`] to automatically
-//! handle disconnections and reconnect with exponential backoff.
+//! handle disconnections and reconnect with exponential backoff. Both clients send
+//! a `PING` heartbeat, and a [`ConnectionHealth`] handle reports a stream's state
+//! and latency to supervising tasks.
 
+mod book;
+mod broadcast;
+mod candles;
+mod capture;
+mod handler;
+mod health;
+mod history;
 mod market;
+mod parse;
 mod stream;
 mod user;
 
+pub use book::{conflate, OrderBook, OrderBooks};
+pub use broadcast::{broadcast, EventBus};
+pub use candles::{Candle, CandleAggregator};
+pub use capture::FrameCapture;
+pub use handler::{run_handler, Dispatch, EventHandler};
+pub use health::{ConnectionHealth, ConnectionState};
+pub use history::{BookHistory, BookMetric, BookSample, RollingStats};
 pub use market::{MarketWsClient, SubscriptionHandle};
+pub use parse::{parse_market_message, parse_market_message_filtered, parse_user_message};
+#[cfg(feature = "raw")]
+pub use parse::{parse_market_message_raw, parse_user_message_raw};
 pub use stream::{ReconnectConfig, ReconnectingStream};
 pub use user::UserWsClient;
 
 // Re-export commonly used types for convenience
 pub use crate::types::{
-    BookEvent, LastTradePriceEvent, MarketSubscription, OrderEvent, PriceChange, PriceChangeEvent,
-    PriceLevel, TradeEvent, UserAuthentication, UserWsEvent, WsEvent,
+    BookEvent, EventFilter, LastTradePriceEvent, MarketEventType, MarketSubscription, OrderEvent,
+    PriceChange, PriceChangeEvent, PriceLevel, TickSizeChangeEvent, TradeEvent, UserAuthentication,
+    UserWsEvent, WsEvent,
 };
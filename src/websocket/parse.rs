@@ -0,0 +1,338 @@
+use serde::{Deserialize, Deserializer};
+use serde_json::value::RawValue;
+use std::borrow::Cow;
+
+use crate::error::{Error, Result};
+#[cfg(feature = "raw")]
+use crate::types::Raw;
+use crate::types::{
+    BookEvent, EventFilter, LastTradePriceEvent, OrderEvent, PriceChangeEvent, TickSizeChangeEvent,
+    TradeEvent, UserWsEvent, WsEvent,
+};
+
+/// The `event_type` discriminator of a message, read without buffering the rest
+#[derive(Deserialize)]
+struct EventTag<'a> {
+    #[serde(borrow)]
+    event_type: Option<Cow<'a, str>>,
+}
+
+/// Read the `event_type` of a single-object message
+fn event_tag(text: &str) -> Option<Cow<'_, str>> {
+    serde_json::from_str::<EventTag>(text).ok()?.event_type
+}
+
+/// An event enum decoded by dispatching on its `event_type`
+///
+/// The event enums are untagged, and deserializing one buffers every field
+/// of the message (including each price level of a book snapshot) into
+/// owned intermediate values before trying each variant. Dispatching on the
+/// tag, read once while filtering, lets the event struct deserialize
+/// straight from the text, with prices and sizes parsed from borrowed
+/// strings.
+pub(super) trait TaggedEvent: Sized {
+    fn deserialize_tagged<'de, D: Deserializer<'de>>(
+        tag: Option<&str>,
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error>;
+}
+
+impl TaggedEvent for WsEvent {
+    fn deserialize_tagged<'de, D: Deserializer<'de>>(
+        tag: Option<&str>,
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        match tag {
+            Some("book") => BookEvent::deserialize(deserializer).map(WsEvent::Book),
+            Some("price_change") => {
+                PriceChangeEvent::deserialize(deserializer).map(WsEvent::PriceChange)
+            }
+            Some("last_trade_price") => {
+                LastTradePriceEvent::deserialize(deserializer).map(WsEvent::LastTradePrice)
+            }
+            Some("tick_size_change") => {
+                TickSizeChangeEvent::deserialize(deserializer).map(WsEvent::TickSizeChange)
+            }
+            _ => WsEvent::deserialize(deserializer),
+        }
+    }
+}
+
+impl TaggedEvent for UserWsEvent {
+    fn deserialize_tagged<'de, D: Deserializer<'de>>(
+        tag: Option<&str>,
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        match tag {
+            Some("trade") => TradeEvent::deserialize(deserializer).map(UserWsEvent::Trade),
+            Some("order") => OrderEvent::deserialize(deserializer).map(UserWsEvent::Order),
+            _ => UserWsEvent::deserialize(deserializer),
+        }
+    }
+}
+
+/// Decode one event whose tag has been read
+#[cfg(not(feature = "simd-json"))]
+fn decode<T: TaggedEvent>(json: &str, tag: Option<&str>) -> Result<T> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let event = T::deserialize_tagged(tag, &mut deserializer)?;
+    deserializer.end()?;
+    Ok(event)
+}
+
+/// Decode one event whose tag has been read, with simd-json
+///
+/// simd-json parses in place, so the event is copied into a scratch buffer
+/// first; strings in the event borrow from that buffer.
+#[cfg(feature = "simd-json")]
+fn decode<T: TaggedEvent>(json: &str, tag: Option<&str>) -> Result<T> {
+    let json_error = |e: simd_json::Error| Error::Json(serde::de::Error::custom(e.to_string()));
+    let mut scratch = json.as_bytes().to_vec();
+    let mut deserializer = simd_json::Deserializer::from_slice(&mut scratch).map_err(json_error)?;
+    T::deserialize_tagged(tag, &mut deserializer).map_err(json_error)
+}
+
+/// Decode one event whose tag has been read, keeping its JSON
+#[cfg(feature = "raw")]
+fn decode_raw<T: TaggedEvent>(json: &str, tag: Option<&str>) -> Result<Raw<T>> {
+    let event = decode(json, tag)?;
+    Ok(Raw::new(event, RawValue::from_string(json.to_string())?))
+}
+
+/// The payload of a text message, or `None` for one that carries no event:
+/// an empty payload or a text keep-alive (`PING`/`PONG`, which some servers
+/// send as text)
+fn payload(text: &str) -> Option<&str> {
+    let trimmed = text.trim();
+    let keep_alive = trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong");
+    (!trimmed.is_empty() && !keep_alive).then_some(trimmed)
+}
+
+/// Decode the events of a payload, each through `decode`
+///
+/// Arrays are split into borrowed slices of the original text, so each
+/// event decodes exactly like a single object without an intermediate
+/// `Value` tree.
+fn decode_events<U>(
+    payload: &str,
+    decode: fn(&str, Option<&str>) -> Result<U>,
+    filter: EventFilter,
+) -> Vec<Result<U>> {
+    let events: Vec<&str> = if payload.starts_with('[') {
+        match serde_json::from_str::<Vec<&RawValue>>(payload) {
+            Ok(events) => events.into_iter().map(RawValue::get).collect(),
+            Err(e) => {
+                warn_unexpected(payload);
+                return vec![Err(Error::Json(e))];
+            }
+        }
+    } else {
+        vec![payload]
+    };
+
+    let results: Vec<Result<U>> = events
+        .into_iter()
+        .filter_map(|json| {
+            let tag = event_tag(json);
+            filter
+                .allows_tag(tag.as_deref())
+                .then(|| decode(json, tag.as_deref()))
+        })
+        .collect();
+
+    if results.iter().any(Result::is_err) {
+        warn_unexpected(payload);
+    }
+    results
+}
+
+/// Log unexpected message format for debugging
+fn warn_unexpected(payload: &str) {
+    log::warn!(
+        "Unexpected WebSocket message (first 200 chars): {}",
+        &payload.chars().take(200).collect::<String>()
+    );
+}
+
+/// Parse a websocket text message into its events
+///
+/// The server sends either a single object or an array of them; every event
+/// `filter` passes is returned, in order. Messages that carry no event, such
+/// as empty payloads, text keep-alives (`PING`/`PONG`) and empty arrays,
+/// give an empty `Vec`. An event that fails to decode is returned as an
+/// error in its place without affecting the others.
+///
+/// Each event's tag is read once, to apply `filter` and to pick the type it
+/// deserializes as. With the `simd-json` feature each event is decoded by
+/// simd-json.
+fn parse_text<T: TaggedEvent>(text: &str, filter: EventFilter) -> Vec<Result<T>> {
+    match payload(text) {
+        Some(payload) => decode_events(payload, decode::<T>, filter),
+        None => Vec::new(),
+    }
+}
+
+/// Parse a websocket text message into its events, keeping each event's JSON
+#[cfg(feature = "raw")]
+pub(super) fn parse_text_raw<T: TaggedEvent>(
+    text: &str,
+    filter: EventFilter,
+) -> Vec<Result<Raw<T>>> {
+    match payload(text) {
+        Some(payload) => decode_events(payload, decode_raw::<T>, filter),
+        None => Vec::new(),
+    }
+}
+
+/// Parse a text message from the market channel
+///
+/// This is the parsing step [`MarketWsClient`](super::MarketWsClient) applies
+/// to every message. Returns every event the message carries, in order; messages
+/// that carry none give an empty `Vec`.
+pub fn parse_market_message(text: &str) -> Vec<Result<WsEvent>> {
+    parse_text(text, EventFilter::all())
+}
+
+/// Parse a text message from the market channel, dropping events `filter`
+/// excludes before decoding them
+pub fn parse_market_message_filtered(text: &str, filter: EventFilter) -> Vec<Result<WsEvent>> {
+    parse_text(text, filter)
+}
+
+/// Parse a text message from the user channel
+///
+/// This is the parsing step [`UserWsClient`](super::UserWsClient) applies to
+/// every message. Returns every event the message carries, in order.
+pub fn parse_user_message(text: &str) -> Vec<Result<UserWsEvent>> {
+    parse_text(text, EventFilter::all())
+}
+
+/// Parse a text message from the market channel, keeping each event's JSON
+///
+/// Like [`parse_market_message`]; for an array each event keeps the JSON of
+/// its own element.
+#[cfg(feature = "raw")]
+pub fn parse_market_message_raw(text: &str) -> Vec<Result<Raw<WsEvent>>> {
+    parse_text_raw(text, EventFilter::all())
+}
+
+/// Parse a text message from the user channel, keeping each event's JSON
+#[cfg(feature = "raw")]
+pub fn parse_user_message_raw(text: &str) -> Vec<Result<Raw<UserWsEvent>>> {
+    parse_text_raw(text, EventFilter::all())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MarketEventType;
+
+    const TICK_SIZE_CHANGE: &str = r#"{
+        "event_type": "tick_size_change",
+        "asset_id": "123",
+        "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+        "old_tick_size": "0.01",
+        "new_tick_size": "0.001",
+        "timestamp": "1700000000000"
+    }"#;
+
+    fn book(asset_id: &str) -> String {
+        format!(
+            r#"{{"event_type": "book", "asset_id": "{}",
+            "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "timestamp": "1700000000000", "hash": "0x",
+            "bids": [{{"price": "0.48", "size": "30"}}], "asks": []}}"#,
+            asset_id
+        )
+    }
+
+    #[test]
+    fn test_parse_market_message() {
+        assert!(matches!(
+            parse_market_message(TICK_SIZE_CHANGE)[..],
+            [Ok(WsEvent::TickSizeChange(_))]
+        ));
+        let array = format!("[{}]", TICK_SIZE_CHANGE);
+        assert!(matches!(
+            parse_market_message(&array)[..],
+            [Ok(WsEvent::TickSizeChange(_))]
+        ));
+
+        assert_eq!(
+            event_tag(TICK_SIZE_CHANGE).as_deref(),
+            Some("tick_size_change")
+        );
+        assert!(parse_market_message("PONG").is_empty());
+        assert!(parse_market_message("[]").is_empty());
+        assert!(matches!(
+            parse_market_message("[{\"event_type\": \"book\"}")[..],
+            [Err(Error::Json(_))]
+        ));
+        assert!(matches!(
+            parse_market_message("{\"event_type\": \"unknown\"}")[..],
+            [Err(Error::Json(_))]
+        ));
+    }
+
+    #[test]
+    fn test_array_yields_every_event() {
+        let array = format!("[{}, {}]", book("123"), book("456"));
+        let assets: Vec<String> = parse_market_message(&array)
+            .into_iter()
+            .map(|event| match event.unwrap() {
+                WsEvent::Book(book) => book.asset_id.into_inner(),
+                other => panic!("unexpected event {:?}", other),
+            })
+            .collect();
+        assert_eq!(assets, ["123", "456"]);
+
+        // A bad element doesn't hide the events around it
+        let array = format!(
+            "[{}, {{\"event_type\": \"book\"}}, {}]",
+            book("1"),
+            book("2")
+        );
+        let events = parse_market_message(&array);
+        assert!(matches!(events[..], [Ok(_), Err(_), Ok(_)]));
+    }
+
+    #[cfg(feature = "raw")]
+    #[test]
+    fn test_parse_market_message_raw() {
+        let raw = parse_market_message_raw(&format!("[{},{}]", book("1"), book("2")));
+        assert_eq!(raw.len(), 2);
+        assert_eq!(raw[1].as_ref().unwrap().raw(), book("2"));
+
+        let array = format!("[{}]", TICK_SIZE_CHANGE);
+        let event = parse_market_message_raw(&array).remove(0).unwrap();
+        assert!(matches!(*event, WsEvent::TickSizeChange(_)));
+        assert_eq!(event.raw(), TICK_SIZE_CHANGE);
+        assert!(parse_market_message_raw("PONG").is_empty());
+    }
+
+    #[test]
+    fn test_filter_skips_unwanted_events() {
+        let only_books = EventFilter::only(&[MarketEventType::Book]);
+        assert!(parse_market_message_filtered(TICK_SIZE_CHANGE, only_books).is_empty());
+        assert!(
+            parse_market_message_filtered("{\"event_type\": \"unknown\"}", only_books).is_empty()
+        );
+
+        let filter = only_books.with(MarketEventType::TickSizeChange);
+        let array = format!(
+            "[{{\"event_type\": \"price_change\"}}, {}, {}, {{\"event_type\": \"unknown\"}}, {}]",
+            TICK_SIZE_CHANGE,
+            book("123"),
+            TICK_SIZE_CHANGE
+        );
+        assert!(matches!(
+            parse_market_message_filtered(&array, filter)[..],
+            [
+                Ok(WsEvent::TickSizeChange(_)),
+                Ok(WsEvent::Book(_)),
+                Ok(WsEvent::TickSizeChange(_))
+            ]
+        ));
+        assert!(EventFilter::default().allows_all());
+    }
+}
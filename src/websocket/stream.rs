@@ -5,6 +5,7 @@ use std::task::{Context, Poll};
 use std::time::Duration;
 use tokio::time::sleep;
 
+use crate::config::RetryPolicy;
 use crate::error::{Error, Result};
 
 /// Configuration for reconnection behavior
@@ -31,37 +32,17 @@ impl Default for ReconnectConfig {
     }
 }
 
-/// Exponential backoff calculator
-#[derive(Debug, Clone)]
-struct ExponentialBackoff {
-    current_delay: Duration,
-    max_delay: Duration,
-    multiplier: f64,
-}
-
-impl ExponentialBackoff {
-    fn new(initial_delay: Duration, max_delay: Duration, multiplier: f64) -> Self {
-        Self {
-            current_delay: initial_delay,
-            max_delay,
-            multiplier,
+impl From<ReconnectConfig> for RetryPolicy {
+    /// The same backoff, retrying after any error
+    fn from(config: ReconnectConfig) -> Self {
+        let policy = RetryPolicy::new()
+            .with_backoff(config.initial_delay, config.max_delay, config.multiplier)
+            .with_retryable(|_| true);
+        match config.max_attempts {
+            Some(max) => policy.with_max_attempts(max),
+            None => policy.with_unlimited_attempts(),
         }
     }
-
-    /// Get the next delay duration
-    fn next_delay(&mut self) -> Duration {
-        let delay = self.current_delay;
-        self.current_delay = std::cmp::min(
-            Duration::from_secs_f64(delay.as_secs_f64() * self.multiplier),
-            self.max_delay,
-        );
-        delay
-    }
-
-    /// Reset the backoff to initial delay
-    fn reset(&mut self) {
-        self.current_delay = Duration::from_secs(1);
-    }
 }
 
 /// State of the reconnecting stream
@@ -89,16 +70,22 @@ enum StreamState<S, Fut> {
 /// - Using exponential backoff between reconnection attempts
 /// - Optionally limiting the number of reconnection attempts
 ///
+/// It accepts a [`ReconnectConfig`] or a [`RetryPolicy`]. With a policy, a
+/// failed connection attempt whose error the policy doesn't retry ends the
+/// stream with [`Error::ReconnectFailed`], and delays are jittered if the
+/// policy sets [`with_jitter`](RetryPolicy::with_jitter).
+///
 /// # Example
 ///
 /// ```no_run
 /// use polymarket_rs::websocket::{MarketWsClient, ReconnectingStream, ReconnectConfig};
+/// use polymarket_rs::TokenId;
 /// use futures_util::StreamExt;
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let client = MarketWsClient::new();
-///     let token_ids = vec!["token_id".to_string()];
+///     let token_ids = vec![TokenId::new("token_id")];
 ///
 ///     let config = ReconnectConfig::default();
 ///     let mut stream = ReconnectingStream::new(
@@ -127,10 +114,8 @@ where
     connect_fn: F,
     /// Current state of the stream
     state: StreamState<S, Fut>,
-    /// Reconnection configuration
-    config: ReconnectConfig,
-    /// Exponential backoff calculator
-    backoff: ExponentialBackoff,
+    /// Reconnection policy
+    policy: RetryPolicy,
     /// Sleep future for reconnection delay
     sleep_future: Option<Pin<Box<tokio::time::Sleep>>>,
 }
@@ -145,14 +130,10 @@ where
     ///
     /// # Arguments
     ///
-    /// * `config` - Configuration for reconnection behavior
+    /// * `config` - A [`ReconnectConfig`] or [`RetryPolicy`]
     /// * `connect_fn` - Function that creates a new stream connection
-    pub fn new(config: ReconnectConfig, connect_fn: F) -> Self {
-        let backoff = ExponentialBackoff::new(
-            config.initial_delay,
-            config.max_delay,
-            config.multiplier,
-        );
+    pub fn new(config: impl Into<RetryPolicy>, connect_fn: F) -> Self {
+        let policy = config.into();
 
         Self {
             connect_fn,
@@ -160,26 +141,28 @@ where
                 attempts: 0,
                 future: None,
             },
-            config,
-            backoff,
+            policy,
             sleep_future: None,
         }
     }
 
     /// Handle a disconnection and prepare for reconnection
+    ///
+    /// Returns `Poll::Pending` once a reconnection has been scheduled; the
+    /// caller must keep polling so the backoff sleep registers a wakeup.
     fn handle_disconnection(&mut self, attempts: u32) -> Poll<Option<Result<T>>> {
         // Check if we've exceeded max attempts
-        if let Some(max) = self.config.max_attempts {
-            if attempts >= max {
-                self.state = StreamState::Terminated;
-                return Poll::Ready(Some(Err(Error::ReconnectFailed {
-                    attempts,
-                    last_error: "Maximum reconnection attempts reached".to_string(),
-                })));
-            }
+        if !self.policy.allows(attempts) {
+            self.state = StreamState::Terminated;
+            return Poll::Ready(Some(Err(Error::ReconnectFailed {
+                attempts,
+                last_error: "Maximum reconnection attempts reached".to_string(),
+            })));
         }
 
-        let delay = self.backoff.next_delay();
+        // Attempts restart from 1 after every successful connection, so the
+        // backoff does too
+        let delay = self.policy.delay(attempts);
         self.state = StreamState::Reconnecting { attempts, delay };
         self.sleep_future = Some(Box::pin(sleep(delay)));
         Poll::Pending
@@ -200,13 +183,14 @@ where
                 StreamState::Connected(stream) => {
                     match Pin::new(stream).poll_next(cx) {
                         Poll::Ready(Some(Ok(item))) => {
-                            // Successfully received an item, reset backoff
-                            self.backoff.reset();
                             return Poll::Ready(Some(Ok(item)));
                         }
                         Poll::Ready(Some(Err(Error::ConnectionClosed))) => {
                             // Connection closed, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            if let Poll::Ready(item) = self.handle_disconnection(1) {
+                                return Poll::Ready(item);
+                            }
+                            continue;
                         }
                         Poll::Ready(Some(Err(e))) => {
                             // Other error, pass through and prepare to reconnect
@@ -215,7 +199,10 @@ where
                         }
                         Poll::Ready(None) => {
                             // Stream ended, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            if let Poll::Ready(item) = self.handle_disconnection(1) {
+                                return Poll::Ready(item);
+                            }
+                            continue;
                         }
                         Poll::Pending => {
                             return Poll::Pending;
@@ -262,14 +249,23 @@ where
                     match boxed_fut.as_mut().poll(cx) {
                         Poll::Ready(Ok(stream)) => {
                             self.state = StreamState::Connected(stream);
-                            self.backoff.reset();
                             continue;
                         }
-                        Poll::Ready(Err(_e)) => {
+                        Poll::Ready(Err(e)) => {
                             // Connection failed, prepare to reconnect
                             // Increment attempts (or start at 1 if this is the first attempt)
                             let next_attempts = if current_attempts == 0 { 1 } else { current_attempts + 1 };
-                            return self.handle_disconnection(next_attempts);
+                            if !self.policy.is_retryable(&e) {
+                                self.state = StreamState::Terminated;
+                                return Poll::Ready(Some(Err(Error::ReconnectFailed {
+                                    attempts: next_attempts,
+                                    last_error: e.to_string(),
+                                })));
+                            }
+                            if let Poll::Ready(item) = self.handle_disconnection(next_attempts) {
+                                return Poll::Ready(item);
+                            }
+                            continue;
                         }
                         Poll::Pending => {
                             // Store the future for next poll
@@ -292,49 +288,65 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures_util::stream::{self, StreamExt};
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
 
-    #[test]
-    fn test_backoff() {
-        let mut backoff = ExponentialBackoff::new(
-            Duration::from_secs(1),
-            Duration::from_secs(60),
-            2.0,
-        );
-
-        assert_eq!(backoff.next_delay(), Duration::from_secs(1));
-        assert_eq!(backoff.next_delay(), Duration::from_secs(2));
-        assert_eq!(backoff.next_delay(), Duration::from_secs(4));
-        assert_eq!(backoff.next_delay(), Duration::from_secs(8));
+    /// A stream that connects to each scripted session in turn, each ending
+    /// after its items
+    fn scripted(
+        sessions: Vec<Vec<u32>>,
+        config: ReconnectConfig,
+    ) -> impl Stream<Item = Result<u32>> + Unpin {
+        let sessions = Arc::new(Mutex::new(VecDeque::from(sessions)));
+        ReconnectingStream::new(config, move || {
+            let items = sessions.lock().unwrap().pop_front();
+            std::future::ready(match items {
+                Some(items) => Ok(stream::iter(items.into_iter().map(Ok))),
+                None => Err(Error::ConnectionClosed),
+            })
+        })
     }
 
-    #[test]
-    fn test_backoff_max() {
-        let mut backoff = ExponentialBackoff::new(
-            Duration::from_secs(1),
-            Duration::from_secs(5),
-            2.0,
-        );
+    #[tokio::test]
+    async fn test_reconnects_after_stream_ends() {
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            multiplier: 1.0,
+            max_attempts: Some(3),
+        };
+        let mut stream = scripted(vec![vec![1], vec![], vec![2]], config);
 
-        assert_eq!(backoff.next_delay(), Duration::from_secs(1));
-        assert_eq!(backoff.next_delay(), Duration::from_secs(2));
-        assert_eq!(backoff.next_delay(), Duration::from_secs(4));
-        assert_eq!(backoff.next_delay(), Duration::from_secs(5)); // capped
-        assert_eq!(backoff.next_delay(), Duration::from_secs(5)); // still capped
+        // Every disconnection schedules a sleep that must wake the stream
+        let items = tokio::time::timeout(Duration::from_secs(5), async {
+            vec![stream.next().await, stream.next().await]
+        })
+        .await
+        .expect("stream stalled after a disconnection");
+        assert!(matches!(items[..], [Some(Ok(1)), Some(Ok(2))]));
     }
 
-    #[test]
-    fn test_backoff_reset() {
-        let mut backoff = ExponentialBackoff::new(
-            Duration::from_secs(1),
-            Duration::from_secs(60),
-            2.0,
-        );
-
-        assert_eq!(backoff.next_delay(), Duration::from_secs(1));
-        assert_eq!(backoff.next_delay(), Duration::from_secs(2));
-
-        backoff.reset();
+    #[tokio::test]
+    async fn test_backoff_resets_to_initial_delay() {
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2.0,
+            max_attempts: None,
+        };
+        let mut stream = scripted(vec![vec![1], vec![2], vec![3]], config);
 
-        assert_eq!(backoff.next_delay(), Duration::from_secs(1));
+        let start = Instant::now();
+        for expected in 1..=3 {
+            let item = tokio::time::timeout(Duration::from_secs(5), stream.next())
+                .await
+                .expect("stream stalled after a disconnection");
+            assert_eq!(item.unwrap().unwrap(), expected);
+        }
+        // A reset to a fixed second rather than the configured delay would
+        // take at least two seconds here
+        assert!(start.elapsed() < Duration::from_millis(500));
     }
 }
@@ -1,9 +1,18 @@
 use futures_util::{SinkExt, Stream, StreamExt};
 use std::pin::Pin;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio_tungstenite::{connect_async, tungstenite, tungstenite::Message};
 
+use super::health::{monitor, ConnectionHealth, WsReader, WsWriter, DEFAULT_PING_INTERVAL};
+#[cfg(feature = "raw")]
+use super::parse::parse_text_raw;
+use super::parse::parse_user_message;
 use crate::error::{Error, Result};
 use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
+#[cfg(feature = "raw")]
+use crate::types::{EventFilter, Raw};
 
 /// WebSocket client for streaming authenticated user events
 ///
@@ -13,6 +22,10 @@ use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 /// # Connection Management
 ///
 /// The Polymarket WebSocket server will disconnect idle connections after 1-2 minutes.
+/// Streams send a `PING` heartbeat every 10 seconds by default (see
+/// [`with_ping_interval`](Self::with_ping_interval)), and report their state and
+/// heartbeat latency to a [`ConnectionHealth`] attached with
+/// [`with_health`](Self::with_health).
 /// For production use, it's recommended to use [`ReconnectingStream`](crate::websocket::ReconnectingStream)
 /// to automatically handle disconnections and reconnect with exponential backoff.
 ///
@@ -60,6 +73,62 @@ use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 #[derive(Debug, Clone)]
 pub struct UserWsClient {
     ws_url: String,
+    ping_interval: Option<Duration>,
+    health: Option<ConnectionHealth>,
+}
+
+/// Parse a user channel message into an event
+fn parse_ws_message(
+    msg: std::result::Result<Message, tungstenite::Error>,
+) -> Vec<Result<UserWsEvent>> {
+    match msg {
+        Ok(Message::Text(text)) => parse_user_message(&text),
+        Ok(Message::Close(close_frame)) => {
+            // Connection closed - may indicate auth failure
+            if let Some(frame) = close_frame {
+                vec![Err(Error::WebSocket(format!(
+                    "Connection closed: code={}, reason={}",
+                    frame.code, frame.reason
+                )))]
+            } else {
+                vec![Err(Error::ConnectionClosed)]
+            }
+        }
+        Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
+            // Ignore ping/pong frames (handled automatically)
+            Vec::new()
+        }
+        Ok(Message::Binary(_)) => {
+            // Unexpected binary message
+            vec![Err(Error::WebSocket(
+                "Unexpected binary message".to_string(),
+            ))]
+        }
+        Ok(Message::Frame(_)) => {
+            // Raw frame (shouldn't happen)
+            Vec::new()
+        }
+        Err(e) => {
+            // WebSocket error
+            vec![Err(Error::WebSocket(e.to_string()))]
+        }
+    }
+}
+
+/// Parse a WebSocket message into its UserWsEvents along with their JSON
+#[cfg(feature = "raw")]
+fn parse_raw_ws_message(
+    msg: std::result::Result<Message, tungstenite::Error>,
+) -> Vec<Result<Raw<UserWsEvent>>> {
+    match msg {
+        Ok(Message::Text(text)) => parse_text_raw(&text, EventFilter::all()),
+        // Other frames never carry an event
+        other => parse_ws_message(other)
+            .into_iter()
+            .filter_map(|result| result.err())
+            .map(Err)
+            .collect(),
+    }
 }
 
 impl UserWsClient {
@@ -68,18 +137,30 @@ impl UserWsClient {
 
     /// Create a new user WebSocket client with the default endpoint
     pub fn new() -> Self {
-        Self {
-            ws_url: Self::DEFAULT_WS_URL.to_string(),
-        }
+        Self::with_url(Self::DEFAULT_WS_URL)
     }
 
     /// Create a new user WebSocket client with a custom endpoint
     pub fn with_url(ws_url: impl Into<String>) -> Self {
         Self {
             ws_url: ws_url.into(),
+            ping_interval: Some(DEFAULT_PING_INTERVAL),
+            health: None,
         }
     }
 
+    /// Interval of the `PING` heartbeat; `Duration::ZERO` disables it
+    pub fn with_ping_interval(mut self, interval: Duration) -> Self {
+        self.ping_interval = Some(interval).filter(|interval| !interval.is_zero());
+        self
+    }
+
+    /// Report the state of every stream this client opens to `health`
+    pub fn with_health(mut self, health: ConnectionHealth) -> Self {
+        self.health = Some(health);
+        self
+    }
+
     /// Subscribe to user events with API credentials
     ///
     /// Returns a stream of [`UserWsEvent`] items. The stream will yield events as they
@@ -166,80 +247,72 @@ impl UserWsClient {
         api_secret: String,
         api_passphrase: String,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>> {
-        // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (mut write, read) = ws_stream.split();
-
-        // Create authentication message
         let auth = UserAuthentication::new(api_key, api_secret, api_passphrase);
+        let health = self.health.clone().unwrap_or_default();
+        let (write, read) = self.connect(auth, &health).await?;
 
-        let auth_msg = serde_json::to_string(&auth)?;
+        // Return stream that parses events, keeping the write half for the heartbeat
+        Ok(monitor(
+            read,
+            Arc::new(Mutex::new(Some(write))),
+            health,
+            self.ping_interval,
+            parse_ws_message,
+        ))
+    }
 
-        // Send authentication message
-        write
-            .send(Message::Text(auth_msg))
-            .await
-            .map_err(|e| Error::WebSocket(e.to_string()))?;
+    /// Subscribe like [`subscribe_with_creds`](Self::subscribe_with_creds),
+    /// keeping each event's JSON
+    ///
+    /// Requires the `raw` feature.
+    #[cfg(feature = "raw")]
+    pub async fn subscribe_raw_with_creds(
+        &self,
+        creds: &ApiCreds,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Raw<UserWsEvent>>> + Send>>> {
+        let auth = UserAuthentication::new(
+            creds.api_key.clone(),
+            creds.secret.clone(),
+            creds.passphrase.clone(),
+        );
+        let health = self.health.clone().unwrap_or_default();
+        let (write, read) = self.connect(auth, &health).await?;
+        Ok(monitor(
+            read,
+            Arc::new(Mutex::new(Some(write))),
+            health,
+            self.ping_interval,
+            parse_raw_ws_message,
+        ))
+    }
 
-        // Return stream that parses events
-        let stream = read.filter_map(|msg| async move {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    // The server can send either a single object or an array
-                    // Try to parse as array first
-                    if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                        // Got an array, take the first event
-                        if let Some(first) = events.first() {
-                            match serde_json::from_value::<UserWsEvent>(first.clone()) {
-                                Ok(event) => return Some(Ok(event)),
-                                Err(e) => return Some(Err(Error::Json(e))),
-                            }
-                        } else {
-                            // Empty array, ignore
-                            return None;
-                        }
-                    }
+    /// Connect and send the authentication message
+    async fn connect(
+        &self,
+        auth: UserAuthentication,
+        health: &ConnectionHealth,
+    ) -> Result<(WsWriter, WsReader)> {
+        health.connecting();
+        let result: Result<_> = async {
+            // Connect to the WebSocket endpoint
+            let (ws_stream, _) = connect_async(&self.ws_url).await?;
+            let (mut write, read) = ws_stream.split();
 
-                    // Try parsing as single object
-                    match serde_json::from_str::<UserWsEvent>(&text) {
-                        Ok(event) => Some(Ok(event)),
-                        Err(e) => Some(Err(Error::Json(e))),
-                    }
-                }
-                Ok(Message::Close(close_frame)) => {
-                    // Connection closed - may indicate auth failure
-                    if let Some(frame) = close_frame {
-                        Some(Err(Error::WebSocket(format!(
-                            "Connection closed: code={}, reason={}",
-                            frame.code, frame.reason
-                        ))))
-                    } else {
-                        Some(Err(Error::ConnectionClosed))
-                    }
-                }
-                Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
-                    // Ignore ping/pong frames (handled automatically)
-                    None
-                }
-                Ok(Message::Binary(_)) => {
-                    // Unexpected binary message
-                    Some(Err(Error::WebSocket(
-                        "Unexpected binary message".to_string(),
-                    )))
-                }
-                Ok(Message::Frame(_)) => {
-                    // Raw frame (shouldn't happen)
-                    None
-                }
-                Err(e) => {
-                    // WebSocket error
-                    Some(Err(Error::WebSocket(e.to_string())))
-                }
-            }
-        });
+            // Create authentication message
+            let auth_msg = serde_json::to_string(&auth)?;
 
-        Ok(Box::pin(stream))
+            // Send authentication message
+            write
+                .send(Message::Text(auth_msg))
+                .await
+                .map_err(|e| Error::WebSocket(e.to_string()))?;
+            Ok((write, read))
+        }
+        .await;
+        if result.is_err() {
+            health.failed();
+        }
+        result
     }
 }
 